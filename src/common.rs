@@ -11,7 +11,7 @@ pub const USECONDS_PER_HOUR: i64 = 3_600_000_000;
 pub const USECONDS_PER_MINUTE: i64 = 60_000_000;
 pub const USECONDS_PER_SECOND: i64 = 1_000_000;
 
-pub const DATE_MIN_YEAR: i32 = 1;
+pub const DATE_MIN_YEAR: i32 = -4712;
 pub const DATE_MAX_YEAR: i32 = 9999;
 
 pub const UNIX_EPOCH_JULIAN: i32 = date2julian(1970, 1, 1);
@@ -28,14 +28,44 @@ const SUM_OF_DAYS_TABLE: [[u32; 12]; 2] = [
     [0, 31, 60, 91, 121, 152, 182, 213, 244, 274, 305, 335],
 ];
 
+/// Converts a calendar year to an astronomical year.
+/// There is no year 0 in the calendar, negative years are BC years, e.g. 1 BC (-1) is year 0.
+#[inline(always)]
+pub const fn to_astronomical_year(year: i32) -> i32 {
+    if year < 0 {
+        year + 1
+    } else {
+        year
+    }
+}
+
+/// Converts an astronomical year to a calendar year, e.g. year 0 is 1 BC (-1).
+#[inline(always)]
+pub const fn from_astronomical_year(year: i32) -> i32 {
+    if year <= 0 {
+        year - 1
+    } else {
+        year
+    }
+}
+
+/// Adds years to a calendar year, skipping the non-existent year 0.
+#[inline(always)]
+pub const fn add_years(year: i32, years: i32) -> i32 {
+    from_astronomical_year(to_astronomical_year(year) + years)
+}
+
 /// Calendar date to Julian day conversion.
 /// Julian date is commonly used in astronomical applications,
 /// since it is numerically accurate and computationally simple.
 /// The algorithms here will accurately convert between Julian day
 /// and calendar date for all non-negative Julian days
-/// (i.e. from Nov 24, -4713 on).
+/// (i.e. from Nov 24, 4714 BC on).
+///
+/// The year is a calendar year, in which -1 is 1 BC.
 #[inline]
 pub const fn date2julian(year: i32, month: u32, day: u32) -> i32 {
+    let year = to_astronomical_year(year);
     let (y, m) = if month > 2 {
         (year + 4800, month + 1)
     } else {
@@ -51,7 +81,7 @@ pub const fn date2julian(year: i32, month: u32, day: u32) -> i32 {
     julian
 }
 
-/// Julian day to Calendar date conversion.
+/// Julian day to Calendar date conversion, in which -1 is 1 BC.
 #[inline]
 pub const fn julian2date(julian_day: i32) -> (i32, u32, u32) {
     let mut julian = julian_day as u32 + 32044;
@@ -68,11 +98,11 @@ pub const fn julian2date(julian_day: i32) -> (i32, u32, u32) {
         (julian + 306) % 366 + 123
     };
     y += (quad * 4) as i32;
-    let year = y - 4800;
+    let year = from_astronomical_year(y - 4800);
     quad = julian * 2141 / 65_536;
 
     let day = julian - 7834 * quad / 256;
-    let month = (quad + 10) % MONTHS_PER_YEAR as u32 + 1;
+    let month = (quad + 10) % MONTHS_PER_YEAR + 1;
 
     (year, month, day)
 }
@@ -92,8 +122,10 @@ pub const fn is_valid_time(time: i64) -> bool {
     time >= 0 && time < USECONDS_PER_DAY
 }
 
+/// Checks if the calendar year is a leap year, e.g. 1 BC (-1) is a leap year.
 #[inline(always)]
 pub const fn is_leap_year(year: i32) -> bool {
+    let year = to_astronomical_year(year);
     year % 4 == 0 && ((year % 100) != 0 || (year % 400) == 0)
 }
 
@@ -1,11 +1,13 @@
 //! Date implementation.
 
 use crate::common::{
-    date2julian, days_of_month, is_valid_date, julian2date, DATE_MAX_YEAR, DATE_MIN_YEAR,
-    MONTHS_PER_YEAR, UNIX_EPOCH_JULIAN,
+    add_years, date2julian, days_of_month, from_astronomical_year, is_valid_date, julian2date,
+    to_astronomical_year, DATE_MAX_YEAR, DATE_MIN_YEAR, MONTHS_PER_YEAR, UNIX_EPOCH_JULIAN,
 };
 use crate::error::{Error, Result};
 use crate::format::{Formatter, LazyFormat, NaiveDateTime};
+use crate::iso8601::{self, Iso8601};
+use crate::literal::{parse_auto, parse_mysql_datetime, DateOrder, LiteralForm};
 use crate::{DateTime, IntervalDT, IntervalYM, Round, Time, Timestamp, Trunc};
 use chrono::{Datelike, Local};
 use std::cmp::Ordering;
@@ -29,6 +31,36 @@ const ISO_YEAR_TABLE: [(DateSubMethod, i32); 8] = [
     (sub_to_date, -2),
 ];
 
+/// Gets the first year of the century containing the year, e.g. 2001 for 2023 and -100 for -44.
+#[inline]
+const fn century_first_year(year: i32) -> i32 {
+    if year > 0 {
+        (year - 1) / 100 * 100 + 1
+    } else {
+        (year + 1) / 100 * 100 - 100
+    }
+}
+
+/// Converts Julian date to day-of-week (0..6 == Mon..Sun)
+#[inline]
+const fn week_day_of_julian(date: i32) -> i32 {
+    let mut date = date;
+    date %= 7;
+    // Cope if division truncates towards zero, as it probably does
+    if date < 0 {
+        date += 7;
+    }
+    date
+}
+
+/// Gets the Julian day of the first day (Monday) of the ISO year,
+/// which is the Monday of the week containing January 4th.
+#[inline]
+const fn iso_year_first_julian(iso_year: i32) -> i32 {
+    let fourth_julian_day = date2julian(iso_year, 1, 4);
+    fourth_julian_day - week_day_of_julian(fourth_julian_day)
+}
+
 /// Weekdays in the order of 1..=7 Sun..=Sat for formatting and calculation use
 #[derive(Debug, Copy, Clone, PartialOrd, PartialEq)]
 pub enum WeekDay {
@@ -89,19 +121,21 @@ impl From<usize> for Month {
     }
 }
 
-/// Date represents a valid Gregorian date.
+/// Date represents a valid proleptic Gregorian date.
+///
+/// Years before 1 AD are BC years, there is no year 0, so the year before 1 is -1 (1 BC).
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
 #[repr(transparent)]
 pub struct Date(i32);
 
 impl Date {
-    /// The smallest date that can be represented by `Date`, i.e. `0001-01-01`.
-    pub const MIN: Self = unsafe { Date::from_ymd_unchecked(1, 1, 1) };
+    /// The smallest date that can be represented by `Date`, i.e. `-4712-01-01` (4712 BC).
+    pub const MIN: Self = unsafe { Date::from_ymd_unchecked(DATE_MIN_YEAR, 1, 1) };
 
     /// The largest date that can be represented by `Date`, i.e. `9999-12-31`.
     pub const MAX: Self = unsafe { Date::from_ymd_unchecked(9999, 12, 31) };
 
-    /// Creates a `Date` from the given year, month, and day.
+    /// Creates a `Date` from the given year, month, and day, the year -1 is 1 BC.
     ///
     /// # Safety
     /// This function is unsafe because the values are not checked for validity!
@@ -112,10 +146,10 @@ impl Date {
         Date(date)
     }
 
-    /// Creates a `Date` from the given year, month, and day.
+    /// Creates a `Date` from the given year, month, and day, the year -1 is 1 BC.
     #[inline]
     pub const fn try_from_ymd(year: i32, month: u32, day: u32) -> Result<Date> {
-        if year < DATE_MIN_YEAR || year > DATE_MAX_YEAR {
+        if year < DATE_MIN_YEAR || year > DATE_MAX_YEAR || year == 0 {
             return Err(Error::DateOutOfRange);
         }
 
@@ -137,7 +171,7 @@ impl Date {
     /// Checks if the given year, month, and day fields are valid.
     #[inline]
     pub const fn is_valid(year: i32, month: u32, day: u32) -> bool {
-        if year < DATE_MIN_YEAR || year > DATE_MAX_YEAR {
+        if year < DATE_MIN_YEAR || year > DATE_MAX_YEAR || year == 0 {
             return false;
         }
 
@@ -159,7 +193,7 @@ impl Date {
     /// Checks the given year, month, and day fields for building Date.
     #[inline]
     pub(crate) const fn validate_ymd(year: i32, month: u32, day: u32) -> Result<()> {
-        if year < DATE_MIN_YEAR || year > DATE_MAX_YEAR {
+        if year < DATE_MIN_YEAR || year > DATE_MAX_YEAR || year == 0 {
             return Err(Error::DateOutOfRange);
         }
 
@@ -239,6 +273,67 @@ impl Date {
         fmt.parse(input)
     }
 
+    /// Parses `Date` from a MySQL date literal, e.g. '2021-03-03', '2021#03#03', '20210303' or '21-3-3'.
+    /// The time of a datetime literal is ignored.
+    #[inline]
+    pub fn parse_mysql<S: AsRef<str>>(input: S) -> Result<Self> {
+        Date::try_from(parse_mysql_datetime(input.as_ref())?)
+    }
+
+    /// Parses `Date` from a literal of a common form without a format, e.g. '2021-10-01',
+    /// DATE '2021-10-01', '01-OCT-2021', '01/10/2021' or '20211001', and returns the form matched.
+    /// A date separated by slashes is read in the given `order` unless it is obvious.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use sqldatetime::{Date, DateOrder, LiteralForm};
+    ///
+    /// let date = Date::try_from_ymd(2021, 10, 1).unwrap();
+    /// assert_eq!(
+    ///     Date::parse_auto("01/10/2021", DateOrder::DayMonthYear).unwrap(),
+    ///     (date, LiteralForm::DayMonthYear)
+    /// );
+    /// assert_eq!(
+    ///     Date::parse_auto("10/01/2021", DateOrder::MonthDayYear).unwrap(),
+    ///     (date, LiteralForm::MonthDayYear)
+    /// );
+    /// ```
+    #[inline]
+    pub fn parse_auto<S: AsRef<str>>(input: S, order: DateOrder) -> Result<(Self, LiteralForm)> {
+        match parse_auto(input.as_ref(), order, "date")? {
+            auto if !auto.has_time => Ok((Date::try_from(auto.dt)?, auto.form)),
+            _ => Err(Error::InvalidLiteral("date")),
+        }
+    }
+
+    /// Parses `Date` from an ISO 8601 date in the calendar, week or ordinal form, extended or basic,
+    /// e.g. '2021-10-01', '20211001', '2021-W39-5' or '2021-274'. The year 0000 is 1 BC.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use sqldatetime::Date;
+    ///
+    /// let date = Date::try_from_ymd(2021, 10, 1).unwrap();
+    /// assert_eq!(Date::parse_iso8601("2021-W39-5").unwrap(), date);
+    /// assert_eq!(Date::parse_iso8601("2021274").unwrap(), date);
+    /// ```
+    #[inline]
+    pub fn parse_iso8601<S: AsRef<str>>(input: S) -> Result<Self> {
+        iso8601::parse_date(input.as_ref())
+    }
+
+    /// Formats `Date` as an ISO 8601 date, e.g. '2021-10-01', where the year 0000 is 1 BC.
+    #[inline]
+    pub fn iso8601(self) -> impl Display {
+        Iso8601 {
+            date: Some(self),
+            time: None,
+            utc: false,
+        }
+    }
+
     /// Makes a new `Timestamp` from the current date and 00:00:00.
     #[inline(always)]
     pub(crate) const fn and_zero_time(self) -> Timestamp {
@@ -260,7 +355,7 @@ impl Date {
         let (year, month, day) = self.extract();
 
         let mut new_month = month as i32 + interval.months();
-        let mut new_year = year;
+        let mut new_year = to_astronomical_year(year);
 
         if new_month > MONTHS_PER_YEAR as i32 {
             new_year += (new_month - 1) / MONTHS_PER_YEAR as i32;
@@ -270,7 +365,7 @@ impl Date {
             new_month = new_month % MONTHS_PER_YEAR as i32 + MONTHS_PER_YEAR as i32;
         }
 
-        Date::try_from_ymd(new_year, new_month as u32, day)
+        Date::try_from_ymd(from_astronomical_year(new_year), new_month as u32, day)
     }
 
     /// `Date` adds `IntervalYM`
@@ -353,19 +448,7 @@ impl Date {
 
     /// Converts date to ISO year.
     #[inline]
-    fn date_to_iso_year(self) -> i32 {
-        // Converts Julian date to day-of-week (0..6 == Mon..Sun)
-        #[inline]
-        fn week_day_of_julian(date: i32) -> i32 {
-            let mut date = date;
-            date %= 7;
-            // Cope if division truncates towards zero, as it probably does
-            if date < 0 {
-                date += 7;
-            }
-            date
-        }
-
+    pub(crate) fn date_to_iso_year(self) -> i32 {
         let mut year = self.year().unwrap();
         // current day
         let current_julian_day = self.days() + UNIX_EPOCH_JULIAN;
@@ -377,25 +460,46 @@ impl Date {
         // We need the first week containing a Thursday, otherwise this day falls
         // into the previous year for purposes of counting weeks
         if current_julian_day < fourth_julian_day - offset_to_monday {
-            fourth_julian_day = date2julian(year - 1, 1, 4);
+            year = add_years(year, -1);
+            fourth_julian_day = date2julian(year, 1, 4);
             offset_to_monday = week_day_of_julian(fourth_julian_day);
-            year -= 1;
         }
 
         // Sometimes the last few days in a year will fall into the first week of
         // the next year, so check for this
         let num_of_week = (current_julian_day - (fourth_julian_day - offset_to_monday)) / 7 + 1;
         if num_of_week >= 52 {
-            fourth_julian_day = date2julian(year + 1, 1, 4);
+            let next_year = add_years(year, 1);
+            fourth_julian_day = date2julian(next_year, 1, 4);
             offset_to_monday = week_day_of_julian(fourth_julian_day);
             if current_julian_day >= fourth_julian_day - offset_to_monday {
-                year += 1;
+                year = next_year;
             }
         }
 
         year
     }
 
+    /// Gets the ISO week number (1..=53) of the date.
+    #[inline]
+    pub(crate) fn iso_week(self) -> u32 {
+        let first_julian_day = iso_year_first_julian(self.date_to_iso_year());
+        ((self.days() + UNIX_EPOCH_JULIAN - first_julian_day) / 7 + 1) as u32
+    }
+
+    /// Creates a `Date` from the ISO year, ISO week number and ISO day of week (1..=7 == Mon..Sun).
+    #[inline]
+    pub(crate) fn try_from_iso_week_date(iso_year: i32, week: u32, week_day: u32) -> Result<Date> {
+        debug_assert!((1..=7).contains(&week_day));
+        if !(DATE_MIN_YEAR..=DATE_MAX_YEAR).contains(&iso_year) || iso_year == 0 {
+            return Err(Error::DateOutOfRange);
+        }
+
+        let julian_day =
+            iso_year_first_julian(iso_year) + (week as i32 - 1) * 7 + week_day as i32 - 1;
+        Date::try_from_days(julian_day - UNIX_EPOCH_JULIAN)
+    }
+
     #[inline]
     pub(crate) fn round_week_internal(self, year: i32) -> Result<Date> {
         const WEEK_TABLE: [(DateSubMethod, i32); 8] = [
@@ -447,13 +551,10 @@ impl Date {
 impl Trunc for Date {
     #[inline]
     fn trunc_century(self) -> Result<Self> {
-        let mut year = self.year().unwrap();
-
-        if year % 100 == 0 {
-            year -= 1;
+        let year = century_first_year(self.year().unwrap());
+        if year < DATE_MIN_YEAR {
+            return Err(Error::DateOutOfRange);
         }
-
-        year = year / 100 * 100 + 1;
         Ok(unsafe { Date::from_ymd_unchecked(year, 1, 1) })
     }
 
@@ -561,14 +662,15 @@ impl Round for Date {
             return Err(Error::DateOutOfRange);
         }
 
-        let mut century = input_year / 100;
-        if input_year % 100 == 0 {
-            century -= 1;
-        } else if input_year % 100 > 50 {
-            century += 1;
+        let first_year = century_first_year(input_year);
+        // Rounds up from the 51st year to the 99th year of the century
+        let res_year = match to_astronomical_year(input_year) - to_astronomical_year(first_year) {
+            50..=98 => add_years(first_year, 100),
+            _ => first_year,
+        };
+        if res_year < DATE_MIN_YEAR {
+            return Err(Error::DateOutOfRange);
         }
-
-        let res_year = century * 100 + 1;
         Ok(unsafe { Date::from_ymd_unchecked(res_year, 1, 1) })
     }
 
@@ -579,7 +681,7 @@ impl Round for Date {
             if year == DATE_MAX_YEAR {
                 return Err(Error::DateOutOfRange);
             }
-            year += 1;
+            year = add_years(year, 1);
         }
         Ok(unsafe { Date::from_ymd_unchecked(year, 1, 1) })
     }
@@ -593,7 +695,7 @@ impl Round for Date {
                 return Err(Error::DateOutOfRange);
             }
             // Sets the month and date into the first week.
-            date = unsafe { Date::from_ymd_unchecked(year + 1, 1, 4) };
+            date = unsafe { Date::from_ymd_unchecked(add_years(year, 1), 1, 4) };
         }
         date.trunc_iso_year()
     }
@@ -609,12 +711,12 @@ impl Round for Date {
         let index = month as usize - 1;
         let quarter_month = if is_round {
             if month >= 11 {
-                year += 1;
+                year = add_years(year, 1);
             }
             QUARTER_ROUND_MONTH[index]
         } else {
             if month == 12 {
-                year += 1;
+                year = add_years(year, 1);
             }
             QUARTER_TRUNC_MONTH[index]
         };
@@ -634,7 +736,7 @@ impl Round for Date {
                 if year == DATE_MAX_YEAR {
                     return Err(Error::DateOutOfRange);
                 }
-                year += 1;
+                year = add_years(year, 1);
                 month = 1;
             } else {
                 month += 1;
@@ -794,6 +896,7 @@ impl DateTime for Date {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{Locale, Territory};
     use chrono::{Datelike, Local};
 
     #[test]
@@ -866,18 +969,18 @@ mod tests {
             assert_eq!(format!("{}", date.format("MON").unwrap()), "AUG");
             assert_eq!(format!("{}", date.format("Mon").unwrap()), "Aug");
             assert_eq!(format!("{}", date.format("mon").unwrap()), "aug");
-            assert_eq!(format!("{}", date.format("MONTH").unwrap()), "AUGUST");
-            assert_eq!(format!("{}", date.format("MONtH").unwrap()), "AUGUST");
-            assert_eq!(format!("{}", date.format("Month").unwrap()), "August");
-            assert_eq!(format!("{}", date.format("month").unwrap()), "august");
+            assert_eq!(format!("{}", date.format("MONTH").unwrap()), "AUGUST   ");
+            assert_eq!(format!("{}", date.format("MONtH").unwrap()), "AUGUST   ");
+            assert_eq!(format!("{}", date.format("Month").unwrap()), "August   ");
+            assert_eq!(format!("{}", date.format("month").unwrap()), "august   ");
             assert_eq!(format!("{}", date.format("WW").unwrap()), "32");
             assert_eq!(format!("{}", date.format("W").unwrap()), "1");
-            assert_eq!(format!("{}", date.format("DAY").unwrap()), "SUNDAY");
-            assert_eq!(format!("{}", date.format("DAy").unwrap()), "SUNDAY");
-            assert_eq!(format!("{}", date.format("Day").unwrap()), "Sunday");
-            assert_eq!(format!("{}", date.format("DaY").unwrap()), "Sunday");
-            assert_eq!(format!("{}", date.format("day").unwrap()), "sunday");
-            assert_eq!(format!("{}", date.format("daY").unwrap()), "sunday");
+            assert_eq!(format!("{}", date.format("DAY").unwrap()), "SUNDAY   ");
+            assert_eq!(format!("{}", date.format("DAy").unwrap()), "SUNDAY   ");
+            assert_eq!(format!("{}", date.format("Day").unwrap()), "Sunday   ");
+            assert_eq!(format!("{}", date.format("DaY").unwrap()), "Sunday   ");
+            assert_eq!(format!("{}", date.format("day").unwrap()), "sunday   ");
+            assert_eq!(format!("{}", date.format("daY").unwrap()), "sunday   ");
             assert_eq!(format!("{}", date.format("DY").unwrap()), "SUN");
             assert_eq!(format!("{}", date.format("Dy").unwrap()), "Sun");
             assert_eq!(format!("{}", date.format("dy").unwrap()), "sun");
@@ -889,14 +992,14 @@ mod tests {
         {
             let date = generate_date(2000, 1, 1);
             let fmt = format!("{}", date.format("yyyy-MONTH-dd").unwrap());
-            assert_eq!(fmt, "2000-JANUARY-01");
+            assert_eq!(fmt, "2000-JANUARY  -01");
 
             let date = generate_date(2000, 1, 1);
             let fmt = format!("{}", date.format("yyyy-Mon-dd").unwrap());
             assert_eq!(fmt, "2000-Jan-01");
 
             let fmt = format!("{}", date.format("Day yyyy-Mon-dd").unwrap());
-            assert_eq!(fmt, "Saturday 2000-Jan-01");
+            assert_eq!(fmt, "Saturday  2000-Jan-01");
 
             let fmt = format!("{}", date.format("yyyyMMdd").unwrap());
             assert_eq!(fmt, "20000101");
@@ -979,12 +1082,12 @@ mod tests {
                     date.format("DAY DaY DY D W WW WW MM MM yyyy YYYY DDD")
                         .unwrap()
                 ),
-                "SUNDAY Sunday SUN 1 4 17 17 04 04 2021 2021 115"
+                "SUNDAY    Sunday    SUN 1 4 17 17 04 04 2021 2021 115"
             );
 
             assert_eq!(
                 format!("{}", date.format("DAYDaYDYDWWWWWDMMMMyyyyYYYYDDD").unwrap()),
-                "SUNDAYSundaySUN1171741040420212021115"
+                "SUNDAY   Sunday   SUN1171741040420212021115"
             );
         }
 
@@ -1006,6 +1109,698 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_date_fill_mode() {
+        let date = generate_date(987, 8, 6);
+
+        // Format
+        assert_eq!(
+            format!("{}", date.format("Month DD, YYYY").unwrap()),
+            "August    06, 0987"
+        );
+        assert_eq!(
+            format!("{}", date.format("FMMonth DD, YYYY").unwrap()),
+            "August 6, 987"
+        );
+        assert_eq!(
+            format!("{}", date.format("FMDay, Month FMDD").unwrap()),
+            "Monday, August 06"
+        );
+        assert_eq!(
+            format!("{}", date.format("FMMM/DD/YY DDD WW").unwrap()),
+            "8/6/87 218 32"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                generate_date(2000, 1, 1).format("FMDDD WW YY").unwrap()
+            ),
+            "1 1 0"
+        );
+        assert_eq!(format!("{}", date.format("FMMon Dy").unwrap()), "Aug Mon");
+
+        // Parse
+        assert_eq!(
+            Date::parse("August 6, 987", "FMMonth DD, YYYY").unwrap(),
+            date
+        );
+        assert_eq!(
+            Date::parse("August    06, 0987", "Month DD, YYYY").unwrap(),
+            date
+        );
+        assert_eq!(
+            Date::parse("August 6, 987", "Month DD, YYYY").unwrap(),
+            date
+        );
+
+        // Exact matching requires padding unless FM is in effect
+        assert_eq!(
+            Date::parse("August    06, 0987", "FXMonth DD, YYYY").unwrap(),
+            date
+        );
+        assert!(Date::parse("August 06, 0987", "FXMonth DD, YYYY").is_err());
+        assert!(Date::parse("August    6, 0987", "FXMonth DD, YYYY").is_err());
+        assert_eq!(
+            Date::parse("August 6, 987", "FXFMMonth DD, YYYY").unwrap(),
+            date
+        );
+        assert_eq!(
+            Date::parse("August 06, 0987", "FXFMMonth DD, YYYY").unwrap(),
+            date
+        );
+        assert!(Date::parse("August    6, 987", "FXFMMonth DD, YYYY").is_err());
+        assert!(Date::parse("August 6, 987", "FXFMMonth FMDD, YYYY").is_err());
+        assert_eq!(
+            Date::parse("Monday    08/06/0987", "FXDay FMMM/DD/YYYY").unwrap(),
+            date
+        );
+        assert_eq!(
+            Date::parse("2021-1-01", "FXYYYY-MM-DD")
+                .unwrap_err()
+                .oracle_code(),
+            1862
+        );
+    }
+
+    #[test]
+    fn test_date_round_year() {
+        let date = generate_date(1998, 10, 27);
+
+        // Format
+        assert_eq!(
+            format!("{}", date.format("DD-MON-RR").unwrap()),
+            "27-OCT-98"
+        );
+        assert_eq!(
+            format!("{}", date.format("DD-MON-RRRR").unwrap()),
+            "27-OCT-1998"
+        );
+        assert_eq!(
+            format!("{}", generate_date(2005, 1, 1).format("FMRR RRRR").unwrap()),
+            "5 2005"
+        );
+
+        // Parse
+        let current_year = Local::now().naive_local().year();
+        let century = current_year - current_year % 100;
+        let (low, high) = if current_year % 100 < 50 {
+            (century + 10, century - 100 + 90)
+        } else {
+            (century + 100 + 10, century + 90)
+        };
+        for fmt in ["DD-MON-RR", "DD-MON-RRRR", "FXDD-MON-RR", "FXDD-MON-RRRR"] {
+            assert_eq!(
+                Date::parse("27-OCT-10", fmt).unwrap(),
+                generate_date(low, 10, 27)
+            );
+            assert_eq!(
+                Date::parse("27-OCT-90", fmt).unwrap(),
+                generate_date(high, 10, 27)
+            );
+        }
+        assert_eq!(Date::parse("27-OCT-1998", "DD-MON-RR").unwrap(), date);
+        assert_eq!(Date::parse("27-OCT-1998", "DD-MON-RRRR").unwrap(), date);
+        assert_eq!(Date::parse("27-OCT-1998", "FXDD-MON-RRRR").unwrap(), date);
+        assert!(Date::parse("27-OCT-1998", "FXDD-MON-RR").is_err());
+        assert!(Date::parse("27-OCT-998", "FXDD-MON-RRRR").is_err());
+        assert!(Date::parse("27-OCT--98", "DD-MON-RR").is_err());
+        assert!(Date::parse("1998 98", "YYYY RR").is_err());
+        assert!(Date::parse("98", "R").is_err());
+    }
+
+    #[test]
+    fn test_date_bc() {
+        assert_eq!(Date::try_from_ymd(-4712, 1, 1).unwrap(), Date::MIN);
+        assert!(Date::try_from_ymd(-4713, 12, 31).is_err());
+        assert!(Date::try_from_ymd(0, 1, 1).is_err());
+        assert!(!Date::is_valid(0, 1, 1));
+        assert_eq!(
+            generate_date(-1, 12, 31).add_days(1).unwrap(),
+            generate_date(1, 1, 1)
+        );
+        assert_eq!(generate_date(-44, 3, 15).extract(), (-44, 3, 15));
+        assert_eq!(Date::MIN.extract(), (-4712, 1, 1));
+
+        // Leap years, 1 BC is a leap year
+        assert!(Date::try_from_ymd(-1, 2, 29).is_ok());
+        assert!(Date::try_from_ymd(-5, 2, 29).is_ok());
+        assert!(Date::try_from_ymd(-2, 2, 29).is_err());
+        assert!(Date::try_from_ymd(-101, 2, 29).is_err());
+        assert!(Date::try_from_ymd(-401, 2, 29).is_ok());
+
+        // Arithmetic skips year 0
+        let month = IntervalYM::try_from_ym(0, 1).unwrap();
+        let year = IntervalYM::try_from_ym(1, 0).unwrap();
+        assert_eq!(
+            generate_date(-1, 12, 15).add_interval_ym(month).unwrap(),
+            generate_ts(1, 1, 15, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            generate_date(1, 1, 15).sub_interval_ym(month).unwrap(),
+            generate_ts(-1, 12, 15, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            generate_date(-1, 3, 1).add_interval_ym(year).unwrap(),
+            generate_ts(1, 3, 1, 0, 0, 0, 0)
+        );
+        assert!(generate_date(-1, 2, 29).add_interval_ym(year).is_err());
+        assert_eq!(
+            generate_date(1, 1, 1).sub_date(generate_date(-1, 1, 1)),
+            366
+        );
+
+        // Trunc and round
+        let date = generate_date(-44, 3, 15);
+        assert_eq!(date.trunc_century().unwrap(), generate_date(-100, 1, 1));
+        assert_eq!(date.round_century().unwrap(), generate_date(1, 1, 1));
+        assert_eq!(
+            generate_date(-60, 1, 1).round_century().unwrap(),
+            generate_date(-100, 1, 1)
+        );
+        assert!(Date::MIN.trunc_century().is_err());
+        assert_eq!(date.trunc_year().unwrap(), generate_date(-44, 1, 1));
+        assert_eq!(
+            generate_date(-1, 7, 1).round_year().unwrap(),
+            generate_date(1, 1, 1)
+        );
+        assert_eq!(
+            generate_date(-1, 12, 31).round_month().unwrap(),
+            generate_date(1, 1, 1)
+        );
+
+        // Format
+        assert_eq!(
+            format!("{}", date.format("YYYY-MM-DD AD").unwrap()),
+            "0044-03-15 BC"
+        );
+        assert_eq!(
+            format!("{}", date.format("SYYYY B.C. bc a.d. SCC CC").unwrap()),
+            "-0044 B.C. bc b.c. -01 01"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                generate_date(2023, 1, 1).format("YYYY BC A.D. ad").unwrap()
+            ),
+            "2023 AD A.D. ad"
+        );
+        assert_eq!(
+            format!("{}", Date::MIN.format("FMDD Month YYYY AD").unwrap()),
+            "1 January 4712 BC"
+        );
+        assert_eq!(format!("{}", Date::MIN.format("J").unwrap()), "0000404");
+        assert_eq!(Date::parse("0000404", "J").unwrap(), Date::MIN);
+        assert_eq!(format!("{}", Date::MAX.format("J").unwrap()), "5373484");
+        assert_eq!(Date::parse("5373484", "J").unwrap(), Date::MAX);
+        assert!(Date::parse("1", "J").is_err());
+        assert!(Date::parse("403", "J").is_err());
+
+        // Parse
+        assert_eq!(Date::parse("0044-03-15 BC", "YYYY-MM-DD AD").unwrap(), date);
+        assert_eq!(
+            Date::parse("0044-03-15 b.c.", "YYYY-MM-DD B.C.").unwrap(),
+            date
+        );
+        assert_eq!(Date::parse("bc 44-03-15", "BC YYYY-MM-DD").unwrap(), date);
+        assert_eq!(Date::parse("-0044-03-15", "SYYYY-MM-DD").unwrap(), date);
+        assert_eq!(
+            Date::parse("2023-01-01 AD", "YYYY-MM-DD BC").unwrap(),
+            generate_date(2023, 1, 1)
+        );
+        assert_eq!(
+            Date::parse("4712-01-01 BC", "YYYY-MM-DD BC").unwrap(),
+            Date::MIN
+        );
+        assert!(Date::parse("4713-01-01 BC", "YYYY-MM-DD BC").is_err());
+        assert!(Date::parse("0000-01-01", "YYYY-MM-DD").is_err());
+        assert!(Date::parse("0000-01-01 BC", "YYYY-MM-DD BC").is_err());
+        assert!(Date::parse("-0044-03-15 BC", "SYYYY-MM-DD BC").is_err());
+        assert!(Date::parse("0044-03-15 BC", "YYYY-MM-DD A.D.").is_err());
+        assert!(Date::parse("0044-03-15 BX", "YYYY-MM-DD BC").is_err());
+        assert!(Date::parse("0044-03-15 BC AD", "YYYY-MM-DD BC AD").is_err());
+        assert_eq!(
+            Timestamp::parse("0044-03-15 10:00:00 BC", "YYYY-MM-DD HH24:MI:SS BC").unwrap(),
+            generate_ts(-44, 3, 15, 10, 0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_date_century_year() {
+        let date = generate_date(2000, 1, 1);
+
+        // Format
+        assert_eq!(
+            format!("{}", date.format("CC SCC SYYYY Y,YYY").unwrap()),
+            "20  20  2000 2,000"
+        );
+        assert_eq!(
+            format!("{}", generate_date(2001, 1, 1).format("CC").unwrap()),
+            "21"
+        );
+        assert_eq!(
+            format!("{}", generate_date(50, 1, 1).format("CC Y,YYY").unwrap()),
+            "01 0,050"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                generate_date(987, 1, 1).format("FMCC SCC SYYYY").unwrap()
+            ),
+            "10 10 987"
+        );
+        for year in [1, 99, 100, 101, 1999, 2000, 2001, 9999] {
+            let date = generate_date(year, 6, 15);
+            let century: i32 = date.format("CC").unwrap().to_string().parse().unwrap();
+            assert_eq!(
+                date.trunc_century().unwrap(),
+                generate_date((century - 1) * 100 + 1, 1, 1)
+            );
+        }
+
+        // Parse
+        let date = generate_date(2023, 1, 1);
+        assert_eq!(Date::parse("2,023-01-01", "Y,YYY-MM-DD").unwrap(), date);
+        assert_eq!(Date::parse("2,023-01-01", "FXY,YYY-MM-DD").unwrap(), date);
+        assert_eq!(Date::parse(" 2023-01-01", "SYYYY-MM-DD").unwrap(), date);
+        assert_eq!(Date::parse("+2023-01-01", "SYYYY-MM-DD").unwrap(), date);
+        assert_eq!(Date::parse(" 2023-01-01", "FXSYYYY-MM-DD").unwrap(), date);
+        assert_eq!(Date::parse("2023-01-01", "FXSYYYY-MM-DD").unwrap(), date);
+        assert_eq!(
+            Date::parse("-2023-01-01", "SYYYY-MM-DD").unwrap(),
+            generate_date(-2023, 1, 1)
+        );
+        assert!(Date::parse(" 023-01-01", "FXSYYYY-MM-DD").is_err());
+        assert!(Date::parse("2023-01-01", "Y,YYY-MM-DD").is_err());
+        assert!(Date::parse("2,23-01-01", "Y,YYY-MM-DD").is_err());
+        assert!(Date::parse("2023 2,023", "YYYY Y,YYY").is_err());
+        assert!(Date::parse("21", "CC").is_err());
+        assert!(Date::parse("21", "SCC").is_err());
+    }
+
+    #[test]
+    fn test_date_locale() {
+        fn format(date: Date, fmt: &str, locale: &'static Locale) -> String {
+            let mut s = String::new();
+            Formatter::try_new(fmt)
+                .unwrap()
+                .with_locale(locale)
+                .format(date, &mut s)
+                .unwrap();
+            s
+        }
+
+        fn parse(input: &str, fmt: &str, locale: &'static Locale) -> Result<Date> {
+            Formatter::try_new(fmt)
+                .unwrap()
+                .with_locale(locale)
+                .parse(input)
+        }
+
+        let date = generate_date(2021, 3, 3);
+
+        // Format
+        assert_eq!(
+            format(date, "DAY DD MONTH YYYY", &Locale::ENGLISH),
+            "WEDNESDAY 03 MARCH     2021"
+        );
+        assert_eq!(
+            format(date, "Day, DD. Month YYYY", &Locale::GERMAN),
+            "Mittwoch  , 03. März      2021"
+        );
+        assert_eq!(
+            format(date, "DAY DD MON YYYY", &Locale::GERMAN),
+            "MITTWOCH   03 MÄR 2021"
+        );
+        assert_eq!(
+            format(date, "fmday dd month yyyy", &Locale::FRENCH),
+            "mercredi 3 mars 2021"
+        );
+        assert_eq!(
+            format(generate_date(2021, 2, 6), "Dy DD Mon YYYY", &Locale::FRENCH),
+            "Sam. 06 Févr. 2021"
+        );
+        assert_eq!(
+            format(date, "FMDay, DD \"de\" month \"de\" YYYY", &Locale::SPANISH),
+            "Miércoles, 3 de marzo de 2021"
+        );
+        assert_eq!(
+            format(date, "YYYY MONTH DD DAY", &Locale::SIMPLIFIED_CHINESE),
+            "2021 三月  03 星期三"
+        );
+
+        // Parse
+        assert_eq!(
+            parse(
+                "Mittwoch, 03. März 2021",
+                "Day, DD. Month YYYY",
+                &Locale::GERMAN
+            )
+            .unwrap(),
+            date
+        );
+        assert_eq!(
+            parse("03 MÄR 2021", "DD MON YYYY", &Locale::GERMAN).unwrap(),
+            date
+        );
+        assert_eq!(
+            parse("03 märz 2021", "DD MM YYYY", &Locale::GERMAN).unwrap(),
+            date
+        );
+        assert_eq!(
+            parse("03 MARS 2021", "DD MONTH YYYY", &Locale::FRENCH).unwrap(),
+            date
+        );
+        assert_eq!(
+            parse(
+                "MIÉRCOLES 03 MARZO 2021",
+                "DAY DD MONTH YYYY",
+                &Locale::SPANISH
+            )
+            .unwrap(),
+            date
+        );
+        assert_eq!(
+            parse(
+                "2021 十二月 03",
+                "YYYY MONTH DD",
+                &Locale::SIMPLIFIED_CHINESE
+            )
+            .unwrap(),
+            generate_date(2021, 12, 3)
+        );
+        assert_eq!(
+            parse("2021 12月 03", "YYYY MON DD", &Locale::SIMPLIFIED_CHINESE).unwrap(),
+            generate_date(2021, 12, 3)
+        );
+        assert_eq!(
+            parse(
+                "Mittwoch  , 03. März      2021",
+                "FXDay, DD. Month YYYY",
+                &Locale::GERMAN
+            )
+            .unwrap(),
+            date
+        );
+        assert!(parse(
+            "Mittwoch, 03. März 2021",
+            "FXDay, DD. Month YYYY",
+            &Locale::GERMAN
+        )
+        .is_err());
+        assert!(parse("03 March 2021", "DD MONTH YYYY", &Locale::GERMAN).is_err());
+        assert!(parse("Wednesday 03 2021", "DAY DD YYYY", &Locale::FRENCH).is_err());
+    }
+
+    #[test]
+    fn test_date_territory() {
+        fn format(date: Date, fmt: &str, territory: &Territory, locale: &'static Locale) -> String {
+            let mut s = String::new();
+            Formatter::try_new_with_territory(fmt, territory)
+                .unwrap()
+                .with_locale(locale)
+                .format(date, &mut s)
+                .unwrap();
+            s
+        }
+
+        let date = generate_date(2021, 3, 3);
+
+        // Format
+        assert_eq!(
+            format!("{}", date.format("DL").unwrap()),
+            "Wednesday, March 3, 2021"
+        );
+        assert_eq!(format!("{}", date.format("DS").unwrap()), "03/03/2021");
+        assert_eq!(
+            format!("{}", date.format("DL \"(\"DS\")\"").unwrap()),
+            "Wednesday, March 3, 2021 (03/03/2021)"
+        );
+        assert_eq!(
+            format(date, "DL", &Territory::GERMANY, &Locale::GERMAN),
+            "Mittwoch, 3. März 2021"
+        );
+        assert_eq!(
+            format(date, "DS", &Territory::GERMANY, &Locale::GERMAN),
+            "03.03.21"
+        );
+        assert_eq!(
+            format(date, "DL", &Territory::SPAIN, &Locale::SPANISH),
+            "Miércoles, 3 de Marzo de 2021"
+        );
+        assert_eq!(
+            format(date, "DL", &Territory::CHINA, &Locale::SIMPLIFIED_CHINESE),
+            "2021年3月3日 星期三"
+        );
+        assert_eq!(
+            format(date, "DS", &Territory::UNITED_KINGDOM, &Locale::ENGLISH),
+            "03/03/2021"
+        );
+
+        static BROKEN: Territory = Territory::new("BROKEN", "DL", "DS", "TS");
+        assert!(Formatter::try_new_with_territory("DL", &BROKEN).is_err());
+        assert!(Formatter::try_new_with_territory("YYYY", &BROKEN).is_ok());
+
+        // Parse
+        assert_eq!(Date::parse("Wednesday, March 3, 2021", "DL").unwrap(), date);
+        assert_eq!(Date::parse("03/03/2021", "DS").unwrap(), date);
+        assert_eq!(
+            Formatter::try_new_with_territory("DS", &Territory::GERMANY)
+                .unwrap()
+                .parse::<_, Date>("03.03.21")
+                .unwrap(),
+            date
+        );
+        assert!(Date::parse("03/03/2021", "DL").is_err());
+    }
+
+    #[test]
+    fn test_date_suffix() {
+        let date = generate_date(2021, 3, 3);
+
+        // Format
+        assert_eq!(format!("{}", date.format("DDTH").unwrap()), "03RD");
+        assert_eq!(format!("{}", date.format("FMDDTH").unwrap()), "3RD");
+        assert_eq!(format!("{}", date.format("FMDdth").unwrap()), "3rd");
+        assert_eq!(format!("{}", date.format("ddTH").unwrap()), "03rd");
+        assert_eq!(format!("{}", date.format("DDSP").unwrap()), "THREE");
+        assert_eq!(format!("{}", date.format("DDSPTH").unwrap()), "THIRD");
+        assert_eq!(format!("{}", date.format("DdTHSP").unwrap()), "Third");
+        assert_eq!(
+            format!("{}", date.format("\"the\" ddspth \"of\" fmmonth").unwrap()),
+            "the third of march"
+        );
+        assert_eq!(
+            format!("{}", date.format("FMDDDTH MMSP YYYYSP").unwrap()),
+            "62ND THREE TWO THOUSAND TWENTY-ONE"
+        );
+        assert_eq!(
+            format!("{}", date.format("YEAR Year year").unwrap()),
+            "TWENTY TWENTY-ONE Twenty Twenty-One twenty twenty-one"
+        );
+        assert_eq!(
+            format!("{}", date.format("SYEAR").unwrap()),
+            " TWENTY TWENTY-ONE"
+        );
+        assert_eq!(
+            format!("{}", generate_date(-44, 3, 15).format("SYEAR").unwrap()),
+            "-FORTY-FOUR"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                generate_date(-44, 3, 15)
+                    .format("SYYYYSP YYYYTH SCCSPTH")
+                    .unwrap()
+            ),
+            "-FORTY-FOUR 0044TH -FIRST"
+        );
+        for (day, ordinal) in [
+            (1, "1st"),
+            (2, "2nd"),
+            (11, "11th"),
+            (12, "12th"),
+            (21, "21st"),
+            (22, "22nd"),
+            (23, "23rd"),
+            (31, "31st"),
+        ] {
+            assert_eq!(
+                format!("{}", generate_date(2021, 1, day).format("FMddth").unwrap()),
+                ordinal
+            );
+        }
+
+        // Parse
+        assert!(Date::parse("03RD", "DDTH").is_err());
+        assert!(Date::parse("THREE", "DDSP").is_err());
+        assert!(Date::parse("TWENTY TWENTY-ONE", "YEAR").is_err());
+    }
+
+    #[test]
+    fn test_date_roman_month() {
+        let date = generate_date(2021, 4, 12);
+
+        // Format
+        assert_eq!(
+            format!("{}", date.format("DD-RM-YYYY").unwrap()),
+            "12-IV  -2021"
+        );
+        assert_eq!(
+            format!("{}", date.format("FMDD-RM-YYYY").unwrap()),
+            "12-IV-2021"
+        );
+        assert_eq!(format!("{}", date.format("FMrm").unwrap()), "iv");
+        for (month, roman) in [(1, "I"), (8, "VIII"), (9, "IX"), (12, "XII")] {
+            assert_eq!(
+                format!("{}", generate_date(2021, month, 1).format("FMRM").unwrap()),
+                roman
+            );
+        }
+
+        // Parse
+        assert_eq!(Date::parse("12-IV-2021", "DD-RM-YYYY").unwrap(), date);
+        assert_eq!(Date::parse("12-iv-2021", "DD-RM-YYYY").unwrap(), date);
+        assert_eq!(Date::parse("12-Iv-2021", "DD-rm-YYYY").unwrap(), date);
+        assert_eq!(Date::parse("12-IV  -2021", "FXDD-RM-YYYY").unwrap(), date);
+        assert_eq!(Date::parse("12-IV-2021", "FXDD-FMRM-YYYY").unwrap(), date);
+        assert!(Date::parse("12-IV-2021", "FXDD-RM-YYYY").is_err());
+        for (month, roman) in [(3, "III"), (7, "VII"), (8, "VIII"), (11, "XI"), (12, "XII")] {
+            assert_eq!(
+                Date::parse(format!("{} 2021", roman), "RM YYYY").unwrap(),
+                generate_date(2021, month, 1)
+            );
+        }
+        assert!(Date::parse("XIII 2021", "RM YYYY").is_err());
+        assert!(Date::parse("M 2021", "RM YYYY").is_err());
+        assert!(Date::parse("IV 04 2021", "RM MM YYYY").is_err());
+    }
+
+    #[test]
+    fn test_date_iso_week() {
+        let date = generate_date(2021, 1, 3);
+
+        // Format
+        assert_eq!(
+            format!("{}", date.format(r#"IYYY-"W"IW-D"#).unwrap()),
+            "2020-W53-1"
+        );
+        assert_eq!(
+            format!("{}", date.format("IYY IY I YYYY WW").unwrap()),
+            "020 20 0 2021 01"
+        );
+        assert_eq!(
+            format!("{}", generate_date(2024, 12, 30).format("IYYY IW").unwrap()),
+            "2025 01"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                generate_date(2024, 12, 30).format("FMIYYY IW").unwrap()
+            ),
+            "2025 1"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                generate_date(2023, 8, 15).format(r#"YYYY"Q"Q"#).unwrap()
+            ),
+            "2023Q3"
+        );
+        assert_eq!(
+            format!("{}", generate_date(2023, 12, 31).format("Q").unwrap()),
+            "4"
+        );
+        assert_eq!(
+            format!("{}", generate_date(2023, 1, 1).format("Q").unwrap()),
+            "1"
+        );
+
+        // Parse
+        assert_eq!(Date::parse("2020-W53-1", r#"IYYY-"W"IW-D"#).unwrap(), date);
+        assert_eq!(
+            Date::parse("2025-W01", r#"IYYY-"W"IW"#).unwrap(),
+            generate_date(2024, 12, 30)
+        );
+        assert_eq!(
+            Date::parse("2009 53 1", "IYYY IW D").unwrap(),
+            generate_date(2010, 1, 3)
+        );
+        assert_eq!(
+            Date::parse("2023", "IYYY").unwrap(),
+            generate_date(2023, 1, 2)
+        );
+        assert!(Date::parse("2021 53", "IYYY IW").is_err());
+        assert!(Date::parse("2021 54", "IYYY IW").is_err());
+        assert!(Date::parse("2021 00", "IYYY IW").is_err());
+        assert!(Date::parse("2021 01 01", "IYYY IW MM").is_err());
+        assert!(Date::parse("2021 01", "YYYY IW").is_err());
+        assert!(Date::parse("2021 01 01", "IYYY IW IW").is_err());
+        assert!(Date::parse("2023Q3", r#"YYYY"Q"Q"#).is_err());
+        let mut s = String::new();
+        let time = Time::try_from_hms(1, 2, 3, 0).unwrap();
+        assert!(Formatter::try_new("IW")
+            .unwrap()
+            .format(time, &mut s)
+            .is_err());
+    }
+
+    #[test]
+    fn test_date_literal() {
+        let date = generate_date(2023, 3, 9);
+
+        // Format
+        assert_eq!(
+            format!("{}", date.format(r#"YYYY"年"MM"月"DD"日""#).unwrap()),
+            "2023年03月09日"
+        );
+        assert_eq!(
+            format!("{}", date.format(r#""Week "WW", day "DDD"#).unwrap()),
+            "Week 10, day 068"
+        );
+        assert_eq!(
+            format!("{}", date.format(r#""YYYY \"MM\\" YYYY"#).unwrap()),
+            r#"YYYY "MM\ 2023"#
+        );
+        assert_eq!(
+            format!("{}", date.format(r#"\"YYYY\""#).unwrap()),
+            r#""2023""#
+        );
+        assert!(date.format(r#"YYYY "MM"#).is_err());
+
+        // Parse
+        assert_eq!(
+            Date::parse("2023年03月09日", r#"YYYY"年"MM"月"DD"日""#).unwrap(),
+            date
+        );
+        assert_eq!(
+            Date::parse("2023 day 068", r#"YYYY" day "DDD"#).unwrap(),
+            date
+        );
+        assert_eq!(
+            Date::parse("2023DAY068", r#"YYYY" day "DDD"#).unwrap(),
+            date
+        );
+        assert_eq!(
+            Date::parse("2023  Day   068", r#"YYYY" day "DDD"#).unwrap(),
+            date
+        );
+        assert_eq!(
+            Date::parse("2023-03-09T", r#"YYYY-MM-DD"T""#).unwrap(),
+            date
+        );
+        assert!(Date::parse("2023 dya 068", r#"YYYY" day "DDD"#).is_err());
+        assert!(Date::parse("2023年03-09", r#"YYYY"年"MM"月"DD"#).is_err());
+        assert!(Date::parse("2023", r#"YYYY "MM"#).is_err());
+
+        // Exact matching
+        assert_eq!(
+            Date::parse("2023 DAY 068", r#"FXYYYY" day "DDD"#).unwrap(),
+            date
+        );
+        assert!(Date::parse("2023day068", r#"FXYYYY" day "DDD"#).is_err());
+        assert!(Date::parse("2023  day 068", r#"FXYYYY" day "DDD"#).is_err());
+    }
+
     fn generate_ts(
         year: i32,
         month: u32,
@@ -1028,7 +1823,7 @@ mod tests {
     #[test]
     fn test_add_sub_days() {
         let upper_date = Date::try_from_ymd(9999, 12, 31).unwrap();
-        let lower_date = Date::try_from_ymd(1, 1, 1).unwrap();
+        let lower_date = Date::try_from_ymd(-4712, 1, 1).unwrap();
 
         // Out of range
         assert!(lower_date.add_days(i32::MAX).is_err());
@@ -1050,7 +1845,7 @@ mod tests {
         );
         assert_eq!(
             lower_date.add_days(366).unwrap(),
-            Date::try_from_ymd(2, 1, 2).unwrap()
+            Date::try_from_ymd(-4711, 1, 2).unwrap()
         );
 
         let date = Date::try_from_ymd(5000, 6, 15).unwrap();
@@ -1140,9 +1935,9 @@ mod tests {
         let interval = IntervalDT::try_from_dhms(12345, 12, 3, 5, 6).unwrap();
         assert!(date.add_interval_dt(interval).is_err());
 
-        let date = generate_date(1, 1, 1);
+        let date = generate_date(-4712, 1, 1);
         let interval = IntervalDT::try_from_dhms(5, 4, 3, 2, 1).unwrap();
-        let expect = generate_ts(1, 1, 6, 4, 3, 2, 1);
+        let expect = generate_ts(-4712, 1, 6, 4, 3, 2, 1);
         assert_eq!(date.add_interval_dt(interval).unwrap(), expect);
 
         let interval = IntervalDT::try_from_dhms(0, 0, 0, 0, 1).unwrap();
@@ -1228,7 +2023,7 @@ mod tests {
 
         // Boundary test
         let upper_date = generate_date(9999, 12, 31);
-        let lower_date = generate_date(1, 1, 1);
+        let lower_date = generate_date(-4712, 1, 1);
         let interval = IntervalYM::try_from_ym(0, 1).unwrap();
 
         assert!(upper_date.add_interval_ym(interval).is_err());
@@ -1298,7 +2093,7 @@ mod tests {
         );
 
         // Out of range
-        assert!(Date::try_from_ymd(1, 1, 1)
+        assert!(Date::try_from_ymd(-4712, 1, 1)
             .unwrap()
             .sub_time(Time::try_from_hms(12, 34, 56, 999999).unwrap())
             .is_err());
@@ -1376,7 +2171,7 @@ mod tests {
     fn test_now() {
         let now = Local::now().naive_local();
         let dt = Date::now().unwrap();
-        assert_eq!(now.year() as i32, dt.year().unwrap());
+        assert_eq!(now.year(), dt.year().unwrap());
         assert_eq!(now.month() as i32, dt.month().unwrap());
         assert_eq!(now.day() as i32, dt.day().unwrap());
     }
@@ -1,6 +1,7 @@
 //! Error definitions.
 
 use std::collections::TryReserveError;
+use std::fmt;
 use thiserror::Error;
 
 /// A type alias for `Result<T, Error>`.
@@ -9,7 +10,7 @@ pub type Result<T> = std::result::Result<T, Error>;
 /// An error that can be returned when uses date/time types.
 #[derive(Error, Debug, Clone, PartialEq, Eq)]
 pub enum Error {
-    #[error("(full) year must be between 1 and 9999")]
+    #[error("(full) year must be between -4712 and +9999, and not be 0")]
     DateOutOfRange,
     #[error("(full) hour must be between 0 and 23")]
     TimeOutOfRange,
@@ -33,24 +34,220 @@ pub enum Error {
     NumericOverflow,
     #[error("divisor is equal to zero")]
     DivideByZero,
+    #[error("hour must be between 1 and 12")]
+    InvalidHour12,
+    #[error("julian date must be between 404 and 5373484")]
+    InvalidJulianDate,
+    #[error("seconds in day must be between 0 and 86399")]
+    InvalidSecondsInDay,
+    #[error("day of year must be between 1 and 365 (366 for leap year)")]
+    InvalidDayOfYear,
+    #[error("not a valid day of the week")]
+    InvalidDayOfWeek,
+    #[error("week of year must be between {0} and 53")]
+    InvalidWeekOfYear(u32),
+    #[error("week of year is out of range for the year")]
+    WeekNotInYear,
+    #[error("a non-numeric character was found where a numeric was expected")]
+    NonNumeric,
+    #[error("the numeric value does not match the length of the format item")]
+    NumberLengthMismatch,
+    #[error("literal does not match format string")]
+    LiteralMismatch,
+    #[error("input value not long enough for date format")]
+    InputTooShort,
+    #[error("format picture ends before converting entire input string")]
+    TrailingInput,
+    #[error("AM/A.M. or PM/P.M. required")]
+    AmPmRequired,
+    #[error("BC/B.C. or AD/A.D. required")]
+    EraRequired,
+    #[error("'HH24' precludes use of meridian indicator")]
+    HourPrecludesMeridian,
+    #[error("signed year precludes use of BC/AD")]
+    SignedYearPrecludesEra,
+    #[error("format code ({0}) appears twice")]
+    DuplicateField(DateTimeField),
+    #[error("format code ({0}) cannot appear in date input format")]
+    UnsupportedField(DateTimeField),
+    #[error("{0} conflicts with Julian date")]
+    ConflictsWithJulian(DateTimeField),
+    #[error("{0} conflicts with seconds in day")]
+    ConflictsWithSecondsInDay(DateTimeField),
+    #[error("invalid combination of date conventions")]
+    ConflictingConventions,
+    #[error("not a valid {0} literal")]
+    InvalidLiteral(&'static str),
+    #[error("{0} fields are not allowed in a {1} interval")]
+    MixedIntervalFields(&'static str, &'static str),
+    #[error("date format not recognized")]
+    InvalidFormat,
+    #[error("an error occurred when formatting an argument")]
+    FormatError,
     #[error("{0}")]
-    InvalidFormat(String),
-    #[error("{0}")]
-    FormatError(String),
-    #[error("{0}")]
-    ParseError(String),
+    InvalidInput(InputError),
     #[error("{0}")]
     TryReserveError(TryReserveError),
 }
 
-impl From<std::fmt::Error> for Error {
+impl Error {
+    /// Gets the error without where it is found in the input, i.e. the error of the field
+    /// for `Error::InvalidInput` and the error itself otherwise.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use sqldatetime::{Date, Error};
+    ///
+    /// let e = Date::parse("2021-13-01", "YYYY-MM-DD").unwrap_err();
+    /// assert!(matches!(e, Error::InvalidInput(_)));
+    /// assert_eq!(e.kind(), Error::InvalidMonth);
+    /// ```
     #[inline]
-    fn from(e: std::fmt::Error) -> Self {
-        match try_format!("{}", e) {
-            Ok(s) => Error::FormatError(s),
-            Err(e) => e,
+    pub fn kind(&self) -> Error {
+        match self {
+            Error::InvalidInput(e) => e.error(),
+            e => e.clone(),
+        }
+    }
+
+    /// Gets the number of the Oracle error, e.g. 1843 for `ORA-01843: not a valid month`.
+    ///
+    /// Errors which Oracle does not raise get the number of the closest Oracle error.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use sqldatetime::{Date, Error};
+    ///
+    /// let e = Date::parse("2021-13-01", "YYYY-MM-DD").unwrap_err();
+    /// assert_eq!(e.kind(), Error::InvalidMonth);
+    /// assert_eq!(format!("ORA-{:05}", e.oracle_code()), "ORA-01843");
+    /// ```
+    pub fn oracle_code(&self) -> u32 {
+        match self {
+            Error::DateOutOfRange => 1841,
+            Error::TimeOutOfRange => 1850,
+            Error::IntervalOutOfRange => 1873,
+            Error::InvalidNumber => 1722,
+            Error::InvalidMonth => 1843,
+            Error::InvalidDay => 1847,
+            Error::InvalidMinute => 1851,
+            Error::InvalidSecond => 1852,
+            Error::InvalidFraction => 1880,
+            Error::InvalidDate => 1839,
+            Error::NumericOverflow => 1426,
+            Error::DivideByZero => 1476,
+            Error::InvalidHour12 => 1849,
+            Error::InvalidJulianDate => 1854,
+            Error::InvalidSecondsInDay => 1853,
+            Error::InvalidDayOfYear => 1848,
+            Error::InvalidDayOfWeek => 1846,
+            Error::InvalidWeekOfYear(_) | Error::WeekNotInYear => 1860,
+            Error::NonNumeric => 1858,
+            Error::NumberLengthMismatch => 1862,
+            Error::LiteralMismatch | Error::InvalidLiteral(_) => 1861,
+            Error::InputTooShort => 1840,
+            Error::TrailingInput => 1830,
+            Error::AmPmRequired => 1855,
+            Error::EraRequired => 1856,
+            Error::HourPrecludesMeridian => 1818,
+            Error::SignedYearPrecludesEra => 1819,
+            Error::DuplicateField(field) => match field {
+                DateTimeField::Year => 1812,
+                DateTimeField::Hour => 1813,
+                DateTimeField::Month => 1816,
+                DateTimeField::DayOfWeek => 1817,
+                _ => 1810,
+            },
+            // Oracle rejects the ISO week date fields in the input format
+            Error::UnsupportedField(_) | Error::ConflictingConventions => 1820,
+            Error::ConflictsWithJulian(field) => match field {
+                DateTimeField::Year => 1831,
+                DateTimeField::DayOfYear => 1832,
+                DateTimeField::Month => 1833,
+                DateTimeField::Day => 1834,
+                _ => 1835,
+            },
+            Error::ConflictsWithSecondsInDay(field) => match field {
+                DateTimeField::Hour => 1836,
+                DateTimeField::Minute => 1837,
+                _ => 1838,
+            },
+            Error::MixedIntervalFields(..) => 1867,
+            Error::InvalidFormat => 1821,
+            Error::FormatError => 1877,
+            Error::InvalidInput(e) => e.error().oracle_code(),
+            Error::TryReserveError(_) => 4030,
         }
     }
+
+    /// Gets the PostgreSQL SQLSTATE, e.g. "22008" (datetime_field_overflow)
+    /// for a field out of range, or "22007" (invalid_datetime_format) for an input
+    /// which does not match the format.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use sqldatetime::Date;
+    ///
+    /// let e = Date::parse("2021-13-01", "YYYY-MM-DD").unwrap_err();
+    /// assert_eq!(e.sqlstate(), "22008");
+    /// let e = Date::parse("2021-10-01 12", "YYYY-MM-DD").unwrap_err();
+    /// assert_eq!(e.sqlstate(), "22007");
+    /// ```
+    pub fn sqlstate(&self) -> &'static str {
+        match self {
+            Error::DateOutOfRange
+            | Error::TimeOutOfRange
+            | Error::InvalidMonth
+            | Error::InvalidDay
+            | Error::InvalidMinute
+            | Error::InvalidSecond
+            | Error::InvalidFraction
+            | Error::InvalidDate
+            | Error::InvalidHour12
+            | Error::InvalidJulianDate
+            | Error::InvalidSecondsInDay
+            | Error::InvalidDayOfYear
+            | Error::InvalidWeekOfYear(_)
+            | Error::WeekNotInYear => "22008",
+            Error::IntervalOutOfRange => "22015",
+            Error::InvalidNumber => "22P02",
+            Error::NumericOverflow => "22003",
+            Error::DivideByZero => "22012",
+            Error::InvalidDayOfWeek
+            | Error::NonNumeric
+            | Error::NumberLengthMismatch
+            | Error::LiteralMismatch
+            | Error::InputTooShort
+            | Error::TrailingInput
+            | Error::AmPmRequired
+            | Error::EraRequired
+            | Error::HourPrecludesMeridian
+            | Error::SignedYearPrecludesEra
+            | Error::DuplicateField(_)
+            | Error::ConflictsWithJulian(_)
+            | Error::ConflictsWithSecondsInDay(_)
+            | Error::ConflictingConventions
+            | Error::InvalidLiteral(_)
+            | Error::MixedIntervalFields(..)
+            | Error::InvalidFormat => "22007",
+            // PostgreSQL only supports the time zone fields in `to_char`
+            Error::UnsupportedField(DateTimeField::TimeZone) => "0A000",
+            Error::UnsupportedField(_) => "22007",
+            Error::FormatError => "22001",
+            Error::InvalidInput(e) => e.error().sqlstate(),
+            Error::TryReserveError(_) => "53200",
+        }
+    }
+}
+
+impl From<std::fmt::Error> for Error {
+    #[inline]
+    fn from(_: std::fmt::Error) -> Self {
+        Error::FormatError
+    }
 }
 
 impl From<TryReserveError> for Error {
@@ -59,3 +256,293 @@ impl From<TryReserveError> for Error {
         Error::TryReserveError(e)
     }
 }
+
+/// A field of date and time named by an error, e.g. the year in `Error::DuplicateField`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateTimeField {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    Fraction,
+    Era,
+    Meridian,
+    DayOfWeek,
+    DayOfYear,
+    WeekOfMonth,
+    WeekOfYear,
+    WeekYear,
+    Quarter,
+    IsoWeek,
+    IsoYear,
+    Julian,
+    SecondsInDay,
+    UnixTime,
+    Century,
+    Suffix,
+    SpelledYear,
+    TimeZone,
+}
+
+impl DateTimeField {
+    /// Gets the name of the field as Oracle words it, e.g. "day of month".
+    #[inline]
+    pub const fn name(self) -> &'static str {
+        match self {
+            DateTimeField::Year => "year",
+            DateTimeField::Month => "month",
+            DateTimeField::Day => "day of month",
+            DateTimeField::Hour => "hour",
+            DateTimeField::Minute => "minute",
+            DateTimeField::Second => "second",
+            DateTimeField::Fraction => "fraction",
+            DateTimeField::Era => "BC/AD",
+            DateTimeField::Meridian => "am/pm",
+            DateTimeField::DayOfWeek => "day of week",
+            DateTimeField::DayOfYear => "day of year",
+            DateTimeField::WeekOfMonth => "week of month",
+            DateTimeField::WeekOfYear => "week of year",
+            DateTimeField::WeekYear => "week year",
+            DateTimeField::Quarter => "quarter",
+            DateTimeField::IsoWeek => "ISO week",
+            DateTimeField::IsoYear => "ISO year",
+            DateTimeField::Julian => "julian date",
+            DateTimeField::SecondsInDay => "seconds in day",
+            DateTimeField::UnixTime => "seconds since epoch",
+            DateTimeField::Century => "century",
+            DateTimeField::Suffix => "suffix",
+            DateTimeField::SpelledYear => "spelled year",
+            DateTimeField::TimeZone => "time zone",
+        }
+    }
+}
+
+impl fmt::Display for DateTimeField {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A field of the format being matched when an input error is found, see [`InputError::field`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputField {
+    /// A field of date and time, e.g. the month of 'MM', 'MON' or 'RM'
+    DateTime(DateTimeField),
+    /// A separator, e.g. '-', or ' ' for blanks
+    Separator(char),
+    /// Literal text, e.g. quoted text
+    Literal,
+}
+
+impl fmt::Display for InputField {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputField::DateTime(field) => f.write_str(field.name()),
+            InputField::Separator(ch) => write!(f, "separator '{}'", ch),
+            InputField::Literal => f.write_str("literal text"),
+        }
+    }
+}
+
+/// Where and why an input does not match the format, see [`Error::InvalidInput`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputError {
+    error: FieldError,
+    position: usize,
+    field: Option<InputField>,
+    found: Option<char>,
+}
+
+impl InputError {
+    /// Locates the error of the `field` at the byte `position` of the input,
+    /// the errors which are not about the input, e.g. out of memory, are returned as is.
+    #[inline]
+    pub(crate) fn locate(
+        error: Error,
+        input: &str,
+        position: usize,
+        field: Option<InputField>,
+    ) -> Error {
+        let error = match FieldError::new(&error) {
+            Some(error) => error,
+            None => return error,
+        };
+        let found = match input.get(position..) {
+            Some(rest) => rest.chars().next(),
+            None => Some(char::REPLACEMENT_CHARACTER),
+        };
+        Error::InvalidInput(InputError {
+            error,
+            position,
+            field,
+            found,
+        })
+    }
+
+    /// Gets the error of the field, e.g. `Error::InvalidMonth`.
+    #[inline]
+    pub const fn error(&self) -> Error {
+        self.error.error()
+    }
+
+    /// Gets the byte offset in the input at which the field being matched starts,
+    /// or the input goes on after the format ends.
+    #[inline]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Gets the format field being matched, e.g. the month,
+    /// or `None` if the input goes on after the format ends.
+    #[inline]
+    pub const fn field(&self) -> Option<InputField> {
+        self.field
+    }
+
+    /// Gets the character found at the position, or `None` at the end of the input.
+    #[inline]
+    pub const fn found(&self) -> Option<char> {
+        self.found
+    }
+}
+
+impl fmt::Display for InputError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.error(), self.position)?;
+        if let Some(field) = self.field {
+            write!(f, " while matching the {}", field)?;
+        }
+        match self.found {
+            Some(ch) => write!(f, ", found '{}'", ch),
+            None => write!(f, ", found the end of the input"),
+        }
+    }
+}
+
+/// An error which can be located in the input, kept inline in `InputError`
+/// so that a rejected input does not allocate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FieldError {
+    DateOutOfRange,
+    TimeOutOfRange,
+    IntervalOutOfRange,
+    InvalidNumber,
+    InvalidMonth,
+    InvalidDay,
+    InvalidMinute,
+    InvalidSecond,
+    InvalidFraction,
+    InvalidDate,
+    NumericOverflow,
+    InvalidHour12,
+    InvalidJulianDate,
+    InvalidSecondsInDay,
+    InvalidDayOfYear,
+    InvalidDayOfWeek,
+    InvalidWeekOfYear(u32),
+    WeekNotInYear,
+    NonNumeric,
+    NumberLengthMismatch,
+    LiteralMismatch,
+    InputTooShort,
+    TrailingInput,
+    AmPmRequired,
+    EraRequired,
+    HourPrecludesMeridian,
+    SignedYearPrecludesEra,
+    DuplicateField(DateTimeField),
+    UnsupportedField(DateTimeField),
+    ConflictsWithJulian(DateTimeField),
+    ConflictsWithSecondsInDay(DateTimeField),
+    ConflictingConventions,
+}
+
+impl FieldError {
+    #[inline]
+    const fn new(error: &Error) -> Option<Self> {
+        Some(match *error {
+            Error::DateOutOfRange => FieldError::DateOutOfRange,
+            Error::TimeOutOfRange => FieldError::TimeOutOfRange,
+            Error::IntervalOutOfRange => FieldError::IntervalOutOfRange,
+            Error::InvalidNumber => FieldError::InvalidNumber,
+            Error::InvalidMonth => FieldError::InvalidMonth,
+            Error::InvalidDay => FieldError::InvalidDay,
+            Error::InvalidMinute => FieldError::InvalidMinute,
+            Error::InvalidSecond => FieldError::InvalidSecond,
+            Error::InvalidFraction => FieldError::InvalidFraction,
+            Error::InvalidDate => FieldError::InvalidDate,
+            Error::NumericOverflow => FieldError::NumericOverflow,
+            Error::InvalidHour12 => FieldError::InvalidHour12,
+            Error::InvalidJulianDate => FieldError::InvalidJulianDate,
+            Error::InvalidSecondsInDay => FieldError::InvalidSecondsInDay,
+            Error::InvalidDayOfYear => FieldError::InvalidDayOfYear,
+            Error::InvalidDayOfWeek => FieldError::InvalidDayOfWeek,
+            Error::InvalidWeekOfYear(min) => FieldError::InvalidWeekOfYear(min),
+            Error::WeekNotInYear => FieldError::WeekNotInYear,
+            Error::NonNumeric => FieldError::NonNumeric,
+            Error::NumberLengthMismatch => FieldError::NumberLengthMismatch,
+            Error::LiteralMismatch => FieldError::LiteralMismatch,
+            Error::InputTooShort => FieldError::InputTooShort,
+            Error::TrailingInput => FieldError::TrailingInput,
+            Error::AmPmRequired => FieldError::AmPmRequired,
+            Error::EraRequired => FieldError::EraRequired,
+            Error::HourPrecludesMeridian => FieldError::HourPrecludesMeridian,
+            Error::SignedYearPrecludesEra => FieldError::SignedYearPrecludesEra,
+            Error::DuplicateField(field) => FieldError::DuplicateField(field),
+            Error::UnsupportedField(field) => FieldError::UnsupportedField(field),
+            Error::ConflictsWithJulian(field) => FieldError::ConflictsWithJulian(field),
+            Error::ConflictsWithSecondsInDay(field) => FieldError::ConflictsWithSecondsInDay(field),
+            Error::ConflictingConventions => FieldError::ConflictingConventions,
+            Error::DivideByZero
+            | Error::InvalidLiteral(_)
+            | Error::MixedIntervalFields(..)
+            | Error::InvalidFormat
+            | Error::FormatError
+            | Error::InvalidInput(_)
+            | Error::TryReserveError(_) => return None,
+        })
+    }
+
+    #[inline]
+    const fn error(self) -> Error {
+        match self {
+            FieldError::DateOutOfRange => Error::DateOutOfRange,
+            FieldError::TimeOutOfRange => Error::TimeOutOfRange,
+            FieldError::IntervalOutOfRange => Error::IntervalOutOfRange,
+            FieldError::InvalidNumber => Error::InvalidNumber,
+            FieldError::InvalidMonth => Error::InvalidMonth,
+            FieldError::InvalidDay => Error::InvalidDay,
+            FieldError::InvalidMinute => Error::InvalidMinute,
+            FieldError::InvalidSecond => Error::InvalidSecond,
+            FieldError::InvalidFraction => Error::InvalidFraction,
+            FieldError::InvalidDate => Error::InvalidDate,
+            FieldError::NumericOverflow => Error::NumericOverflow,
+            FieldError::InvalidHour12 => Error::InvalidHour12,
+            FieldError::InvalidJulianDate => Error::InvalidJulianDate,
+            FieldError::InvalidSecondsInDay => Error::InvalidSecondsInDay,
+            FieldError::InvalidDayOfYear => Error::InvalidDayOfYear,
+            FieldError::InvalidDayOfWeek => Error::InvalidDayOfWeek,
+            FieldError::InvalidWeekOfYear(min) => Error::InvalidWeekOfYear(min),
+            FieldError::WeekNotInYear => Error::WeekNotInYear,
+            FieldError::NonNumeric => Error::NonNumeric,
+            FieldError::NumberLengthMismatch => Error::NumberLengthMismatch,
+            FieldError::LiteralMismatch => Error::LiteralMismatch,
+            FieldError::InputTooShort => Error::InputTooShort,
+            FieldError::TrailingInput => Error::TrailingInput,
+            FieldError::AmPmRequired => Error::AmPmRequired,
+            FieldError::EraRequired => Error::EraRequired,
+            FieldError::HourPrecludesMeridian => Error::HourPrecludesMeridian,
+            FieldError::SignedYearPrecludesEra => Error::SignedYearPrecludesEra,
+            FieldError::DuplicateField(field) => Error::DuplicateField(field),
+            FieldError::UnsupportedField(field) => Error::UnsupportedField(field),
+            FieldError::ConflictsWithJulian(field) => Error::ConflictsWithJulian(field),
+            FieldError::ConflictsWithSecondsInDay(field) => Error::ConflictsWithSecondsInDay(field),
+            FieldError::ConflictingConventions => Error::ConflictingConventions,
+        }
+    }
+}
@@ -1,9 +1,14 @@
 //! Formatting (and parsing) utilities for date and time.
 
-use crate::common::{is_leap_year, the_day_of_year, the_month_day_of_days, DATE_MIN_YEAR};
+use crate::common::{
+    add_years, date2julian, is_leap_year, julian2date, the_day_of_year, the_month_day_of_days,
+    DATE_MAX_JULIAN, DATE_MIN_JULIAN, DATE_MIN_YEAR, MINUTES_PER_HOUR, SECONDS_PER_MINUTE,
+    UNIX_EPOCH_JULIAN,
+};
 use crate::date::{Month, WeekDay};
-use crate::error::Result;
-use crate::format::NameStyle::{AbbrCapital, Capital};
+use crate::error::{DateTimeField, InputError, InputField, Result};
+use crate::literal::mysql_year;
+use crate::locale::{Locale, Territory};
 use crate::util::StrExt;
 use crate::{Date, DateTime, Error, IntervalDT, IntervalYM, Time, Timestamp};
 use chrono::{Datelike, Local};
@@ -11,7 +16,8 @@ use stack_buf::StackVec;
 use std::convert::TryFrom;
 use std::fmt;
 
-const MAX_FIELDS: usize = 36;
+/// The number of fields stored inline, longer format models are stored on the heap.
+const INLINE_FIELDS: usize = 36;
 
 const FRACTION_FACTOR: [f64; 10] = [
     1000000.0, 100000.0, 10000.0, 1000.0, 100.0, 10.0, 1.0, 0.1, 0.01, 0.001,
@@ -19,6 +25,9 @@ const FRACTION_FACTOR: [f64; 10] = [
 
 const YEAR_MODIFIER: [u32; 4] = [10, 100, 1000, 10000];
 
+const SECONDS_PER_DAY: u32 = 86_400;
+const SECONDS_PER_HOUR: u32 = 3_600;
+
 const MONTH_TABLE: [&str; 13] = [
     "00", "01", "02", "03", "04", "05", "06", "07", "08", "09", "10", "11", "12",
 ];
@@ -40,93 +49,94 @@ const MINUTE_SECOND_TABLE: [&str; 61] = [
     "48", "49", "50", "51", "52", "53", "54", "55", "56", "57", "58", "59", "60",
 ];
 
-const MONTH_NAME_TABLE: [[&str; 12]; 6] = [
-    [
-        "January",
-        "February",
-        "March",
-        "April",
-        "May",
-        "June",
-        "July",
-        "August",
-        "September",
-        "October",
-        "November",
-        "December",
-    ],
-    [
-        "january",
-        "february",
-        "march",
-        "april",
-        "may",
-        "june",
-        "july",
-        "august",
-        "september",
-        "october",
-        "november",
-        "december",
-    ],
+const ROMAN_MONTH_TABLE: [[&str; 12]; 2] = [
     [
-        "JANUARY",
-        "FEBRUARY",
-        "MARCH",
-        "APRIL",
-        "MAY",
-        "JUNE",
-        "JULY",
-        "AUGUST",
-        "SEPTEMBER",
-        "OCTOBER",
-        "NOVEMBER",
-        "DECEMBER",
+        "I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX", "X", "XI", "XII",
     ],
     [
-        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+        "i", "ii", "iii", "iv", "v", "vi", "vii", "viii", "ix", "x", "xi", "xii",
     ],
+];
+
+const SPELLED_ONES_TABLE: [[&str; 20]; 2] = [
     [
-        "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+        "zero",
+        "one",
+        "two",
+        "three",
+        "four",
+        "five",
+        "six",
+        "seven",
+        "eight",
+        "nine",
+        "ten",
+        "eleven",
+        "twelve",
+        "thirteen",
+        "fourteen",
+        "fifteen",
+        "sixteen",
+        "seventeen",
+        "eighteen",
+        "nineteen",
     ],
     [
-        "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+        "zeroth",
+        "first",
+        "second",
+        "third",
+        "fourth",
+        "fifth",
+        "sixth",
+        "seventh",
+        "eighth",
+        "ninth",
+        "tenth",
+        "eleventh",
+        "twelfth",
+        "thirteenth",
+        "fourteenth",
+        "fifteenth",
+        "sixteenth",
+        "seventeenth",
+        "eighteenth",
+        "nineteenth",
     ],
 ];
 
-const DAY_NAME_TABLE: [[&str; 7]; 6] = [
-    [
-        "Sunday",
-        "Monday",
-        "Tuesday",
-        "Wednesday",
-        "Thursday",
-        "Friday",
-        "Saturday",
-    ],
+const SPELLED_TENS_TABLE: [[&str; 10]; 2] = [
     [
-        "sunday",
-        "monday",
-        "tuesday",
-        "wednesday",
-        "thursday",
-        "friday",
-        "saturday",
+        "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
     ],
     [
-        "SUNDAY",
-        "MONDAY",
-        "TUESDAY",
-        "WEDNESDAY",
-        "THURSDAY",
-        "FRIDAY",
-        "SATURDAY",
+        "",
+        "",
+        "twentieth",
+        "thirtieth",
+        "fortieth",
+        "fiftieth",
+        "sixtieth",
+        "seventieth",
+        "eightieth",
+        "ninetieth",
     ],
-    ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"],
-    ["sun", "mon", "tue", "wed", "thu", "fri", "sat"],
-    ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"],
 ];
 
+const SPELLED_SCALE_TABLE: [[&str; 3]; 2] = [
+    ["hundred", "thousand", "million"],
+    ["hundredth", "thousandth", "millionth"],
+];
+
+const ORDINAL_SUFFIX_TABLE: [[&str; 4]; 2] = [["th", "st", "nd", "rd"], ["TH", "ST", "ND", "RD"]];
+
+/// The maximum number of words and separators of a spelled number,
+/// e.g. 'five million three hundred seventy-three thousand four hundred eighty-four'
+const SPELLED_MAX_WORDS: usize = 32;
+
+/// The length of the longest Roman numeral month, which Roman numeral months are padded to
+const ROMAN_MONTH_MAX_LENGTH: usize = 4;
+
 const DAY_OF_WEEK_TABLE: [&str; 8] = ["0", "1", "2", "3", "4", "5", "6", "7"];
 
 #[rustfmt::skip]
@@ -413,7 +423,7 @@ impl NaiveDateTime {
                 }
             };
 
-            self.hour = hour24 as u32;
+            self.hour = hour24;
         }
     }
 
@@ -448,18 +458,16 @@ impl NaiveDateTime {
     }
 
     #[inline]
-    pub fn month_name(&self, style: NameStyle) -> &str {
-        Month::from(self.month as usize).name(style)
+    pub fn roman_month(&self, style: NameStyle) -> &str {
+        Month::from(self.month as usize).roman(style)
     }
 
     #[inline]
-    pub fn week_day_name(&self, date: Option<Date>, style: NameStyle) -> Result<&str> {
+    pub fn week_day(&self, date: Option<Date>) -> Result<WeekDay> {
         if let Some(d) = date {
-            Ok(d.day_of_week().name(style))
+            Ok(d.day_of_week())
         } else {
-            Ok(Date::try_from_ymd(self.year, self.month, self.day)?
-                .day_of_week()
-                .name(style))
+            Ok(Date::try_from_ymd(self.year, self.month, self.day)?.day_of_week())
         }
     }
 
@@ -489,14 +497,101 @@ impl NaiveDateTime {
     pub const fn week_of_year_str(&self) -> &str {
         WEEK_OF_YEAR_TABLE[the_day_of_year(self.year, self.month, self.day) as usize]
     }
-}
 
-impl WeekDay {
-    #[inline(always)]
-    pub(crate) fn name(self, style: NameStyle) -> &'static str {
-        DAY_NAME_TABLE[style as usize][self as usize - 1]
+    /// Gets the week of year in which weeks start on `first_day`,
+    /// the days before the first `first_day` of the year are in week 0.
+    #[inline]
+    pub fn week_of_year_from(&self, date: Option<Date>, first_day: WeekDay) -> Result<u32> {
+        let days = the_day_of_year(self.year, self.month, self.day) - 1;
+        let offset = (self.week_day(date)? as u32 + 7 - first_day as u32) % 7;
+        Ok((days + 7 - offset) / 7)
+    }
+
+    /// Gets the week of year in which weeks start on Monday and week 1 is the first week with 4 or more days
+    /// in the year, the days before week 1 are in week 0.
+    #[inline]
+    pub fn four_day_week(&self, date: Option<Date>) -> Result<u32> {
+        let days = the_day_of_year(self.year, self.month, self.day) - 1;
+        let offset = (self.week_day(date)? as u32 + 7 - WeekDay::Monday as u32) % 7;
+        // Days of January 1st from Monday
+        let first_offset = (offset + 7 - days % 7) % 7;
+        let first_week = if first_offset <= 3 { 7 } else { 0 };
+        Ok((days + first_offset + first_week) / 7)
+    }
+
+    /// Gets the year and week of year in which weeks start on Sunday,
+    /// the days before the first Sunday are in the last week of the previous year.
+    #[inline]
+    pub fn sunday_year_week(&self, date: Option<Date>) -> Result<(i32, u32)> {
+        let week = self.week_of_year_from(date, WeekDay::Sunday)?;
+        if week > 0 {
+            return Ok((self.year, week));
+        }
+
+        let last_day = NaiveDateTime {
+            year: add_years(self.year, -1),
+            month: 12,
+            day: 31,
+            ..NaiveDateTime::new()
+        };
+        Ok((
+            last_day.year,
+            last_day.week_of_year_from(None, WeekDay::Sunday)?,
+        ))
+    }
+
+    #[inline]
+    pub const fn julian(&self) -> i32 {
+        date2julian(self.year, self.month, self.day)
     }
 
+    #[inline]
+    pub const fn unix_time(&self) -> i64 {
+        (self.julian() - UNIX_EPOCH_JULIAN) as i64 * SECONDS_PER_DAY as i64
+            + self.seconds_past_midnight() as i64
+    }
+
+    #[inline]
+    pub const fn seconds_past_midnight(&self) -> u32 {
+        (self.hour * MINUTES_PER_HOUR + self.minute) * SECONDS_PER_MINUTE + self.sec
+    }
+
+    /// Gets the century, e.g. 2000 is in the 20th century and 2001 is in the 21st century.
+    #[inline]
+    pub const fn century(&self) -> i32 {
+        let century = (self.year.abs() + 99) / 100;
+        if self.year < 0 {
+            -century
+        } else {
+            century
+        }
+    }
+
+    #[inline]
+    pub const fn quarter(&self) -> u32 {
+        (self.month - 1) / 3 + 1
+    }
+
+    #[inline]
+    pub fn iso_year(&self, date: Option<Date>) -> Result<i32> {
+        if let Some(d) = date {
+            Ok(d.date_to_iso_year())
+        } else {
+            Ok(Date::try_from_ymd(self.year, self.month, self.day)?.date_to_iso_year())
+        }
+    }
+
+    #[inline]
+    pub fn iso_week(&self, date: Option<Date>) -> Result<u32> {
+        if let Some(d) = date {
+            Ok(d.iso_week())
+        } else {
+            Ok(Date::try_from_ymd(self.year, self.month, self.day)?.iso_week())
+        }
+    }
+}
+
+impl WeekDay {
     #[inline(always)]
     pub(crate) fn num_str(self) -> &'static str {
         DAY_OF_WEEK_TABLE[self as usize]
@@ -505,8 +600,51 @@ impl WeekDay {
 
 impl Month {
     #[inline(always)]
-    pub(crate) fn name(self, style: NameStyle) -> &'static str {
-        MONTH_NAME_TABLE[style as usize][self as usize - 1]
+    pub(crate) fn roman(self, style: NameStyle) -> &'static str {
+        ROMAN_MONTH_TABLE[(style == NameStyle::Lower) as usize][self as usize - 1]
+    }
+}
+
+/// The dialect of format models.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dialect {
+    /// Oracle `TO_CHAR`/`TO_DATE` format models, which is the default.
+    Oracle,
+    /// PostgreSQL `to_char`/`to_timestamp` template patterns.
+    ///
+    /// - Unrecognized characters are taken as literal text.
+    /// - 'FM' only affects the next field.
+    /// - 'MS' and 'US' are milliseconds and microseconds, 'SSSS' is seconds past midnight.
+    /// - 'OF' and 'TZ' are the time zone offset and abbreviation, which are '+00' and empty
+    ///   as date and time values have no time zone.
+    /// - The case of a 'TH' suffix follows the suffix itself, e.g. 'DDth' is '03rd'.
+    /// - When parsing, a separator or space matches any single non-alphanumeric character,
+    ///   literal text skips as many characters as it contains, and 'Q' is ignored,
+    ///   unless 'FX' is in effect for separators.
+    Postgres,
+    /// C `strftime`/`strptime` directives, e.g. '%Y-%m-%d %H:%M:%S.%f'.
+    ///
+    /// - Text other than directives is taken as literal text, '%%' is a literal '%'.
+    /// - '%f' and '%N' are microseconds and nanoseconds, '%s' is seconds since the Unix Epoch.
+    /// - When parsing, '%y' follows POSIX, i.e. 69-99 are in 1969-1999 and 00-68 are in 2000-2068.
+    /// - '%C', '%z' and '%Z' are not supported.
+    /// - The GNU flags '-', '_' and '0' change the padding of numbers,
+    ///   '^' writes names in upper case.
+    Strftime,
+    /// MySQL `DATE_FORMAT`/`STR_TO_DATE` specifiers, e.g. '%W %M %Y'.
+    ///
+    /// - '%x' is a literal 'x' for any 'x' that is not a specifier.
+    /// - '%i' is minutes, '%f' is microseconds and '%D' is the day with an English suffix, e.g. '3rd'.
+    /// - '%U', '%u', '%V' and '%v' are the weeks of the modes 0 to 3 of `WEEK()`,
+    ///   '%X' and '%x' are the years of the weeks of '%V' and '%v'.
+    /// - When parsing, two-digit years from 70 to 99 are in 1900s and the others are in 2000s.
+    MySql,
+}
+
+impl Default for Dialect {
+    #[inline]
+    fn default() -> Self {
+        Dialect::Oracle
     }
 }
 
@@ -561,9 +699,218 @@ pub enum Field {
     WeekOfMonth,
     /// 'WW'
     WeekOfYear,
+    /// 'FX'
+    FormatExact,
+    /// 'FM'
+    FillMode,
+    /// '"text"'
+    Literal(String),
+    /// 'RR', 'RRRR'
+    RoundYear(u8),
+    /// 'Q'
+    Quarter,
+    /// 'IW'
+    IsoWeek,
+    /// 'IYYY', 'IYY', 'IY', 'I'
+    IsoYear(u8),
+    /// 'J', the Julian day number of the proleptic Gregorian calendar
+    ///
+    /// Oracle switches to the Julian calendar before 1582-10-15, but dates of this crate are
+    /// proleptic Gregorian throughout, so `J` only agrees with Oracle from that day on. The
+    /// earliest date, 4712-01-01 BC, is Julian day 404; smaller numbers are rejected.
+    Julian,
+    /// 'SSSSS'
+    SecondsPastMidnight,
+    /// 'CC'
+    Century,
+    /// 'SCC'
+    SignedCentury,
+    /// 'SYYYY'
+    SignedYear,
+    /// 'Y,YYY'
+    CommaYear,
+    /// 'AD', 'ad', 'A.D.', 'a.d.', 'BC', 'bc', 'B.C.', 'b.c.'
+    Era(EraStyle),
+    /// 'RM', 'rm'
+    RomanMonth(NameStyle),
+    /// 'TH', 'SP', 'SPTH', 'THSP' following a numeric field
+    Suffix(Suffix, NameStyle),
+    /// 'YEAR'
+    YearName(NameStyle),
+    /// 'SYEAR'
+    SignedYearName(NameStyle),
+    /// 'DL', expanded to the long date format of the territory
+    LongDate,
+    /// 'DS', expanded to the short date format of the territory
+    ShortDate,
+    /// 'TS', expanded to the short time format of the territory
+    ShortTime,
+    /// 'OF'
+    TimeZoneOffset,
+    /// 'TZ', 'tz'
+    TimeZone,
+    /// Toggles padding numbers with spaces instead of zeros, for '%e', '%k', '%l' and the '_' flag
+    SpacePadding,
+    /// '%U', week of year in which weeks start on Sunday
+    SundayWeek,
+    /// '%W', week of year in which weeks start on Monday
+    MondayWeek,
+    /// '%u', day of week from 1 (Monday) to 7
+    IsoDayOfWeek,
+    /// '%w', day of week from 0 (Sunday) to 6
+    ZeroDayOfWeek,
+    /// '%s', seconds since the Unix Epoch
+    UnixTime,
+    /// MySQL '%u', week of year in which weeks start on Monday
+    /// and week 1 is the first week with 4 or more days in the year
+    FourDayWeek,
+    /// MySQL '%V', week of year in which weeks start on Sunday,
+    /// the days before the first Sunday are in the last week of the previous year
+    SundayYearWeek,
+    /// MySQL '%X', the year of the week of '%V'
+    SundayYear,
 }
 
-#[derive(Debug)]
+impl Field {
+    /// Checks if the field is a number, which may be followed by a suffix.
+    #[inline]
+    const fn is_numeric(&self) -> bool {
+        matches!(
+            self,
+            Field::Year(_)
+                | Field::RoundYear(_)
+                | Field::IsoYear(_)
+                | Field::SignedYear
+                | Field::CommaYear
+                | Field::Century
+                | Field::SignedCentury
+                | Field::Month
+                | Field::Day
+                | Field::Hour24
+                | Field::Hour12
+                | Field::Minute
+                | Field::Second
+                | Field::DayOfWeek
+                | Field::DayOfYear
+                | Field::WeekOfMonth
+                | Field::WeekOfYear
+                | Field::Quarter
+                | Field::IsoWeek
+                | Field::Julian
+                | Field::SecondsPastMidnight
+        )
+    }
+
+    /// Gets the field named in parse errors, regardless of the dialect,
+    /// or `None` for the modifiers which match no input.
+    #[inline]
+    const fn input_field(&self) -> Option<InputField> {
+        let field = match self {
+            Field::Invalid
+            | Field::FormatExact
+            | Field::FillMode
+            | Field::SpacePadding
+            | Field::LongDate
+            | Field::ShortDate
+            | Field::ShortTime => return None,
+            Field::Blank(_) => return Some(InputField::Separator(' ')),
+            Field::Hyphen => return Some(InputField::Separator('-')),
+            Field::Colon => return Some(InputField::Separator(':')),
+            Field::Slash => return Some(InputField::Separator('/')),
+            Field::Backslash => return Some(InputField::Separator('\\')),
+            Field::Comma => return Some(InputField::Separator(',')),
+            Field::Dot => return Some(InputField::Separator('.')),
+            Field::Semicolon => return Some(InputField::Separator(';')),
+            Field::T => return Some(InputField::Separator('T')),
+            Field::Literal(_) => return Some(InputField::Literal),
+            Field::Year(_) | Field::RoundYear(_) | Field::SignedYear | Field::CommaYear => {
+                DateTimeField::Year
+            }
+            Field::YearName(_) | Field::SignedYearName(_) => DateTimeField::SpelledYear,
+            Field::Month | Field::MonthName(_) | Field::RomanMonth(_) => DateTimeField::Month,
+            Field::Day => DateTimeField::Day,
+            Field::DayName(_) | Field::DayOfWeek | Field::IsoDayOfWeek | Field::ZeroDayOfWeek => {
+                DateTimeField::DayOfWeek
+            }
+            Field::DayOfYear => DateTimeField::DayOfYear,
+            Field::Hour24 | Field::Hour12 => DateTimeField::Hour,
+            Field::Minute => DateTimeField::Minute,
+            Field::Second => DateTimeField::Second,
+            Field::Fraction(_) => DateTimeField::Fraction,
+            Field::AmPm(_) => DateTimeField::Meridian,
+            Field::Era(_) => DateTimeField::Era,
+            Field::WeekOfMonth => DateTimeField::WeekOfMonth,
+            Field::WeekOfYear
+            | Field::SundayWeek
+            | Field::MondayWeek
+            | Field::FourDayWeek
+            | Field::SundayYearWeek => DateTimeField::WeekOfYear,
+            Field::SundayYear => DateTimeField::WeekYear,
+            Field::Quarter => DateTimeField::Quarter,
+            Field::IsoWeek => DateTimeField::IsoWeek,
+            Field::IsoYear(_) => DateTimeField::IsoYear,
+            Field::Julian => DateTimeField::Julian,
+            Field::SecondsPastMidnight => DateTimeField::SecondsInDay,
+            Field::Century | Field::SignedCentury => DateTimeField::Century,
+            Field::Suffix(..) => DateTimeField::Suffix,
+            Field::TimeZoneOffset | Field::TimeZone => DateTimeField::TimeZone,
+            Field::UnixTime => DateTimeField::UnixTime,
+        };
+        Some(InputField::DateTime(field))
+    }
+
+    /// Gets the value of a numeric field as it is formatted, which is signed for 'SYYYY' and 'SCC'.
+    #[inline]
+    fn number<T: DateTimeFormat>(&self, dt: &NaiveDateTime, date: Option<Date>) -> Result<i32> {
+        let number = match self {
+            Field::Year(n) | Field::RoundYear(n) if T::HAS_DATE => {
+                dt.year().abs() % (YEAR_MODIFIER[*n as usize - 1] as i32)
+            }
+            Field::Year(_) if T::IS_INTERVAL_YM => dt.year(),
+            Field::IsoYear(n) if T::HAS_DATE => {
+                dt.iso_year(date)?.abs() % (YEAR_MODIFIER[*n as usize - 1] as i32)
+            }
+            Field::SignedYear if T::HAS_DATE => dt.year(),
+            Field::CommaYear if T::HAS_DATE => dt.year().abs(),
+            Field::Century if T::HAS_DATE => dt.century().abs(),
+            Field::SignedCentury if T::HAS_DATE => dt.century(),
+            Field::Month if T::HAS_DATE || T::IS_INTERVAL_YM => dt.month() as i32,
+            Field::Day if T::HAS_DATE || T::IS_INTERVAL_DT => dt.day() as i32,
+            Field::Hour24 | Field::Minute | Field::Second if T::HAS_TIME => match self {
+                Field::Hour24 => dt.hour24() as i32,
+                Field::Minute => dt.minute() as i32,
+                _ => dt.sec() as i32,
+            },
+            Field::Hour12 if T::HAS_TIME && !T::IS_INTERVAL_DT => dt.hour12() as i32,
+            Field::DayOfWeek if T::HAS_DATE => dt.week_day(date)? as i32,
+            Field::DayOfYear if T::HAS_DATE => the_day_of_year(dt.year, dt.month, dt.day) as i32,
+            Field::WeekOfMonth if T::HAS_DATE => ((dt.day() - 1) / 7 + 1) as i32,
+            Field::WeekOfYear if T::HAS_DATE => {
+                ((the_day_of_year(dt.year, dt.month, dt.day) - 1) / 7 + 1) as i32
+            }
+            Field::Quarter if T::HAS_DATE => dt.quarter() as i32,
+            Field::IsoWeek if T::HAS_DATE => dt.iso_week(date)? as i32,
+            Field::Julian if T::HAS_DATE => dt.julian(),
+            Field::SecondsPastMidnight if T::HAS_TIME && !T::IS_INTERVAL_DT => {
+                dt.seconds_past_midnight() as i32
+            }
+            _ => return Err(Error::InvalidFormat),
+        };
+        Ok(number)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Suffix {
+    /// 'TH', e.g. '3RD'
+    Ordinal,
+    /// 'SP', e.g. 'THREE'
+    Spelled,
+    /// 'SPTH', 'THSP', e.g. 'THIRD'
+    SpelledOrdinal,
+}
+
+#[derive(Debug, Clone, Copy)]
 pub enum AmPm {
     Am,
     Pm,
@@ -579,30 +926,54 @@ pub enum AmPmStyle {
 
 impl AmPmStyle {
     #[inline]
-    const fn am(&self) -> &str {
+    const fn is_dot(&self) -> bool {
+        matches!(self, AmPmStyle::UpperDot | AmPmStyle::LowerDot)
+    }
+
+    #[inline]
+    const fn name_style(&self) -> NameStyle {
+        match self {
+            AmPmStyle::Upper | AmPmStyle::UpperDot => NameStyle::Upper,
+            AmPmStyle::Lower | AmPmStyle::LowerDot => NameStyle::Lower,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum EraStyle {
+    Upper,
+    Lower,
+    UpperDot,
+    LowerDot,
+}
+
+impl EraStyle {
+    #[inline]
+    const fn ad(&self) -> &str {
         match self {
-            AmPmStyle::Upper => "AM",
-            AmPmStyle::Lower => "am",
-            AmPmStyle::UpperDot => "A.M.",
-            AmPmStyle::LowerDot => "a.m.",
+            EraStyle::Upper => "AD",
+            EraStyle::Lower => "ad",
+            EraStyle::UpperDot => "A.D.",
+            EraStyle::LowerDot => "a.d.",
         }
     }
 
     #[inline]
-    const fn pm(&self) -> &str {
+    const fn bc(&self) -> &str {
         match self {
-            AmPmStyle::Upper => "PM",
-            AmPmStyle::Lower => "pm",
-            AmPmStyle::UpperDot => "P.M.",
-            AmPmStyle::LowerDot => "p.m.",
+            EraStyle::Upper => "BC",
+            EraStyle::Lower => "bc",
+            EraStyle::UpperDot => "B.C.",
+            EraStyle::LowerDot => "b.c.",
         }
     }
 
     #[inline]
-    const fn format(&self, hour: u32) -> &str {
-        match hour {
-            0..=11 => self.am(),
-            _ => self.pm(),
+    const fn format(&self, year: i32) -> &str {
+        if year < 0 {
+            self.bc()
+        } else {
+            self.ad()
         }
     }
 }
@@ -617,6 +988,24 @@ pub enum NameStyle {
     AbbrUpper = 5,
 }
 
+impl NameStyle {
+    #[inline]
+    const fn is_abbr(self) -> bool {
+        self as u8 >= NameStyle::AbbrCapital as u8
+    }
+
+    /// Gets the abbreviated style in the same case, e.g. 'MON' for 'MONTH'.
+    #[inline]
+    const fn abbr(self) -> NameStyle {
+        match self {
+            NameStyle::Capital => NameStyle::AbbrCapital,
+            NameStyle::Lower => NameStyle::AbbrLower,
+            NameStyle::Upper => NameStyle::AbbrUpper,
+            style => style,
+        }
+    }
+}
+
 trait CaseInsensitive {
     fn starts_with(&self, needle: &Self) -> bool;
 }
@@ -632,12 +1021,34 @@ impl CaseInsensitive for [u8] {
 pub struct FormatParser<'a> {
     input: &'a [u8],
     pos: usize,
+    dialect: Dialect,
+    // The case of the last numeric field, which a following suffix is written in
+    suffix_style: Option<NameStyle>,
+    // Whether a Postgres 'FM' prefix applies to the next field
+    fill_prefix: bool,
+    // The fields to be yielded before parsing the input further, in reverse order
+    pending: StackVec<Field, 3>,
+    // The input and position to resume from after a composite directive
+    resume: Option<(&'a [u8], usize)>,
 }
 
 impl<'a> FormatParser<'a> {
     #[inline]
     pub const fn new(input: &'a [u8]) -> Self {
-        FormatParser { input, pos: 0 }
+        FormatParser::with_dialect(input, Dialect::Oracle)
+    }
+
+    #[inline]
+    pub const fn with_dialect(input: &'a [u8], dialect: Dialect) -> Self {
+        FormatParser {
+            input,
+            pos: 0,
+            dialect,
+            suffix_style: None,
+            fill_prefix: false,
+            pending: StackVec::new(),
+            resume: None,
+        }
     }
 
     #[inline]
@@ -661,11 +1072,6 @@ impl<'a> FormatParser<'a> {
         self.pos += step;
     }
 
-    #[inline]
-    fn back(&mut self, step: usize) {
-        self.pos -= step;
-    }
-
     #[inline]
     fn remain(&self) -> Option<&[u8]> {
         if self.pos < self.input.len() {
@@ -675,343 +1081,382 @@ impl<'a> FormatParser<'a> {
         }
     }
 
+    /// Parses a suffix of a numeric field, 'TH' is not taken from 'THH' which is 'T' followed by 'HH'.
     #[inline]
-    fn punctuation_count(&mut self, expect: u8) -> u8 {
-        self.remain().map_or(0, |rem| {
-            rem.iter().take_while(|&y| y.eq(&expect)).count() as u8
-        })
+    fn parse_suffix(&mut self) -> Option<Suffix> {
+        let remain = self.remain()?;
+
+        if self.dialect == Dialect::Postgres {
+            // Postgres has no spelled suffixes
+            return if (remain.starts_with(b"TH") || remain.starts_with(b"th"))
+                && !matches!(remain.get(2), Some(ch) if ch.eq_ignore_ascii_case(&b'h') || ch.is_ascii_digit())
+            {
+                self.advance(2);
+                Some(Suffix::Ordinal)
+            } else {
+                None
+            };
+        }
+
+        let (suffix, end) = scan_model_suffix(self.input, self.pos);
+        self.pos = end;
+        suffix
     }
 
+    /// Parses the fields of Postgres templates which differ from Oracle format models.
     #[inline]
-    fn parse_year(&mut self) -> Field {
-        let remain = match self.remain() {
-            Some(rem) => rem,
-            None => return Field::Invalid,
+    fn parse_postgres(&mut self) -> Option<Field> {
+        let remain = self.remain()?;
+
+        let (field, len) = if CaseInsensitive::starts_with(remain, b"fm") {
+            self.fill_prefix = true;
+            (Field::FillMode, 2)
+        } else if CaseInsensitive::starts_with(remain, b"ms") {
+            (Field::Fraction(Some(3)), 2)
+        } else if CaseInsensitive::starts_with(remain, b"us") {
+            (Field::Fraction(Some(6)), 2)
+        } else if CaseInsensitive::starts_with(remain, b"of") {
+            (Field::TimeZoneOffset, 2)
+        } else if remain.starts_with(b"TZ") || remain.starts_with(b"tz") {
+            (Field::TimeZone, 2)
+        } else if CaseInsensitive::starts_with(remain, b"ssss")
+            && !CaseInsensitive::starts_with(remain, b"sssss")
+        {
+            (Field::SecondsPastMidnight, 4)
+        } else {
+            return None;
         };
 
-        let len = remain
-            .iter()
-            .take(4)
-            .take_while(|&y| y.eq_ignore_ascii_case(&b'y'))
-            .count();
+        self.advance(len);
+        Some(field)
+    }
 
-        if len > 0 {
-            self.advance(len);
-            Field::Year(len as u8)
-        } else {
-            Field::Invalid
+    /// Takes the next character as literal text, as Postgres and MySQL do for unrecognized characters.
+    #[inline]
+    fn parse_char_literal(&mut self) -> Field {
+        let len = match self.input[self.pos] {
+            0x00..=0x7F => 1,
+            0xC0..=0xDF => 2,
+            0xE0..=0xEF => 3,
+            _ => 4,
+        };
+        let end = (self.pos + len).min(self.input.len());
+        let text = std::str::from_utf8(&self.input[self.pos..end]);
+        self.pos = end;
+        match text.map(|text| text.try_to_string()) {
+            Ok(Ok(text)) => Field::Literal(text),
+            _ => Field::Invalid,
         }
     }
 
+    /// Unescapes the quoted text or '\"' scanned from `start` into literal text,
+    /// within the quotes a backslash causes the next character to be taken literally.
     #[inline]
-    fn parse_hour(&mut self) -> Field {
-        let ch = match self.pop() {
-            Some(ch) => ch,
-            None => return Field::Invalid,
+    fn quoted_literal(&self, start: usize) -> Field {
+        let token = &self.input[start..self.pos];
+        let quoted = match token {
+            [b'"', quoted @ .., b'"'] => quoted,
+            [b'\\', quote] => std::slice::from_ref(quote),
+            _ => return Field::Invalid,
         };
 
-        if ch != b'H' && ch != b'h' {
+        let mut text = Vec::new();
+        if text.try_reserve(quoted.len()).is_err() {
             return Field::Invalid;
         }
-
-        match self.remain() {
-            Some(rem) => {
-                if rem.starts_with(b"24") {
-                    self.advance(2);
-                    Field::Hour24
-                } else if rem.starts_with(b"12") {
-                    self.advance(2);
-                    Field::Hour12
-                } else {
-                    Field::Hour12
-                }
+        let mut escaped = false;
+        for &ch in quoted {
+            if ch == b'\\' && !escaped {
+                escaped = true;
+            } else {
+                text.push(ch);
+                escaped = false;
             }
-            None => Field::Hour12,
         }
-    }
 
-    #[inline]
-    fn parse_second(&mut self) -> Field {
-        match self.pop() {
-            Some(b'S') | Some(b's') => Field::Second,
-            _ => Field::Invalid,
+        match String::from_utf8(text) {
+            Ok(text) => Field::Literal(text),
+            Err(_) => Field::Invalid,
         }
     }
 
-    #[inline]
-    fn parse_fraction(&mut self) -> Field {
-        match self.pop() {
-            Some(b'F') | Some(b'f') => match self.peek() {
-                Some(ch) if ch.is_ascii_digit() => {
-                    self.advance(1);
-                    let p = ch - b'0';
-                    if (1..=9).contains(&p) {
-                        Field::Fraction(Some(p))
-                    } else {
-                        Field::Invalid
-                    }
-                }
-                _ => Field::Fraction(None),
-            },
-            _ => Field::Invalid,
+    fn next(&mut self) -> Option<Field> {
+        if let Some(style) = self.suffix_style.take() {
+            let start = self.pos;
+            if let Some(suffix) = self.parse_suffix() {
+                let style = match self.dialect {
+                    Dialect::Postgres => name_style(self.input, start),
+                    _ => style,
+                };
+                return Some(Field::Suffix(suffix, style));
+            }
         }
-    }
 
-    #[inline]
-    fn parse_am(&mut self) -> Field {
-        let remain = match self.remain() {
-            Some(rem) => rem,
-            None => return Field::Invalid,
-        };
+        if let Some(field) = self.pending.pop() {
+            return Some(field);
+        }
 
-        if remain.len() >= 4 {
-            let rem = &remain[0..4];
-            match rem {
-                b"A.M." | b"A.m." | b"a.M." => {
-                    self.advance(4);
-                    return Field::AmPm(AmPmStyle::UpperDot);
-                }
-                b"a.m." => {
-                    self.advance(4);
-                    return Field::AmPm(AmPmStyle::LowerDot);
-                }
-                _ => {}
-            };
+        if matches!(self.dialect, Dialect::Strftime | Dialect::MySql) {
+            return self.next_directive();
         }
 
-        if remain.len() >= 2 {
-            let rem = &remain[0..2];
-            return match rem {
-                b"AM" | b"Am" | b"aM" => {
-                    self.advance(2);
-                    Field::AmPm(AmPmStyle::Upper)
+        let start = self.pos;
+        if self.dialect == Dialect::Postgres {
+            let fill_prefix = self.fill_prefix;
+            if let Some(field) = self.parse_postgres() {
+                if field != Field::FillMode {
+                    self.end_fill_prefix(fill_prefix);
                 }
-                b"am" => {
-                    self.advance(2);
-                    Field::AmPm(AmPmStyle::Lower)
-                }
-                _ => Field::Invalid,
-            };
+                return Some(field);
+            }
+        }
+
+        if self.pos >= self.input.len() {
+            return None;
         }
 
-        Field::Invalid
+        let (field, end) = scan_model_field(self.input, start);
+        self.pos = end;
+        let field = match field {
+            Field::Literal(_) => self.quoted_literal(start),
+            Field::Invalid if self.dialect == Dialect::Postgres => {
+                self.pos = start;
+                self.parse_char_literal()
+            }
+            _ => field,
+        };
+        if field.is_numeric() {
+            self.suffix_style = Some(name_style(self.input, start));
+        }
+        self.end_fill_prefix(self.fill_prefix);
+        Some(field)
     }
 
+    /// Restores the fill mode after the field following a Postgres 'FM' prefix.
     #[inline]
-    fn parse_month_name(&mut self) -> Field {
-        let remain = match self.remain() {
-            Some(rem) => rem,
-            None => return Field::Invalid,
-        };
+    fn end_fill_prefix(&mut self, fill_prefix: bool) {
+        if fill_prefix {
+            self.fill_prefix = false;
+            self.pending.push(Field::FillMode);
+        }
+    }
 
-        if CaseInsensitive::starts_with(remain, b"month") {
-            return match &remain[0..2] {
-                b"MO" => {
-                    self.advance(5);
-                    Field::MonthName(NameStyle::Upper)
-                }
-                b"Mo" => {
-                    self.advance(5);
-                    Field::MonthName(NameStyle::Capital)
-                }
-                _ => {
-                    self.advance(5);
-                    Field::MonthName(NameStyle::Lower)
-                }
-            };
+    /// Parses the next '%' directive of the strftime and MySQL dialects,
+    /// or the literal text up to the next directive.
+    fn next_directive(&mut self) -> Option<Field> {
+        while self.pos >= self.input.len() {
+            let (input, pos) = self.resume.take()?;
+            self.input = input;
+            self.pos = pos;
         }
 
-        if CaseInsensitive::starts_with(remain, b"mon") {
-            return match &remain[0..2] {
-                b"MO" => {
-                    self.advance(3);
-                    Field::MonthName(NameStyle::AbbrUpper)
-                }
-                b"Mo" => {
-                    self.advance(3);
-                    Field::MonthName(NameStyle::AbbrCapital)
-                }
-                _ => {
-                    self.advance(3);
-                    Field::MonthName(NameStyle::AbbrLower)
-                }
-            };
+        if self.input[self.pos] == b'%' {
+            self.advance(1);
+            return Some(match self.dialect {
+                Dialect::MySql => self.parse_mysql(),
+                _ => self.parse_strftime(),
+            });
+        }
+
+        let start = self.pos;
+        let len = self.input[start..]
+            .iter()
+            .take_while(|&&ch| ch != b'%')
+            .count();
+        self.advance(len);
+        match std::str::from_utf8(&self.input[start..self.pos]).map(|text| text.try_to_string()) {
+            Ok(Ok(text)) => Some(Field::Literal(text)),
+            _ => Some(Field::Invalid),
         }
-        Field::Invalid
     }
 
+    /// Parses a `strftime` directive, the '%' has been consumed.
     #[inline]
-    fn parse_day_name(&mut self) -> Field {
-        let remain = match self.remain() {
-            Some(rem) => rem,
+    fn parse_strftime(&mut self) -> Field {
+        let flag = match self.peek() {
+            Some(flag @ (b'-' | b'_' | b'0' | b'^')) => {
+                self.advance(1);
+                Some(flag)
+            }
+            _ => None,
+        };
+        let ch = match self.pop() {
+            Some(ch) => ch,
             None => return Field::Invalid,
         };
 
-        if CaseInsensitive::starts_with(remain, b"day") {
-            return match &remain[0..2] {
-                b"DA" => {
-                    self.advance(3);
-                    Field::DayName(NameStyle::Upper)
-                }
-                b"Da" => {
-                    self.advance(3);
-                    Field::DayName(NameStyle::Capital)
-                }
-                _ => {
-                    self.advance(3);
-                    Field::DayName(NameStyle::Lower)
-                }
-            };
-        } else if remain.len() >= 2 {
-            return match &remain[0..2] {
-                b"DY" => {
-                    self.advance(2);
-                    Field::DayName(NameStyle::AbbrUpper)
-                }
-                b"Dy" => {
-                    self.advance(2);
-                    Field::DayName(NameStyle::AbbrCapital)
-                }
-                _ => {
-                    self.advance(2);
-                    Field::DayName(NameStyle::AbbrLower)
-                }
-            };
-        }
+        let upper = flag == Some(b'^');
+        let day_name = |abbr| match (abbr, upper) {
+            (false, false) => Field::DayName(NameStyle::Capital),
+            (false, true) => Field::DayName(NameStyle::Upper),
+            (true, false) => Field::DayName(NameStyle::AbbrCapital),
+            (true, true) => Field::DayName(NameStyle::AbbrUpper),
+        };
+        let month_name = |abbr| match (abbr, upper) {
+            (false, false) => Field::MonthName(NameStyle::Capital),
+            (false, true) => Field::MonthName(NameStyle::Upper),
+            (true, false) => Field::MonthName(NameStyle::AbbrCapital),
+            (true, true) => Field::MonthName(NameStyle::AbbrUpper),
+        };
+
+        // The padding of the directive: '0' for zeros, '_' for spaces and '-' for none
+        let (field, padding) = match ch {
+            b'%' | b'n' | b't' => {
+                let text = match ch {
+                    b'%' => "%",
+                    b'n' => "\n",
+                    _ => "\t",
+                };
+                return match text.try_to_string() {
+                    Ok(text) => Field::Literal(text),
+                    Err(_) => Field::Invalid,
+                };
+            }
+            b'Y' => (Field::Year(4), b'0'),
+            b'y' => (Field::RoundYear(2), b'0'),
+            b'G' => (Field::IsoYear(4), b'0'),
+            b'g' => (Field::IsoYear(2), b'0'),
+            b'm' => (Field::Month, b'0'),
+            b'd' => (Field::Day, b'0'),
+            b'e' => (Field::Day, b'_'),
+            b'j' => (Field::DayOfYear, b'0'),
+            b'H' => (Field::Hour24, b'0'),
+            b'k' => (Field::Hour24, b'_'),
+            b'I' => (Field::Hour12, b'0'),
+            b'l' => (Field::Hour12, b'_'),
+            b'M' => (Field::Minute, b'0'),
+            b'S' => (Field::Second, b'0'),
+            b'f' => (Field::Fraction(Some(6)), b'0'),
+            b'N' => (Field::Fraction(Some(9)), b'0'),
+            b'p' => (Field::AmPm(AmPmStyle::Upper), b'0'),
+            b'P' => (Field::AmPm(AmPmStyle::Lower), b'0'),
+            // Names are not padded to the longest name
+            b'a' => (day_name(true), b'-'),
+            b'A' => (day_name(false), b'-'),
+            b'b' | b'h' => (month_name(true), b'-'),
+            b'B' => (month_name(false), b'-'),
+            b'U' => (Field::SundayWeek, b'0'),
+            b'W' => (Field::MondayWeek, b'0'),
+            b'V' => (Field::IsoWeek, b'0'),
+            b'u' => (Field::IsoDayOfWeek, b'0'),
+            b'w' => (Field::ZeroDayOfWeek, b'0'),
+            b's' => (Field::UnixTime, b'0'),
+            b'D' | b'x' => return self.expand_directives(b"%m/%d/%y"),
+            b'F' => return self.expand_directives(b"%Y-%m-%d"),
+            b'T' | b'X' => return self.expand_directives(b"%H:%M:%S"),
+            b'R' => return self.expand_directives(b"%H:%M"),
+            b'r' => return self.expand_directives(b"%I:%M:%S %p"),
+            b'c' => return self.expand_directives(b"%a %b %e %H:%M:%S %Y"),
+            _ => return Field::Invalid,
+        };
 
-        Field::Invalid
+        // Padding flags only apply to numbers
+        let padding = match flag {
+            Some(flag @ (b'-' | b'_' | b'0')) if padding != b'-' => flag,
+            _ => padding,
+        };
+        self.pad_field(field, padding)
     }
 
+    /// Parses a MySQL `DATE_FORMAT` specifier, the '%' has been consumed.
     #[inline]
-    fn parse_pm(&mut self) -> Field {
-        let remain = match self.remain() {
-            Some(rem) => rem,
+    fn parse_mysql(&mut self) -> Field {
+        let ch = match self.peek() {
+            Some(ch) => ch,
             None => return Field::Invalid,
         };
 
-        if remain.len() >= 4 {
-            let rem = &remain[0..4];
-            match rem {
-                b"P.M." | b"P.m." | b"p.M." => {
-                    self.advance(4);
-                    return Field::AmPm(AmPmStyle::UpperDot);
-                }
-                b"p.m." => {
-                    self.advance(4);
-                    return Field::AmPm(AmPmStyle::LowerDot);
-                }
-                _ => {}
-            };
-        }
-
-        if remain.len() >= 2 {
-            let rem = &remain[0..2];
-            return match rem {
-                b"PM" | b"Pm" | b"pM" => {
-                    self.advance(2);
-                    Field::AmPm(AmPmStyle::Upper)
-                }
-                b"pm" => {
-                    self.advance(2);
-                    Field::AmPm(AmPmStyle::Lower)
-                }
-                _ => Field::Invalid,
-            };
-        }
+        // The padding of the specifier: '0' for zeros and '-' for none
+        let (field, padding) = match ch {
+            b'a' => (Field::DayName(NameStyle::AbbrCapital), b'-'),
+            b'b' => (Field::MonthName(NameStyle::AbbrCapital), b'-'),
+            b'c' => (Field::Month, b'-'),
+            b'D' => {
+                self.advance(1);
+                self.pending.push(Field::FillMode);
+                self.pending
+                    .push(Field::Suffix(Suffix::Ordinal, NameStyle::Lower));
+                self.pending.push(Field::Day);
+                return Field::FillMode;
+            }
+            b'd' => (Field::Day, b'0'),
+            b'e' => (Field::Day, b'-'),
+            b'f' => (Field::Fraction(Some(6)), b'0'),
+            b'H' => (Field::Hour24, b'0'),
+            b'h' | b'I' => (Field::Hour12, b'0'),
+            b'i' => (Field::Minute, b'0'),
+            b'j' => (Field::DayOfYear, b'0'),
+            b'k' => (Field::Hour24, b'-'),
+            b'l' => (Field::Hour12, b'-'),
+            b'M' => (Field::MonthName(NameStyle::Capital), b'-'),
+            b'm' => (Field::Month, b'0'),
+            b'p' => (Field::AmPm(AmPmStyle::Upper), b'0'),
+            b'S' | b's' => (Field::Second, b'0'),
+            b'U' => (Field::SundayWeek, b'0'),
+            b'u' => (Field::FourDayWeek, b'0'),
+            b'V' => (Field::SundayYearWeek, b'0'),
+            b'v' => (Field::IsoWeek, b'0'),
+            b'W' => (Field::DayName(NameStyle::Capital), b'-'),
+            b'w' => (Field::ZeroDayOfWeek, b'0'),
+            b'X' => (Field::SundayYear, b'0'),
+            b'x' => (Field::IsoYear(4), b'0'),
+            b'Y' => (Field::Year(4), b'0'),
+            b'y' => (Field::RoundYear(2), b'0'),
+            b'r' => {
+                self.advance(1);
+                return self.expand_directives(b"%h:%i:%s %p");
+            }
+            b'T' => {
+                self.advance(1);
+                return self.expand_directives(b"%H:%i:%s");
+            }
+            // '%x' is 'x' for any 'x' that is not a specifier
+            _ => return self.parse_char_literal(),
+        };
 
-        Field::Invalid
+        self.advance(1);
+        self.pad_field(field, padding)
     }
 
-    fn next(&mut self) -> Option<Field> {
-        match self.pop() {
-            Some(char) => {
-                let field = match char {
-                    b' ' => {
-                        let len = self.punctuation_count(b' ');
-                        self.advance(len as usize);
-                        Field::Blank(len + 1)
-                    }
-                    b'-' => Field::Hyphen,
-                    b':' => Field::Colon,
-                    b'/' => Field::Slash,
-                    b'\\' => Field::Backslash,
-                    b',' => Field::Comma,
-                    b'.' => Field::Dot,
-                    b';' => Field::Semicolon,
-                    b'A' | b'a' => {
-                        self.back(1);
-                        self.parse_am()
-                    }
-                    b'D' | b'd' => match self.peek() {
-                        Some(ch) => match ch {
-                            b'D' | b'd' => {
-                                self.advance(1);
-                                match self.peek() {
-                                    Some(ch) => match ch {
-                                        b'D' | b'd' => {
-                                            self.advance(1);
-                                            Field::DayOfYear
-                                        }
-                                        _ => Field::Day,
-                                    },
-                                    None => Field::Day,
-                                }
-                            }
-                            b'a' | b'A' | b'Y' | b'y' => {
-                                self.back(1);
-                                self.parse_day_name()
-                            }
-                            _ => Field::DayOfWeek,
-                        },
-                        None => Field::DayOfWeek,
-                    },
-                    b'F' | b'f' => self.parse_fraction(),
-                    b'H' | b'h' => self.parse_hour(),
-                    b'M' | b'm' => match self.peek() {
-                        Some(ch) => match ch {
-                            b'I' | b'i' => {
-                                self.advance(1);
-                                Field::Minute
-                            }
-                            b'M' | b'm' => {
-                                self.advance(1);
-                                Field::Month
-                            }
-                            b'O' | b'o' => {
-                                self.back(1);
-                                self.parse_month_name()
-                            }
-                            _ => Field::Invalid,
-                        },
-                        None => Field::Invalid,
-                    },
-                    b'P' | b'p' => {
-                        self.back(1);
-                        self.parse_pm()
-                    }
-                    b'S' | b's' => self.parse_second(),
-                    b'T' => Field::T,
-                    b'Y' | b'y' => {
-                        self.back(1);
-                        self.parse_year()
-                    }
-                    b'W' | b'w' => match self.peek() {
-                        Some(ch) => match ch {
-                            b'W' | b'w' => {
-                                self.advance(1);
-                                Field::WeekOfYear
-                            }
-                            _ => Field::WeekOfMonth,
-                        },
-                        None => Field::WeekOfMonth,
-                    },
-                    _ => Field::Invalid,
-                };
-                Some(field)
+    /// Surrounds the field with the toggles of its padding, '_' for spaces and '-' for none.
+    #[inline]
+    fn pad_field(&mut self, field: Field, padding: u8) -> Field {
+        let toggle = || match padding {
+            b'-' => Some(Field::FillMode),
+            b'_' => Some(Field::SpacePadding),
+            _ => None,
+        };
+        match toggle() {
+            Some(open) => {
+                self.pending.extend(toggle());
+                self.pending.push(field);
+                open
             }
-            None => None,
+            None => field,
         }
     }
+
+    /// Continues with the directives a composite directive stands for, e.g. '%F'.
+    #[inline]
+    fn expand_directives(&mut self, directives: &'static [u8]) -> Field {
+        self.resume = Some((self.input, self.pos));
+        self.input = directives;
+        self.pos = 0;
+        self.next_directive().unwrap_or(Field::Invalid)
+    }
+}
+
+/// Gets the case of a field by its first two letters at `pos`, e.g. 'DD', 'Dd' and 'dd'.
+#[inline]
+const fn name_style(input: &[u8], pos: usize) -> NameStyle {
+    let first = byte_at(input, pos);
+    if first.is_ascii_uppercase() && byte_at(input, pos + 1).is_ascii_lowercase() {
+        NameStyle::Capital
+    } else if first.is_ascii_uppercase() {
+        NameStyle::Upper
+    } else {
+        NameStyle::Lower
+    }
 }
 
 impl<'a> Iterator for FormatParser<'a> {
@@ -1023,43 +1468,527 @@ impl<'a> Iterator for FormatParser<'a> {
     }
 }
 
-/// Date/Time formatter.
-#[derive(Debug)]
-pub struct Formatter {
-    fields: StackVec<Field, MAX_FIELDS>,
-    format_exact: bool,
+/// Panics if `Formatter::try_new` rejects the format model, which lets `formatter!` check
+/// the model at compile time.
+#[doc(hidden)]
+pub const fn assert_format_model(fmt: &str) {
+    if check_format_model(fmt.as_bytes(), 0, false).is_none() {
+        panic!("date format not recognized");
+    }
 }
 
-impl Formatter {
-    /// Creates a new `Formatter` from given format string.
-    #[inline]
-    pub fn try_new<S: AsRef<str>>(fmt: S) -> Result<Self> {
-        let parser = FormatParser::new(fmt.as_ref().as_bytes());
-
-        let mut fields = StackVec::new();
+/// Checks an Oracle format model in a const context as `Formatter::try_new` does,
+/// returning the number of fields after `count` fields, or `None` if the model is rejected.
+const fn check_format_model(model: &[u8], mut count: usize, expanded: bool) -> Option<usize> {
+    let mut pos = 0;
+    let mut fill = false;
+
+    while pos < model.len() {
+        let scanned = scan_model_field(model, pos);
+        let field = &scanned.0;
+        pos = scanned.1;
+
+        let expansion = match field {
+            Field::LongDate => Some(Territory::AMERICA.long_date()),
+            Field::ShortDate => Some(Territory::AMERICA.short_date()),
+            Field::ShortTime => Some(Territory::AMERICA.short_time()),
+            _ => None,
+        };
+        let rejected = match field {
+            Field::Invalid => true,
+            Field::FormatExact => expanded,
+            _ => expanded && expansion.is_some(),
+        };
+        let toggles_fill = matches!(field, Field::FillMode);
+        let numeric = field.is_numeric();
+        // A field cannot be dropped in a const context, and the scanned ones hold no text
+        std::mem::forget(scanned);
 
-        for field in parser {
-            if let Field::Invalid = field {
-                return Err(Error::InvalidFormat(
-                    "date format not recognized".try_to_string()?,
-                ));
+        if rejected {
+            return None;
+        }
+        if let Some(model) = expansion {
+            count = match check_format_model(model.as_bytes(), count, true) {
+                Some(count) => count,
+                None => return None,
+            };
+            continue;
+        }
+        if toggles_fill {
+            fill = !fill;
+        }
+
+        count += 1;
+
+        if numeric {
+            let (suffix, end) = scan_model_suffix(model, pos);
+            if suffix.is_some() {
+                pos = end;
+                count += 1;
+            }
+        }
+    }
+
+    if expanded && fill {
+        count += 1;
+    }
+    Some(count)
+}
+
+/// Scans the field at `start` of an Oracle format model, returning the field and the position
+/// after it. `FormatParser` tokenizes Oracle and Postgres models with it, and `formatter!`
+/// checks models with it at compile time, so it runs in a const context and returns quoted
+/// text and '\"' as an empty `Field::Literal`, whose text `FormatParser` takes from the model.
+const fn scan_model_field(model: &[u8], start: usize) -> (Field, usize) {
+    let ch = model[start];
+    let pos = start + 1;
+    let next = byte_at(model, pos);
+
+    match ch {
+        b' ' => {
+            // The length of a blank is a `u8`, longer runs of spaces are split
+            let mut len = 1;
+            while len < u8::MAX as usize && byte_at(model, start + len) == b' ' {
+                len += 1;
+            }
+            (Field::Blank(len as u8), start + len)
+        }
+        b'-' => (Field::Hyphen, pos),
+        b':' => (Field::Colon, pos),
+        b'/' => (Field::Slash, pos),
+        b',' => (Field::Comma, pos),
+        b'.' => (Field::Dot, pos),
+        b';' => (Field::Semicolon, pos),
+        b'\\' if next == b'"' => (Field::Literal(String::new()), pos + 1),
+        b'\\' => (Field::Backslash, pos),
+        b'"' => {
+            let mut pos = pos;
+            while pos < model.len() {
+                match model[pos] {
+                    b'"' => return (Field::Literal(String::new()), pos + 1),
+                    b'\\' if pos + 1 < model.len() => pos += 2,
+                    b'\\' => break,
+                    _ => pos += 1,
+                }
+            }
+            // Unterminated quoted text
+            (Field::Invalid, pos)
+        }
+        b'A' | b'a' => {
+            if starts_with_ci(model, start, b"ad") || starts_with_ci(model, start, b"a.d.") {
+                scan_model_era(model, start, b"a.d.", b"ad")
+            } else {
+                scan_model_meridian(model, start, b"a.m.", b"am")
+            }
+        }
+        b'B' | b'b' => scan_model_era(model, start, b"b.c.", b"bc"),
+        b'P' | b'p' => scan_model_meridian(model, start, b"p.m.", b"pm"),
+        b'C' | b'c' => match next {
+            b'C' | b'c' => (Field::Century, pos + 1),
+            _ => (Field::Invalid, pos),
+        },
+        b'D' | b'd' => match next {
+            b'D' | b'd' => match byte_at(model, pos + 1) {
+                b'D' | b'd' => (Field::DayOfYear, pos + 2),
+                _ => (Field::Day, pos + 1),
+            },
+            b'A' | b'a' | b'Y' | b'y' => {
+                let style = name_style(model, start);
+                if starts_with_ci(model, start, b"day") {
+                    (Field::DayName(style), start + 3)
+                } else {
+                    (Field::DayName(style.abbr()), start + 2)
+                }
+            }
+            b'L' | b'l' => (Field::LongDate, pos + 1),
+            // 'DSP' is 'D' followed by a 'SP' suffix
+            b'S' | b's' if !matches!(byte_at(model, pos + 1), b'P' | b'p') => {
+                (Field::ShortDate, pos + 1)
+            }
+            _ => (Field::DayOfWeek, pos),
+        },
+        b'F' | b'f' => match next {
+            b'X' | b'x' => (Field::FormatExact, pos + 1),
+            b'M' | b'm' => (Field::FillMode, pos + 1),
+            b'F' | b'f' => match byte_at(model, pos + 1) {
+                p @ b'1'..=b'9' => (Field::Fraction(Some(p - b'0')), pos + 2),
+                b'0' => (Field::Invalid, pos + 2),
+                _ => (Field::Fraction(None), pos + 1),
+            },
+            _ => (Field::Invalid, pos),
+        },
+        b'H' | b'h' => match next {
+            b'H' | b'h' => {
+                if starts_with(model, pos + 1, b"24") {
+                    (Field::Hour24, pos + 3)
+                } else if starts_with(model, pos + 1, b"12") {
+                    (Field::Hour12, pos + 3)
+                } else {
+                    (Field::Hour12, pos + 1)
+                }
+            }
+            _ => (Field::Invalid, pos),
+        },
+        b'I' | b'i' => match next {
+            b'W' | b'w' => (Field::IsoWeek, pos + 1),
+            _ => {
+                let len = count_ci(model, pos, b'y', 3);
+                (Field::IsoYear(len as u8 + 1), pos + len)
+            }
+        },
+        b'J' | b'j' => (Field::Julian, pos),
+        b'Q' | b'q' => (Field::Quarter, pos),
+        b'M' | b'm' => match next {
+            b'I' | b'i' => (Field::Minute, pos + 1),
+            b'M' | b'm' => (Field::Month, pos + 1),
+            b'O' | b'o' => {
+                let style = name_style(model, start);
+                if starts_with_ci(model, start, b"month") {
+                    (Field::MonthName(style), start + 5)
+                } else if starts_with_ci(model, start, b"mon") {
+                    (Field::MonthName(style.abbr()), start + 3)
+                } else {
+                    (Field::Invalid, pos)
+                }
+            }
+            _ => (Field::Invalid, pos),
+        },
+        b'R' | b'r' => {
+            if starts_with_ci(model, start, b"rm") {
+                let style = if ch == b'r' && next == b'm' {
+                    NameStyle::Lower
+                } else {
+                    NameStyle::Upper
+                };
+                return (Field::RomanMonth(style), start + 2);
+            }
+
+            match count_ci(model, start, b'r', 4) {
+                4 => (Field::RoundYear(4), start + 4),
+                2 | 3 => (Field::RoundYear(2), start + 2),
+                _ => (Field::Invalid, pos),
+            }
+        }
+        b'S' | b's' => {
+            if starts_with_ci(model, pos, b"year") {
+                (Field::SignedYearName(name_style(model, start)), pos + 4)
+            } else if starts_with_ci(model, pos, b"cc") {
+                (Field::SignedCentury, pos + 2)
+            } else if starts_with_ci(model, pos, b"yyyy") {
+                (Field::SignedYear, pos + 4)
+            } else if starts_with_ci(model, pos, b"ssss") {
+                (Field::SecondsPastMidnight, pos + 4)
+            } else if matches!(next, b'S' | b's') {
+                (Field::Second, pos + 1)
+            } else {
+                (Field::Invalid, pos)
+            }
+        }
+        b'T' | b't' => match next {
+            b'S' | b's' => (Field::ShortTime, pos + 1),
+            _ if ch == b'T' => (Field::T, pos),
+            _ => (Field::Invalid, pos),
+        },
+        b'Y' | b'y' => {
+            if starts_with_ci(model, start, b"y,yyy") {
+                (Field::CommaYear, start + 5)
+            } else if starts_with_ci(model, start, b"year") {
+                (Field::YearName(name_style(model, start)), start + 4)
+            } else {
+                let len = count_ci(model, start, b'y', 4);
+                (Field::Year(len as u8), start + len)
             }
+        }
+        b'W' | b'w' => match next {
+            b'W' | b'w' => (Field::WeekOfYear, pos + 1),
+            _ => (Field::WeekOfMonth, pos),
+        },
+        _ => (Field::Invalid, pos),
+    }
+}
+
+/// Scans a name such as 'AM' or 'A.M.' in any case, returning whether it is dotted,
+/// whether it is in lower case, i.e. both of its letters are, and the position after it.
+const fn scan_model_name(
+    model: &[u8],
+    start: usize,
+    dotted: &[u8],
+    name: &[u8],
+) -> Option<(bool, bool, usize)> {
+    let lower = byte_at(model, start).is_ascii_lowercase();
+    if starts_with_ci(model, start, dotted) {
+        let lower = lower && byte_at(model, start + 2).is_ascii_lowercase();
+        Some((true, lower, start + dotted.len()))
+    } else if starts_with_ci(model, start, name) {
+        let lower = lower && byte_at(model, start + 1).is_ascii_lowercase();
+        Some((false, lower, start + name.len()))
+    } else {
+        None
+    }
+}
+
+/// Scans 'AM', 'A.M.', 'PM' or 'P.M.' in any case.
+const fn scan_model_meridian(
+    model: &[u8],
+    start: usize,
+    dotted: &[u8],
+    name: &[u8],
+) -> (Field, usize) {
+    match scan_model_name(model, start, dotted, name) {
+        Some((true, true, end)) => (Field::AmPm(AmPmStyle::LowerDot), end),
+        Some((true, false, end)) => (Field::AmPm(AmPmStyle::UpperDot), end),
+        Some((false, true, end)) => (Field::AmPm(AmPmStyle::Lower), end),
+        Some((false, false, end)) => (Field::AmPm(AmPmStyle::Upper), end),
+        None => (Field::Invalid, start + 1),
+    }
+}
 
-            if fields.is_full() {
-                return Err(Error::InvalidFormat(
-                    "date format is too long for internal buffer".try_to_string()?,
-                ));
+/// Scans 'AD', 'A.D.', 'BC' or 'B.C.' in any case.
+const fn scan_model_era(model: &[u8], start: usize, dotted: &[u8], name: &[u8]) -> (Field, usize) {
+    match scan_model_name(model, start, dotted, name) {
+        Some((true, true, end)) => (Field::Era(EraStyle::LowerDot), end),
+        Some((true, false, end)) => (Field::Era(EraStyle::UpperDot), end),
+        Some((false, true, end)) => (Field::Era(EraStyle::Lower), end),
+        Some((false, false, end)) => (Field::Era(EraStyle::Upper), end),
+        None => (Field::Invalid, start + 1),
+    }
+}
+
+/// Scans the suffix of a numeric field, returning the suffix and the position after it.
+/// 'TH' is not taken from 'THH' which is 'T' followed by 'HH'.
+const fn scan_model_suffix(model: &[u8], pos: usize) -> (Option<Suffix>, usize) {
+    if starts_with_ci(model, pos, b"spth") || starts_with_ci(model, pos, b"thsp") {
+        (Some(Suffix::SpelledOrdinal), pos + 4)
+    } else if starts_with_ci(model, pos, b"sp") {
+        (Some(Suffix::Spelled), pos + 2)
+    } else if starts_with_ci(model, pos, b"th")
+        && !matches!(byte_at(model, pos + 2), b'H' | b'h' | b'0'..=b'9')
+    {
+        (Some(Suffix::Ordinal), pos + 2)
+    } else {
+        (None, pos)
+    }
+}
+
+/// Counts the repeats of the letter `ch` in any case at `pos`, up to `max`.
+const fn count_ci(input: &[u8], pos: usize, ch: u8, max: usize) -> usize {
+    let mut len = 0;
+    while len < max && byte_at(input, pos + len).eq_ignore_ascii_case(&ch) {
+        len += 1;
+    }
+    len
+}
+
+#[inline]
+const fn byte_at(input: &[u8], pos: usize) -> u8 {
+    if pos < input.len() {
+        input[pos]
+    } else {
+        0
+    }
+}
+
+const fn starts_with(input: &[u8], pos: usize, needle: &[u8]) -> bool {
+    if input.len() < pos + needle.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < needle.len() {
+        if input[pos + i] != needle[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+const fn starts_with_ci(input: &[u8], pos: usize, needle: &[u8]) -> bool {
+    if input.len() < pos + needle.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < needle.len() {
+        if !input[pos + i].eq_ignore_ascii_case(&needle[i]) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// The fields of a format model, which are stored inline unless the model is long.
+#[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
+enum FieldVec {
+    Inline(StackVec<Field, INLINE_FIELDS>),
+    Heap(Vec<Field>),
+}
+
+impl FieldVec {
+    #[inline]
+    const fn new() -> Self {
+        FieldVec::Inline(StackVec::new())
+    }
+
+    /// Appends a field, moving the fields onto the heap once the inline storage is full.
+    #[inline]
+    fn push(&mut self, field: Field) -> Result<()> {
+        match self {
+            FieldVec::Inline(fields) if !fields.is_full() => fields.push(field),
+            FieldVec::Inline(fields) => {
+                let mut heap = Vec::new();
+                heap.try_reserve(INLINE_FIELDS * 2)?;
+                heap.extend(fields.drain(..));
+                heap.push(field);
+                *self = FieldVec::Heap(heap);
+            }
+            FieldVec::Heap(fields) => {
+                fields.try_reserve(1)?;
+                fields.push(field);
             }
+        }
+        Ok(())
+    }
+}
+
+impl std::ops::Deref for FieldVec {
+    type Target = [Field];
+
+    #[inline]
+    fn deref(&self) -> &[Field] {
+        match self {
+            FieldVec::Inline(fields) => fields,
+            FieldVec::Heap(fields) => fields,
+        }
+    }
+}
+
+/// Date/Time formatter.
+#[derive(Debug)]
+pub struct Formatter {
+    fields: FieldVec,
+    // Whether the format model contains 'FX', which toggles exact matching when parsing
+    format_exact: bool,
+    dialect: Dialect,
+    locale: &'static Locale,
+}
+
+impl Formatter {
+    /// Creates a new `Formatter` from given format string.
+    /// 'DL', 'DS' and 'TS' are expanded to the formats of the American territory.
+    #[inline]
+    pub fn try_new<S: AsRef<str>>(fmt: S) -> Result<Self> {
+        Formatter::try_new_internal(fmt, Dialect::Oracle, &Territory::AMERICA)
+    }
+
+    /// Creates a new `Formatter` from given format string of the dialect.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use sqldatetime::{Date, Dialect, Formatter, Time, Timestamp};
+    ///
+    /// let fmt = Formatter::try_new_with("FMDD Mon YYYY HH24:MI:SS.MS", Dialect::Postgres).unwrap();
+    /// let ts = Date::try_from_ymd(2021, 3, 3)
+    ///     .unwrap()
+    ///     .and_time(Time::try_from_hms(17, 6, 7, 89000).unwrap());
+    /// let mut s = String::new();
+    /// fmt.format(ts, &mut s).unwrap();
+    /// assert_eq!(s, "3 Mar 2021 17:06:07.089");
+    /// assert_eq!(fmt.parse::<_, Timestamp>("3/Mar/2021 17:06:07.089").unwrap(), ts);
+    /// ```
+    #[inline]
+    pub fn try_new_with<S: AsRef<str>>(fmt: S, dialect: Dialect) -> Result<Self> {
+        Formatter::try_new_internal(fmt, dialect, &Territory::AMERICA)
+    }
+
+    /// Creates a new `Formatter` from given format string,
+    /// in which 'DL', 'DS' and 'TS' are expanded to the formats of the territory.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use sqldatetime::{Date, Formatter, Territory};
+    ///
+    /// let date = Date::try_from_ymd(2021, 3, 3).unwrap();
+    ///
+    /// let fmt = Formatter::try_new("DS").unwrap();
+    /// let mut s = String::new();
+    /// fmt.format(date, &mut s).unwrap();
+    /// assert_eq!(s, "03/03/2021");
+    ///
+    /// let fmt = Formatter::try_new_with_territory("DS", &Territory::GERMANY).unwrap();
+    /// let mut s = String::new();
+    /// fmt.format(date, &mut s).unwrap();
+    /// assert_eq!(s, "03.03.21");
+    /// ```
+    #[inline]
+    pub fn try_new_with_territory<S: AsRef<str>>(fmt: S, territory: &Territory) -> Result<Self> {
+        Formatter::try_new_internal(fmt, Dialect::Oracle, territory)
+    }
+
+    #[inline]
+    fn try_new_internal<S: AsRef<str>>(
+        fmt: S,
+        dialect: Dialect,
+        territory: &Territory,
+    ) -> Result<Self> {
+        let parser = FormatParser::with_dialect(fmt.as_ref().as_bytes(), dialect);
+
+        let mut fields = FieldVec::new();
+        let mut format_exact = false;
+
+        for field in parser {
+            let model = match field {
+                Field::Invalid => {
+                    return Err(Error::InvalidFormat);
+                }
+                Field::FormatExact => {
+                    format_exact = true;
+                    None
+                }
+                Field::LongDate => Some(territory.long_date()),
+                Field::ShortDate => Some(territory.short_date()),
+                Field::ShortTime => Some(territory.short_time()),
+                _ => None,
+            };
 
-            fields.push(field);
+            match model {
+                Some(model) => expand_model(&mut fields, model)?,
+                None => fields.push(field)?,
+            }
         }
 
         Ok(Formatter {
             fields,
-            format_exact: false,
+            format_exact,
+            dialect,
+            locale: &Locale::ENGLISH,
         })
     }
 
+    /// Uses the month names, day names and meridian indicators of the locale, English by default.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use sqldatetime::{Date, Formatter, Locale};
+    ///
+    /// let fmt = Formatter::try_new("DD. FMMonth YYYY").unwrap().with_locale(&Locale::GERMAN);
+    /// let date = Date::try_from_ymd(2021, 3, 3).unwrap();
+    /// let mut s = String::new();
+    /// fmt.format(date, &mut s).unwrap();
+    /// assert_eq!(s, "03. März 2021");
+    /// assert_eq!(fmt.parse::<_, Date>("03. MÄRZ 2021").unwrap(), date);
+    /// ```
+    #[inline]
+    pub fn with_locale(mut self, locale: &'static Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
     /// Formats datetime types
     #[inline]
     pub fn format<W: fmt::Write, T: DateTimeFormat>(&self, datetime: T, mut w: W) -> Result<()> {
@@ -1071,9 +2000,40 @@ impl Formatter {
             w.write_char('+')?;
         }
 
-        for field in self.fields.iter() {
+        // Whether the FM modifier is in effect for the current field
+        let mut fill = false;
+        // Whether numbers are padded with spaces instead of zeros
+        let mut space = false;
+
+        macro_rules! write_number {
+            ($padded: expr) => {{
+                let padded = $padded;
+                if fill {
+                    w.write_str(trim_leading_zeros(padded))?
+                } else if space {
+                    let trimmed = trim_leading_zeros(padded);
+                    for _ in trimmed.len()..padded.len() {
+                        w.write_char(' ')?
+                    }
+                    w.write_str(trimmed)?
+                } else {
+                    w.write_str(padded)?
+                }
+            }};
+        }
+
+        for (i, field) in self.fields.iter().enumerate() {
+            // A spelled number is written by its suffix instead of digits
+            if let Some(Field::Suffix(Suffix::Spelled | Suffix::SpelledOrdinal, _)) =
+                self.fields.get(i + 1)
+            {
+                continue;
+            }
+
             match field {
-                Field::Invalid => unreachable!(),
+                Field::Invalid | Field::LongDate | Field::ShortDate | Field::ShortTime => {
+                    unreachable!()
+                }
                 Field::Blank(n) => {
                     for _ in 0..*n {
                         w.write_char(' ')?
@@ -1087,76 +2047,107 @@ impl Formatter {
                 Field::Dot => w.write_char('.')?,
                 Field::Semicolon => w.write_char(';')?,
                 Field::T => w.write_char('T')?,
+                Field::FormatExact => {}
+                Field::FillMode => fill = !fill,
+                Field::SpacePadding => space = !space,
+                Field::Literal(text) => w.write_str(text)?,
                 Field::Year(n) => {
                     let year = if T::HAS_DATE {
-                        dt.year() % (YEAR_MODIFIER[*n as usize - 1] as i32)
+                        dt.year().abs() % (YEAR_MODIFIER[*n as usize - 1] as i32)
                     } else if T::IS_INTERVAL_YM {
                         dt.year()
                     } else {
-                        return Err(Error::FormatError(
-                            "date format not recognized".try_to_string()?,
-                        ));
+                        return Err(Error::InvalidFormat);
                     };
-                    write_u32(&mut w, year as u32, *n as usize)?;
+                    let width = if fill { 1 } else { *n as usize };
+                    write_u32(&mut w, year as u32, width)?;
+                }
+                Field::Century | Field::SignedCentury => {
+                    if T::HAS_DATE {
+                        let century = dt.century();
+                        if *field == Field::SignedCentury {
+                            write_sign(&mut w, century < 0, fill)?;
+                        }
+                        let width = if fill { 1 } else { 2 };
+                        write_u32(&mut w, century.unsigned_abs(), width)?;
+                    } else {
+                        return Err(Error::InvalidFormat);
+                    }
+                }
+                Field::SignedYear => {
+                    if T::HAS_DATE {
+                        write_sign(&mut w, dt.year() < 0, fill)?;
+                        let width = if fill { 1 } else { 4 };
+                        write_u32(&mut w, dt.year().unsigned_abs(), width)?;
+                    } else {
+                        return Err(Error::InvalidFormat);
+                    }
+                }
+                Field::CommaYear => {
+                    if T::HAS_DATE {
+                        let year = dt.year().unsigned_abs();
+                        write_u32(&mut w, year / 1000, 1)?;
+                        w.write_char(',')?;
+                        write_u32(&mut w, year % 1000, 3)?;
+                    } else {
+                        return Err(Error::InvalidFormat);
+                    }
+                }
+                Field::RoundYear(n) => {
+                    if T::HAS_DATE {
+                        let year = dt.year().abs() % (YEAR_MODIFIER[*n as usize - 1] as i32);
+                        let width = if fill { 1 } else { *n as usize };
+                        write_u32(&mut w, year as u32, width)?;
+                    } else {
+                        return Err(Error::InvalidFormat);
+                    }
                 }
                 Field::Month => {
                     if T::HAS_DATE || T::IS_INTERVAL_YM {
-                        w.write_str(dt.month_str())?
+                        write_number!(dt.month_str())
                     } else {
-                        return Err(Error::FormatError(
-                            "date format not recognized".try_to_string()?,
-                        ));
+                        return Err(Error::InvalidFormat);
                     }
                 }
                 Field::Day => {
                     if T::HAS_DATE {
-                        w.write_str(dt.day_str())?
+                        write_number!(dt.day_str())
                     } else if T::IS_INTERVAL_DT {
                         if dt.day() < 32 {
-                            w.write_str(dt.day_str())?
+                            write_number!(dt.day_str())
                         } else {
                             write!(w, "{}", dt.day())?
                         }
                     } else {
-                        return Err(Error::FormatError(
-                            "date format not recognized".try_to_string()?,
-                        ));
+                        return Err(Error::InvalidFormat);
                     }
                 }
                 Field::Hour24 => {
                     if T::HAS_TIME {
-                        w.write_str(dt.hour24_str())?
+                        write_number!(dt.hour24_str())
                     } else {
-                        return Err(Error::FormatError(
-                            "date format not recognized".try_to_string()?,
-                        ));
+                        return Err(Error::InvalidFormat);
                     }
                 }
                 Field::Hour12 => {
                     if T::HAS_TIME && !T::IS_INTERVAL_DT {
-                        w.write_str(dt.hour12_str())?
+                        write_number!(dt.hour12_str())
                     } else {
-                        return Err(Error::FormatError(
-                            "date format not recognized".try_to_string()?,
-                        ));
+                        return Err(Error::InvalidFormat);
                     }
                 }
                 Field::Minute => {
                     if T::HAS_TIME {
-                        w.write_str(dt.minute_str())?
+                        write_number!(dt.minute_str())
                     } else {
-                        return Err(Error::FormatError(
-                            "date format not recognized".try_to_string()?,
-                        ));
+                        return Err(Error::InvalidFormat);
                     }
                 }
                 Field::Second => {
                     if T::HAS_TIME {
-                        w.write_str(dt.second_str())?
+                        write_number!(dt.second_str())
                     } else {
-                        return Err(Error::FormatError(
-                            "date format not recognized".try_to_string()?,
-                        ));
+                        return Err(Error::InvalidFormat);
                     }
                 }
                 Field::Fraction(p) => {
@@ -1164,72 +2155,222 @@ impl Formatter {
                         let p = p.unwrap_or(6);
                         write_u32(&mut w, dt.fraction(p), p as usize)?;
                     } else {
-                        return Err(Error::FormatError(
-                            "date format not recognized".try_to_string()?,
-                        ));
+                        return Err(Error::InvalidFormat);
+                    }
+                }
+                Field::Era(style) => {
+                    if T::HAS_DATE {
+                        w.write_str(style.format(dt.year()))?
+                    } else {
+                        return Err(Error::InvalidFormat);
                     }
                 }
                 Field::AmPm(am_pm) => {
                     if T::HAS_TIME && !T::IS_INTERVAL_DT {
-                        w.write_str(am_pm.format(dt.hour24()))?
+                        let name = self.locale.am_pm(dt.hour24(), am_pm.is_dot());
+                        write_name(&mut w, name, am_pm.name_style())?;
                     } else {
-                        return Err(Error::FormatError(
-                            "date format not recognized".try_to_string()?,
-                        ));
+                        return Err(Error::InvalidFormat);
                     }
                 }
                 Field::MonthName(style) => {
                     if T::HAS_DATE {
-                        w.write_str(dt.month_name(*style))?
+                        let month = Month::from(dt.month() as usize);
+                        let name = self.locale.month_name(month, style.is_abbr());
+                        write_name(&mut w, name, *style)?;
+                        if !fill && !style.is_abbr() {
+                            write_padding(&mut w, name, self.locale.month_name_max_len())?;
+                        }
+                    } else {
+                        return Err(Error::InvalidFormat);
+                    }
+                }
+                Field::RomanMonth(style) => {
+                    if T::HAS_DATE {
+                        let roman = dt.roman_month(*style);
+                        w.write_str(roman)?;
+                        if !fill {
+                            write_padding(&mut w, roman, ROMAN_MONTH_MAX_LENGTH)?;
+                        }
                     } else {
-                        return Err(Error::FormatError(
-                            "date format not recognized".try_to_string()?,
-                        ));
+                        return Err(Error::InvalidFormat);
                     }
                 }
                 Field::DayName(style) => {
                     if T::HAS_DATE {
-                        w.write_str(dt.week_day_name(datetime.date(), *style)?)?
+                        let week_day = dt.week_day(datetime.date())?;
+                        let name = self.locale.day_name(week_day, style.is_abbr());
+                        write_name(&mut w, name, *style)?;
+                        if !fill && !style.is_abbr() {
+                            write_padding(&mut w, name, self.locale.day_name_max_len())?;
+                        }
                     } else {
-                        return Err(Error::FormatError(
-                            "date format not recognized".try_to_string()?,
-                        ));
+                        return Err(Error::InvalidFormat);
                     }
                 }
                 Field::DayOfWeek => {
                     if T::HAS_DATE {
                         w.write_str(dt.day_of_week_str(datetime.date())?)?
                     } else {
-                        return Err(Error::FormatError(
-                            "date format not recognized".try_to_string()?,
-                        ));
+                        return Err(Error::InvalidFormat);
                     }
                 }
                 Field::DayOfYear => {
                     if T::HAS_DATE {
-                        w.write_str(dt.day_of_year_str())?
+                        write_number!(dt.day_of_year_str())
                     } else {
-                        return Err(Error::FormatError(
-                            "date format not recognized".try_to_string()?,
-                        ));
+                        return Err(Error::InvalidFormat);
                     }
                 }
                 Field::WeekOfMonth => {
                     if T::HAS_DATE {
-                        w.write_str(dt.week_of_month_str())?
+                        write_number!(dt.week_of_month_str())
                     } else {
-                        return Err(Error::FormatError(
-                            "date format not recognized".try_to_string()?,
-                        ));
+                        return Err(Error::InvalidFormat);
                     }
                 }
                 Field::WeekOfYear => {
                     if T::HAS_DATE {
-                        w.write_str(dt.week_of_year_str())?
+                        write_number!(dt.week_of_year_str())
+                    } else {
+                        return Err(Error::InvalidFormat);
+                    }
+                }
+                Field::Quarter => {
+                    if T::HAS_DATE {
+                        write_u32(&mut w, dt.quarter(), 1)?;
+                    } else {
+                        return Err(Error::InvalidFormat);
+                    }
+                }
+                Field::IsoWeek => {
+                    if T::HAS_DATE {
+                        let week = dt.iso_week(datetime.date())?;
+                        let width = if fill { 1 } else { 2 };
+                        write_u32(&mut w, week, width)?;
+                    } else {
+                        return Err(Error::InvalidFormat);
+                    }
+                }
+                Field::Julian => {
+                    if T::HAS_DATE {
+                        write_u32(&mut w, dt.julian() as u32, 7)?;
+                    } else {
+                        return Err(Error::InvalidFormat);
+                    }
+                }
+                Field::SecondsPastMidnight => {
+                    if T::HAS_TIME && !T::IS_INTERVAL_DT {
+                        let width = if fill { 1 } else { 5 };
+                        write_u32(&mut w, dt.seconds_past_midnight(), width)?;
+                    } else {
+                        return Err(Error::InvalidFormat);
+                    }
+                }
+                Field::IsoYear(n) => {
+                    if T::HAS_DATE {
+                        let year = dt.iso_year(datetime.date())?.abs()
+                            % (YEAR_MODIFIER[*n as usize - 1] as i32);
+                        let width = if fill { 1 } else { *n as usize };
+                        write_u32(&mut w, year as u32, width)?;
+                    } else {
+                        return Err(Error::InvalidFormat);
+                    }
+                }
+                Field::TimeZoneOffset | Field::TimeZone => {
+                    if !T::IS_INTERVAL_YM && !T::IS_INTERVAL_DT {
+                        // Date and time values have no time zone, which are taken as UTC
+                        if *field == Field::TimeZoneOffset {
+                            w.write_str("+00")?;
+                        }
+                    } else {
+                        return Err(Error::InvalidFormat);
+                    }
+                }
+                Field::SundayWeek | Field::MondayWeek => {
+                    if T::HAS_DATE {
+                        let first_day = if *field == Field::SundayWeek {
+                            WeekDay::Sunday
+                        } else {
+                            WeekDay::Monday
+                        };
+                        let week = dt.week_of_year_from(datetime.date(), first_day)?;
+                        let width = if fill { 1 } else { 2 };
+                        write_u32(&mut w, week, width)?;
+                    } else {
+                        return Err(Error::InvalidFormat);
+                    }
+                }
+                Field::FourDayWeek => {
+                    if T::HAS_DATE {
+                        let week = dt.four_day_week(datetime.date())?;
+                        let width = if fill { 1 } else { 2 };
+                        write_u32(&mut w, week, width)?;
+                    } else {
+                        return Err(Error::InvalidFormat);
+                    }
+                }
+                Field::SundayYearWeek | Field::SundayYear => {
+                    if T::HAS_DATE {
+                        let (year, week) = dt.sunday_year_week(datetime.date())?;
+                        if *field == Field::SundayYearWeek {
+                            let width = if fill { 1 } else { 2 };
+                            write_u32(&mut w, week, width)?;
+                        } else {
+                            let width = if fill { 1 } else { 4 };
+                            write_u32(&mut w, year.unsigned_abs(), width)?;
+                        }
+                    } else {
+                        return Err(Error::InvalidFormat);
+                    }
+                }
+                Field::IsoDayOfWeek | Field::ZeroDayOfWeek => {
+                    if T::HAS_DATE {
+                        let week_day = dt.week_day(datetime.date())? as usize;
+                        let number = if *field == Field::IsoDayOfWeek {
+                            (week_day + 5) % 7 + 1
+                        } else {
+                            week_day - 1
+                        };
+                        w.write_str(DAY_OF_WEEK_TABLE[number])?
+                    } else {
+                        return Err(Error::InvalidFormat);
+                    }
+                }
+                Field::UnixTime => {
+                    if T::HAS_DATE {
+                        write!(w, "{}", dt.unix_time())?
+                    } else {
+                        return Err(Error::InvalidFormat);
+                    }
+                }
+                Field::Suffix(suffix, style) => {
+                    let numeric_field = &self.fields[i - 1];
+                    let number = numeric_field.number::<T>(&dt, datetime.date())?;
+                    if *suffix == Suffix::Ordinal {
+                        w.write_str(ordinal_suffix(number.unsigned_abs(), *style))?;
+                    } else {
+                        if matches!(numeric_field, Field::SignedYear | Field::SignedCentury) {
+                            write_sign(&mut w, number < 0, fill)?;
+                        }
+                        let mut spelled = SpelledNumber::new();
+                        spelled.push_number(number.unsigned_abs());
+                        if *suffix == Suffix::SpelledOrdinal {
+                            spelled.make_ordinal();
+                        }
+                        spelled.write(&mut w, *style)?;
+                    }
+                }
+                Field::YearName(style) | Field::SignedYearName(style) => {
+                    if T::HAS_DATE {
+                        if let Field::SignedYearName(_) = field {
+                            write_sign(&mut w, dt.year() < 0, fill)?;
+                        }
+                        let mut spelled = SpelledNumber::new();
+                        spelled.push_year(dt.year().unsigned_abs());
+                        spelled.write(&mut w, *style)?;
                     } else {
-                        return Err(Error::FormatError(
-                            "date format not recognized".try_to_string()?,
-                        ));
+                        return Err(Error::InvalidFormat);
                     }
                 }
             }
@@ -1241,22 +2382,9 @@ impl Formatter {
     /// Parses datetime types
     #[inline]
     pub fn parse<S: AsRef<str>, T: DateTimeFormat>(&self, input: S) -> Result<T> {
-        let result = match self.format_exact {
+        match self.format_exact {
             true => self.parse_internal::<S, T, true>(input),
             false => self.parse_internal::<S, T, false>(input),
-        };
-        if T::IS_INTERVAL_YM || T::IS_INTERVAL_DT {
-            match result {
-                Ok(_) => result,
-                Err(e) => match e {
-                    Error::ParseError(_) => Err(Error::ParseError(
-                        "the interval is invalid".try_to_string()?,
-                    )),
-                    _ => Err(e),
-                },
-            }
-        } else {
-            result
         }
     }
 
@@ -1269,15 +2397,41 @@ impl Formatter {
 
         let mut dt = NaiveDateTime::new();
 
+        // Whether the FX modifier is in effect for the current field
+        let mut exact = false;
+        // Whether the FM modifier is in effect for the current field
+        let mut fill = false;
+
+        macro_rules! is_exact {
+            () => {
+                FX && exact
+            };
+        }
+
+        // In FX mode, numbers must have the exact width of the format item unless FM is in effect
+        macro_rules! is_exact_width {
+            () => {
+                is_exact!() && !fill
+            };
+        }
+
+        // In FX mode, full month and day names must be padded unless FM is in effect
+        macro_rules! expect_padding {
+            ($name_len: expr, $max_len: expr) => {{
+                if is_exact_width!() {
+                    for _ in $name_len..$max_len {
+                        expect_char!(b' ');
+                    }
+                }
+            }};
+        }
+
         macro_rules! expect_char {
             ($ch: expr) => {{
                 if expect_char(s, $ch) {
                     s = &s[1..];
                 } else {
-                    return Err(Error::ParseError(try_format!(
-                        "the input {} is inconsistent with the format",
-                        input.as_ref()
-                    )?));
+                    return Err(Error::LiteralMismatch);
                 }
             }};
         }
@@ -1286,33 +2440,48 @@ impl Formatter {
             ($ch: expr) => {{
                 if expect_char(s, $ch) {
                     s = &s[1..];
-                } else if s.is_empty() {
+                } else if s.is_empty() && !is_exact!() {
                     continue;
                 } else {
-                    return Err(Error::ParseError(try_format!(
-                        "the input {} is inconsistent with the format",
-                        input.as_ref()
-                    )?));
+                    return Err(Error::LiteralMismatch);
                 }
             }};
         }
 
-        macro_rules! expect_number {
-            ($max_len: expr) => {{
-                let (neg, n, rem) = parse_number(s, $max_len)?;
-                s = rem;
-                (n, neg)
+        // In the Postgres dialect, a separator matches any single non-alphanumeric character
+        // or nothing unless in FX mode
+        macro_rules! expect_separator {
+            ($ch: expr, $expect: ident) => {{
+                if self.dialect == Dialect::Postgres && !is_exact!() {
+                    if matches!(s.first(), Some(ch) if ch.is_ascii() && !ch.is_ascii_alphanumeric())
+                    {
+                        s = &s[1..];
+                    }
+                } else {
+                    $expect!($ch)
+                }
+            }};
+        }
+
+        macro_rules! expect_number {
+            ($max_len: expr) => {{
+                let (neg, n, rem) = if is_exact_width!() && !T::IS_INTERVAL_YM && !T::IS_INTERVAL_DT
+                {
+                    parse_number_exact(s, $max_len)?
+                } else {
+                    parse_number(s, $max_len)?
+                };
+                s = rem;
+                (n, neg)
             }};
         }
 
         macro_rules! expect_number_with_tolerance {
             ($max_len: expr, $default: expr) => {{
-                if s.is_empty() {
+                if s.is_empty() && !is_exact!() {
                     ($default, $default < 0)
                 } else {
-                    let (neg, n, rem) = parse_number(s, $max_len)?;
-                    s = rem;
-                    (n, neg)
+                    expect_number!($max_len)
                 }
             }};
         }
@@ -1329,6 +2498,15 @@ impl Formatter {
 
         let mut dow: Option<WeekDay> = None;
         let mut doy: Option<u32> = None;
+        let mut iso_year: Option<i32> = None;
+        let mut iso_week: Option<u32> = None;
+        let mut julian: Option<i32> = None;
+        let mut is_bc: Option<bool> = None;
+        let mut seconds: Option<u32> = None;
+        // The week of year, the first day of week and whether week 1 is the first week with 4 or more days
+        let mut week_of_year: Option<(u32, WeekDay, bool)> = None;
+        let mut week_year: Option<i32> = None;
+        let mut unix_time: Option<i64> = None;
         let mut now: Option<chrono::NaiveDateTime> = None;
         let mut get_now = || {
             if now.is_none() {
@@ -1337,420 +2515,872 @@ impl Formatter {
             now.unwrap()
         };
 
-        for field in self.fields.iter() {
-            if !FX {
-                s = eat_whitespaces(s);
-            }
-            match field {
-                // todo ignore the absence of symbols; Format exact
-                Field::Invalid => unreachable!(),
-                Field::Blank(_) => {}
-                Field::Hyphen => expect_char_with_tolerence!(b'-'),
-                Field::Colon => expect_char_with_tolerence!(b':'),
-                Field::Slash => expect_char!(b'/'),
-                Field::Backslash => expect_char!(b'\\'),
-                Field::Comma => expect_char!(b','),
-                Field::Dot => expect_char_with_tolerence!(b'.'),
-                Field::Semicolon => expect_char!(b';'),
-                Field::T => expect_char!(b'T'),
-                Field::Year(n) => {
-                    if T::HAS_DATE || T::IS_INTERVAL_YM {
-                        if is_year_set {
-                            return Err(Error::ParseError(
-                                "format code (year) appears twice".try_to_string()?,
-                            ));
+        // The field being matched and the input from which it is matched, which locate parse errors
+        let mut current: Option<(&Field, &[u8])> = None;
+        // The fields of the date and time, which locate the range errors found once all fields are matched
+        let mut month_at: Option<(&Field, &[u8])> = None;
+        let mut day_at: Option<(&Field, &[u8])> = None;
+        let mut hour_at: Option<(&Field, &[u8])> = None;
+        let mut minute_at: Option<(&Field, &[u8])> = None;
+        let mut second_at: Option<(&Field, &[u8])> = None;
+        let mut fraction_at: Option<(&Field, &[u8])> = None;
+        let result = (|| -> Result<()> {
+            for field in self.fields.iter() {
+                match field {
+                    Field::FormatExact => {
+                        exact = !exact;
+                        continue;
+                    }
+                    Field::FillMode => {
+                        fill = !fill;
+                        continue;
+                    }
+                    Field::SpacePadding => continue,
+                    _ => {}
+                }
+                if !is_exact!() {
+                    s = eat_whitespaces(s);
+                }
+                current = Some((field, s));
+                match field {
+                    Field::Month | Field::MonthName(_) | Field::RomanMonth(_) => month_at = current,
+                    Field::Day => day_at = current,
+                    Field::Hour24 | Field::Hour12 => hour_at = current,
+                    Field::Minute => minute_at = current,
+                    Field::Second => second_at = current,
+                    Field::Fraction(_) => fraction_at = current,
+                    _ => {}
+                }
+                match field {
+                    Field::Invalid
+                    | Field::FormatExact
+                    | Field::FillMode
+                    | Field::SpacePadding
+                    | Field::LongDate
+                    | Field::ShortDate
+                    | Field::ShortTime => unreachable!(),
+                    Field::Blank(n) => {
+                        if is_exact!() {
+                            for _ in 0..*n {
+                                expect_char!(b' ');
+                            }
+                        } else if self.dialect == Dialect::Postgres {
+                            // A space also matches a single separator in the input.
+                            expect_separator!(b' ', expect_char)
+                        }
+                    }
+                    Field::Hyphen => expect_separator!(b'-', expect_char_with_tolerence),
+                    Field::Colon => expect_separator!(b':', expect_char_with_tolerence),
+                    Field::Slash => expect_separator!(b'/', expect_char),
+                    Field::Backslash => expect_separator!(b'\\', expect_char),
+                    Field::Comma => expect_separator!(b',', expect_char),
+                    Field::Dot => expect_separator!(b'.', expect_char_with_tolerence),
+                    Field::Semicolon => expect_separator!(b';', expect_char),
+                    Field::T => match self.dialect {
+                        Dialect::Postgres => s = skip_chars(s, 1),
+                        _ => expect_char!(b'T'),
+                    },
+                    Field::Literal(text) => match self.dialect {
+                        Dialect::Postgres
+                            if text.len() == 1 && !text.as_bytes()[0].is_ascii_alphanumeric() =>
+                        {
+                            expect_separator!(text.as_bytes()[0], expect_char)
                         }
-                        let len = if T::IS_INTERVAL_YM {
-                            T::YEAR_MAX_LENGTH
+                        Dialect::Postgres => s = skip_chars(s, text.chars().count()),
+                        _ => s = parse_literal(s, text, is_exact!())?,
+                    },
+                    Field::Year(n) => {
+                        if T::HAS_DATE || T::IS_INTERVAL_YM {
+                            if is_year_set {
+                                return Err(Error::DuplicateField(DateTimeField::Year));
+                            }
+                            let len = if T::IS_INTERVAL_YM {
+                                T::YEAR_MAX_LENGTH
+                            } else {
+                                *n as usize
+                            };
+                            let (negative, mut year, rem) =
+                                parse_year(s, len, is_exact_width!() && T::HAS_DATE, &mut get_now)?;
+                            // MySQL takes years of one or two digits as two-digit years
+                            if self.dialect == Dialect::MySql
+                                && T::HAS_DATE
+                                && s.len() - rem.len() <= 2
+                            {
+                                year = mysql_year(year);
+                            }
+                            if negative && T::HAS_DATE {
+                                return Err(Error::DateOutOfRange);
+                            }
+                            dt.negative = negative;
+                            dt.year = year;
+                            s = rem;
+                            is_year_set = true;
                         } else {
-                            *n as usize
-                        };
-                        let (negative, year, rem) = parse_year(s, len, &mut get_now)?;
-                        if negative && T::HAS_DATE {
-                            return Err(Error::ParseError(
-                                "(full) year must be between 1 and 9999".try_to_string()?,
-                            ));
+                            return Err(Error::InvalidFormat);
                         }
-                        dt.negative = negative;
-                        dt.year = year;
-                        s = rem;
-                        is_year_set = true;
-                    } else {
-                        return Err(Error::ParseError(
-                            "date format not recognized".try_to_string()?,
-                        ));
                     }
-                }
-                Field::Month => {
-                    if T::HAS_DATE || T::IS_INTERVAL_YM {
-                        if is_month_set {
-                            return Err(Error::ParseError(
-                                "format code (month) appears twice".try_to_string()?,
-                            ));
+                    Field::Century | Field::SignedCentury => {
+                        return Err(Error::UnsupportedField(DateTimeField::Century))
+                    }
+                    Field::SignedYear | Field::CommaYear => {
+                        if T::HAS_DATE {
+                            if is_year_set {
+                                return Err(Error::DuplicateField(DateTimeField::Year));
+                            }
+                            let (negative, year, rem) = if *field == Field::SignedYear {
+                                parse_signed_year(s, is_exact_width!())?
+                            } else {
+                                parse_comma_year(s, is_exact!())?
+                            };
+                            if negative && *field == Field::CommaYear {
+                                return Err(Error::DateOutOfRange);
+                            }
+                            dt.year = year;
+                            s = rem;
+                            is_year_set = true;
+                        } else {
+                            return Err(Error::InvalidFormat);
+                        }
+                    }
+                    Field::RoundYear(n) => {
+                        if T::HAS_DATE {
+                            if is_year_set {
+                                return Err(Error::DuplicateField(DateTimeField::Year));
+                            }
+                            let (negative, year, rem) = if self.dialect == Dialect::MySql {
+                                let (negative, year, rem) = parse_number(s, *n as usize)?;
+                                (negative, mysql_year(year), rem)
+                            } else if self.dialect == Dialect::Strftime {
+                                let (negative, year, rem) = parse_number(s, *n as usize)?;
+                                (negative, posix_year(year), rem)
+                            } else {
+                                parse_round_year(s, *n as usize, is_exact_width!(), &mut get_now)?
+                            };
+                            if negative {
+                                return Err(Error::DateOutOfRange);
+                            }
+                            dt.year = year;
+                            s = rem;
+                            is_year_set = true;
+                        } else {
+                            return Err(Error::InvalidFormat);
                         }
+                    }
+                    Field::Month => {
+                        if T::HAS_DATE || T::IS_INTERVAL_YM {
+                            if is_month_set {
+                                return Err(Error::DuplicateField(DateTimeField::Month));
+                            }
 
-                        let month = match parse_number(s, T::MONTH_MAX_LENGTH) {
-                            Ok((negative, month, rem)) => {
-                                if negative {
-                                    return Err(Error::ParseError(
-                                        "not a valid month".try_to_string()?,
-                                    ));
+                            let month = match if is_exact_width!() && T::HAS_DATE {
+                                parse_number_exact(s, T::MONTH_MAX_LENGTH)
+                            } else {
+                                parse_number(s, T::MONTH_MAX_LENGTH)
+                            } {
+                                Ok((negative, month, rem)) => {
+                                    if negative {
+                                        return Err(Error::InvalidMonth);
+                                    }
+                                    s = rem;
+                                    month as u32
                                 }
-                                s = rem;
-                                month as u32
+                                // Under FX a short number is a length mismatch, not a month name
+                                Err(e) if matches!(s.first(), Some(ch) if ch.is_ascii_digit()) => {
+                                    return Err(e)
+                                }
+                                Err(_) => {
+                                    let (month, _, rem) = parse_month_name(s, self.locale)?;
+                                    s = rem;
+                                    month as u32
+                                }
+                            };
+
+                            dt.month = month;
+                            is_month_set = true;
+                        } else {
+                            return Err(Error::InvalidFormat);
+                        }
+                    }
+                    Field::Day => {
+                        if T::HAS_DATE || T::IS_INTERVAL_DT {
+                            if is_day_set {
+                                return Err(Error::DuplicateField(DateTimeField::Day));
                             }
-                            Err(_) => {
-                                let (month, rem) = parse_month_name(s)?;
-                                s = rem;
-                                month as u32
+                            let (day, negative) = expect_number!(T::DAY_MAX_LENGTH);
+                            if T::HAS_DATE && negative {
+                                return Err(Error::InvalidDay);
                             }
-                        };
-
-                        dt.month = month;
-                        is_month_set = true;
-                    } else {
-                        return Err(Error::ParseError(
-                            "date format not recognized".try_to_string()?,
-                        ));
+                            dt.day = day.unsigned_abs();
+                            dt.negative = negative;
+                            is_day_set = true;
+                        } else {
+                            return Err(Error::InvalidFormat);
+                        }
                     }
-                }
-                Field::Day => {
-                    if T::HAS_DATE || T::IS_INTERVAL_DT {
-                        if is_day_set {
-                            return Err(Error::ParseError(
-                                "format code (day) appears twice".try_to_string()?,
-                            ));
+                    Field::Hour24 => {
+                        if T::HAS_TIME {
+                            if is_hour24_set.is_some() {
+                                return Err(Error::DuplicateField(DateTimeField::Hour));
+                            }
+                            if dt.ampm.is_some() {
+                                return Err(Error::HourPrecludesMeridian);
+                            }
+                            let (hour, negative) = if T::IS_INTERVAL_DT {
+                                expect_number!(T::HOUR_MAX_LENGTH)
+                            } else {
+                                expect_number_with_tolerance!(T::HOUR_MAX_LENGTH, 0)
+                            };
+                            if negative {
+                                return Err(Error::TimeOutOfRange);
+                            }
+                            dt.hour = hour as u32;
+                            is_hour24_set = Some(true);
+                        } else {
+                            return Err(Error::InvalidFormat);
                         }
-                        let (day, negative) = expect_number!(T::DAY_MAX_LENGTH);
-                        if T::HAS_DATE && negative {
-                            return Err(Error::ParseError(
-                                "day of month must be between 1 and last day of month"
-                                    .try_to_string()?,
-                            ));
+                    }
+                    Field::Hour12 => {
+                        if T::HAS_TIME && !T::IS_INTERVAL_DT {
+                            if is_hour24_set.is_some() {
+                                return Err(Error::DuplicateField(DateTimeField::Hour));
+                            }
+                            let (hour, negative) =
+                                expect_number_with_tolerance!(T::HOUR_MAX_LENGTH, 12);
+                            if negative || !(1..=12).contains(&hour) {
+                                return Err(Error::InvalidHour12);
+                            }
+                            dt.hour = hour as u32;
+                            dt.adjust_hour12();
+                            is_hour24_set = Some(false);
+                        } else {
+                            return Err(Error::InvalidFormat);
                         }
-                        dt.day = day.unsigned_abs();
-                        dt.negative = negative;
-                        is_day_set = true;
-                    } else {
-                        return Err(Error::ParseError(
-                            "date format not recognized".try_to_string()?,
-                        ));
                     }
-                }
-                Field::Hour24 => {
-                    if T::HAS_TIME {
-                        if is_hour24_set.is_some() {
-                            return Err(Error::ParseError(
-                                "format code (hour) appears twice".try_to_string()?,
-                            ));
+                    Field::Minute => {
+                        if T::HAS_TIME {
+                            if is_min_set {
+                                return Err(Error::DuplicateField(DateTimeField::Minute));
+                            }
+                            let (minute, negative) = if T::IS_INTERVAL_DT {
+                                expect_number!(T::MINUTE_MAX_LENGTH)
+                            } else {
+                                expect_number_with_tolerance!(T::MINUTE_MAX_LENGTH, 0)
+                            };
+                            if negative {
+                                return Err(Error::InvalidMinute);
+                            }
+                            dt.minute = minute as u32;
+                            is_min_set = true;
+                        } else {
+                            return Err(Error::InvalidFormat);
                         }
-                        if dt.ampm.is_some() {
-                            return Err(Error::ParseError(
-                                "'HH24' precludes use of meridian indicator".try_to_string()?,
-                            ));
+                    }
+                    Field::Second => {
+                        if T::HAS_TIME {
+                            if is_sec_set {
+                                return Err(Error::DuplicateField(DateTimeField::Second));
+                            }
+                            let (sec, negative) = if T::IS_INTERVAL_DT {
+                                expect_number!(T::SECOND_MAX_LENGTH)
+                            } else {
+                                expect_number_with_tolerance!(T::SECOND_MAX_LENGTH, 0)
+                            };
+                            if negative {
+                                return Err(Error::InvalidSecond);
+                            }
+                            dt.sec = sec as u32;
+                            is_sec_set = true;
+                        } else {
+                            return Err(Error::InvalidFormat);
                         }
-                        let (hour, negative) = if T::IS_INTERVAL_DT {
-                            expect_number!(T::HOUR_MAX_LENGTH)
+                    }
+                    Field::Fraction(p) => {
+                        if T::HAS_FRACTION {
+                            if is_fraction_set {
+                                return Err(Error::DuplicateField(DateTimeField::Fraction));
+                            }
+                            // When parsing, if FF is given, the default precision is 9
+                            let (usec, rem) = parse_fraction(
+                                s,
+                                p.unwrap_or(9) as usize,
+                                is_exact!() && p.is_some(),
+                            )?;
+                            s = rem;
+                            dt.usec = usec;
+                            is_fraction_set = true;
                         } else {
-                            expect_number_with_tolerance!(T::HOUR_MAX_LENGTH, 0)
-                        };
-                        if negative {
-                            return Err(Error::ParseError(
-                                "hour must be between 0 and 23".try_to_string()?,
-                            ));
+                            return Err(Error::InvalidFormat);
                         }
-                        dt.hour = hour as u32;
-                        is_hour24_set = Some(true);
-                    } else {
-                        return Err(Error::ParseError(
-                            "date format not recognized".try_to_string()?,
-                        ));
                     }
-                }
-                Field::Hour12 => {
-                    if T::HAS_TIME && !T::IS_INTERVAL_DT {
-                        if is_hour24_set.is_some() {
-                            return Err(Error::ParseError(
-                                "format code (hour) appears twice".try_to_string()?,
-                            ));
+                    Field::Era(style) => {
+                        if T::HAS_DATE {
+                            if is_bc.is_some() {
+                                return Err(Error::DuplicateField(DateTimeField::Era));
+                            }
+                            let (bc, rem) = parse_era(s, style)?;
+                            s = rem;
+                            is_bc = Some(bc);
+                        } else {
+                            return Err(Error::InvalidFormat);
                         }
-                        let (hour, negative) =
-                            expect_number_with_tolerance!(T::HOUR_MAX_LENGTH, 12);
-                        if negative || hour < 1 || hour > 12 {
-                            return Err(Error::ParseError(
-                                "hour must be between 1 and 12".try_to_string()?,
-                            ));
+                    }
+                    Field::AmPm(style) => {
+                        if T::HAS_TIME && !T::IS_INTERVAL_DT {
+                            if dt.ampm.is_some() {
+                                return Err(Error::DuplicateField(DateTimeField::Meridian));
+                            }
+                            if let Some(true) = is_hour24_set {
+                                return Err(Error::HourPrecludesMeridian);
+                            }
+                            let (am_pm, rem) = parse_ampm(s, style, self.locale)?;
+                            s = rem;
+
+                            dt.ampm = am_pm;
+                            if dt.ampm.is_some() {
+                                dt.adjust_hour12();
+                            }
+                        } else {
+                            return Err(Error::InvalidFormat);
                         }
-                        dt.hour = hour as u32;
-                        dt.adjust_hour12();
-                        is_hour24_set = Some(false);
-                    } else {
-                        return Err(Error::ParseError(
-                            "date format not recognized".try_to_string()?,
-                        ));
                     }
-                }
-                Field::Minute => {
-                    if T::HAS_TIME {
-                        if is_min_set {
-                            return Err(Error::ParseError(
-                                "format code (minute) appears twice".try_to_string()?,
-                            ));
+                    Field::MonthName(style) => {
+                        if T::HAS_DATE {
+                            if is_month_set {
+                                return Err(Error::DuplicateField(DateTimeField::Month));
+                            }
+                            let (month, name_len, rem) = parse_month_name(s, self.locale)?;
+                            s = rem;
+                            if !style.is_abbr() {
+                                expect_padding!(name_len, self.locale.month_name_max_len());
+                            }
+
+                            dt.month = month as u32;
+                            is_month_set = true;
+                        } else {
+                            return Err(Error::InvalidFormat);
                         }
-                        let (minute, negative) = if T::IS_INTERVAL_DT {
-                            expect_number!(T::MINUTE_MAX_LENGTH)
+                    }
+                    Field::RomanMonth(_) => {
+                        if T::HAS_DATE {
+                            if is_month_set {
+                                return Err(Error::DuplicateField(DateTimeField::Month));
+                            }
+                            let (month, rem) = parse_roman_month(s)?;
+                            let roman_len = s.len() - rem.len();
+                            s = rem;
+                            expect_padding!(roman_len, ROMAN_MONTH_MAX_LENGTH);
+
+                            dt.month = month as u32;
+                            is_month_set = true;
                         } else {
-                            expect_number_with_tolerance!(T::MINUTE_MAX_LENGTH, 0)
-                        };
-                        if negative {
-                            return Err(Error::ParseError(
-                                "minutes must be between 0 and 59".try_to_string()?,
-                            ));
+                            return Err(Error::InvalidFormat);
                         }
-                        dt.minute = minute as u32;
-                        is_min_set = true;
-                    } else {
-                        return Err(Error::ParseError(
-                            "date format not recognized".try_to_string()?,
-                        ));
                     }
-                }
-                Field::Second => {
-                    if T::HAS_TIME {
-                        if is_sec_set {
-                            return Err(Error::ParseError(
-                                "format code (second) appears twice".try_to_string()?,
-                            ));
+                    Field::DayName(style) => {
+                        if T::HAS_DATE {
+                            if dow.is_some() {
+                                return Err(Error::DuplicateField(DateTimeField::DayOfWeek));
+                            }
+                            let (d, name_len, rem) = parse_week_day_name(s, *style, self.locale)?;
+                            s = rem;
+                            if !style.is_abbr() {
+                                expect_padding!(name_len, self.locale.day_name_max_len());
+                            }
+
+                            dow = Some(d);
+                        } else {
+                            return Err(Error::InvalidFormat);
                         }
-                        let (sec, negative) = if T::IS_INTERVAL_DT {
-                            expect_number!(T::SECOND_MAX_LENGTH)
+                    }
+                    Field::DayOfWeek => {
+                        if T::HAS_DATE {
+                            if dow.is_some() {
+                                return Err(Error::DuplicateField(DateTimeField::DayOfWeek));
+                            }
+                            let (d, rem) = parse_week_day_number(s)?;
+                            s = rem;
+
+                            dow = Some(d);
                         } else {
-                            expect_number_with_tolerance!(T::SECOND_MAX_LENGTH, 0)
-                        };
-                        if negative {
-                            return Err(Error::ParseError(
-                                "seconds must be between 0 and 59".try_to_string()?,
-                            ));
+                            return Err(Error::InvalidFormat);
                         }
-                        dt.sec = sec as u32;
-                        is_sec_set = true;
-                    } else {
-                        return Err(Error::ParseError(
-                            "date format not recognized".try_to_string()?,
-                        ));
                     }
-                }
-                Field::Fraction(p) => {
-                    if T::HAS_FRACTION {
-                        if is_fraction_set {
-                            return Err(Error::ParseError(
-                                "format code (fraction) appears twice".try_to_string()?,
-                            ));
+                    Field::DayOfYear => {
+                        if T::HAS_DATE {
+                            if doy.is_some() {
+                                return Err(Error::DuplicateField(DateTimeField::DayOfYear));
+                            }
+                            let (days, negative) = expect_number!(T::DAY_OF_YEAR_MAX_LENGTH);
+                            if negative {
+                                return Err(Error::InvalidDayOfYear);
+                            }
+                            doy = Some(days as u32);
+                        } else {
+                            return Err(Error::InvalidFormat);
                         }
-                        // When parsing, if FF is given, the default precision is 9
-                        let (usec, rem) = parse_fraction(s, p.unwrap_or(9) as usize)?;
-                        s = rem;
-                        dt.usec = usec;
-                        is_fraction_set = true;
-                    } else {
-                        return Err(Error::ParseError(
-                            "date format not recognized".try_to_string()?,
-                        ));
                     }
-                }
-                Field::AmPm(style) => {
-                    if T::HAS_TIME && !T::IS_INTERVAL_DT {
-                        if dt.ampm.is_some() {
-                            return Err(Error::ParseError(
-                                "format code (am/pm) appears twice".try_to_string()?,
-                            ));
+                    Field::WeekOfMonth => {
+                        return Err(Error::UnsupportedField(DateTimeField::WeekOfMonth))
+                    }
+                    Field::WeekOfYear => {
+                        return Err(Error::UnsupportedField(DateTimeField::WeekOfYear))
+                    }
+                    Field::Quarter => {
+                        if self.dialect == Dialect::Postgres {
+                            expect_number!(1);
+                        } else {
+                            return Err(Error::UnsupportedField(DateTimeField::Quarter));
                         }
-                        if let Some(true) = is_hour24_set {
-                            return Err(Error::ParseError(
-                                "'HH24' precludes use of meridian indicator".try_to_string()?,
-                            ));
+                    }
+                    Field::TimeZoneOffset | Field::TimeZone => {
+                        return Err(Error::UnsupportedField(DateTimeField::TimeZone))
+                    }
+                    // MySQL skips the suffix of '%D'
+                    Field::Suffix(Suffix::Ordinal, _) if self.dialect == Dialect::MySql => {
+                        let len = s
+                            .iter()
+                            .take(2)
+                            .take_while(|ch| ch.is_ascii_alphabetic())
+                            .count();
+                        s = &s[len..];
+                    }
+                    Field::Suffix(..) => {
+                        return Err(Error::UnsupportedField(DateTimeField::Suffix))
+                    }
+                    Field::YearName(_) | Field::SignedYearName(_) => {
+                        return Err(Error::UnsupportedField(DateTimeField::SpelledYear))
+                    }
+                    Field::IsoWeek => {
+                        if T::HAS_DATE {
+                            if iso_week.is_some() {
+                                return Err(Error::DuplicateField(DateTimeField::IsoWeek));
+                            }
+                            let (week, negative) = expect_number!(2);
+                            if negative || !(1..=53).contains(&week) {
+                                return Err(Error::InvalidWeekOfYear(1));
+                            }
+                            iso_week = Some(week as u32);
+                        } else {
+                            return Err(Error::InvalidFormat);
                         }
-                        let (am_pm, rem) = parse_ampm(s, style)?;
-                        s = rem;
-
-                        dt.ampm = am_pm;
-                        if dt.ampm.is_some() {
-                            dt.adjust_hour12();
+                    }
+                    Field::Julian => {
+                        if T::HAS_DATE {
+                            if julian.is_some() {
+                                return Err(Error::DuplicateField(DateTimeField::Julian));
+                            }
+                            let (j, negative) = expect_number!(7);
+                            if negative || !(DATE_MIN_JULIAN..=DATE_MAX_JULIAN).contains(&j) {
+                                return Err(Error::InvalidJulianDate);
+                            }
+                            julian = Some(j);
+                        } else {
+                            return Err(Error::InvalidFormat);
                         }
-                    } else {
-                        return Err(Error::ParseError(
-                            "date format not recognized".try_to_string()?,
-                        ));
                     }
-                }
-                Field::MonthName(_) => {
-                    if T::HAS_DATE {
-                        if is_month_set {
-                            return Err(Error::ParseError(
-                                "format code (month) appears twice".try_to_string()?,
-                            ));
+                    Field::SecondsPastMidnight => {
+                        if T::HAS_TIME && !T::IS_INTERVAL_DT {
+                            if seconds.is_some() {
+                                return Err(Error::DuplicateField(DateTimeField::SecondsInDay));
+                            }
+                            let (secs, negative) = expect_number!(5);
+                            if negative || secs >= SECONDS_PER_DAY as i32 {
+                                return Err(Error::InvalidSecondsInDay);
+                            }
+                            seconds = Some(secs as u32);
+                        } else {
+                            return Err(Error::InvalidFormat);
                         }
-                        let (month, rem) = parse_month_name(s)?;
-                        s = rem;
-
-                        dt.month = month as u32;
-                        is_month_set = true;
-                    } else {
-                        return Err(Error::ParseError(
-                            "date format not recognized".try_to_string()?,
-                        ));
                     }
-                }
-                Field::DayName(style) => {
-                    if T::HAS_DATE {
-                        if dow.is_some() {
-                            return Err(Error::ParseError(
-                                "format code (day of week) appears twice".try_to_string()?,
-                            ));
+                    Field::SundayWeek
+                    | Field::MondayWeek
+                    | Field::FourDayWeek
+                    | Field::SundayYearWeek => {
+                        if T::HAS_DATE {
+                            if week_of_year.is_some() {
+                                return Err(Error::DuplicateField(DateTimeField::WeekOfYear));
+                            }
+                            let (week, negative) = expect_number!(2);
+                            let min = (*field == Field::SundayYearWeek) as i32;
+                            if negative || !(min..=53).contains(&week) {
+                                return Err(Error::InvalidWeekOfYear(min as u32));
+                            }
+                            let (first_day, four_days) = match field {
+                                Field::MondayWeek => (WeekDay::Monday, false),
+                                Field::FourDayWeek => (WeekDay::Monday, true),
+                                _ => (WeekDay::Sunday, false),
+                            };
+                            week_of_year = Some((week as u32, first_day, four_days));
+                        } else {
+                            return Err(Error::InvalidFormat);
                         }
-                        let (d, rem) = parse_week_day_name(s, *style)?;
-                        s = rem;
-
-                        dow = Some(d);
-                    } else {
-                        return Err(Error::ParseError(
-                            "date format not recognized".try_to_string()?,
-                        ));
                     }
-                }
-                Field::DayOfWeek => {
-                    if T::HAS_DATE {
-                        if dow.is_some() {
-                            return Err(Error::ParseError(
-                                "format code (day of week) appears twice".try_to_string()?,
-                            ));
+                    Field::SundayYear => {
+                        if T::HAS_DATE {
+                            if week_year.is_some() {
+                                return Err(Error::DuplicateField(DateTimeField::WeekYear));
+                            }
+                            let (negative, year, rem) =
+                                parse_year(s, 4, is_exact_width!(), &mut get_now)?;
+                            if negative {
+                                return Err(Error::DateOutOfRange);
+                            }
+                            week_year = Some(year);
+                            s = rem;
+                        } else {
+                            return Err(Error::InvalidFormat);
                         }
-                        let (d, rem) = parse_week_day_number(s)?;
-                        s = rem;
-
-                        dow = Some(d);
-                    } else {
-                        return Err(Error::ParseError(
-                            "date format not recognized".try_to_string()?,
-                        ));
                     }
-                }
-                Field::DayOfYear => {
-                    if T::HAS_DATE {
-                        if doy.is_some() {
-                            return Err(Error::ParseError(
-                                "format code (day of year) appears twice".try_to_string()?,
-                            ));
+                    Field::IsoDayOfWeek | Field::ZeroDayOfWeek => {
+                        if T::HAS_DATE {
+                            if dow.is_some() {
+                                return Err(Error::DuplicateField(DateTimeField::DayOfWeek));
+                            }
+                            let (number, negative) = expect_number!(1);
+                            let range = if *field == Field::IsoDayOfWeek {
+                                1..=7
+                            } else {
+                                0..=6
+                            };
+                            if negative || !range.contains(&number) {
+                                return Err(Error::InvalidDayOfWeek);
+                            }
+                            // Changes to 1..=7 (Sun..=Sat)
+                            dow = Some(WeekDay::from(number as usize % 7 + 1));
+                        } else {
+                            return Err(Error::InvalidFormat);
                         }
-                        let (days, negative) = expect_number!(T::DAY_OF_YEAR_MAX_LENGTH);
-                        if negative {
-                            return Err(Error::ParseError(
-                                "day of year must be between 1 and 365 (366 for leap year)"
-                                    .try_to_string()?,
-                            ));
+                    }
+                    Field::UnixTime => {
+                        if T::HAS_DATE {
+                            if unix_time.is_some() {
+                                return Err(Error::DuplicateField(DateTimeField::UnixTime));
+                            }
+                            let (secs, rem) = parse_unix_time(s)?;
+                            s = rem;
+                            unix_time = Some(secs);
+                        } else {
+                            return Err(Error::InvalidFormat);
+                        }
+                    }
+                    Field::IsoYear(n) => {
+                        if T::HAS_DATE {
+                            if iso_year.is_some() {
+                                return Err(Error::DuplicateField(DateTimeField::IsoYear));
+                            }
+                            let (negative, year, rem) =
+                                parse_year(s, *n as usize, is_exact_width!(), &mut get_now)?;
+                            if negative {
+                                return Err(Error::DateOutOfRange);
+                            }
+                            iso_year = Some(year);
+                            s = rem;
+                        } else {
+                            return Err(Error::InvalidFormat);
                         }
-                        doy = Some(days as u32);
-                    } else {
-                        return Err(Error::ParseError(
-                            "date format not recognized".try_to_string()?,
-                        ));
                     }
-                }
-                Field::WeekOfMonth => {
-                    return Err(Error::ParseError(
-                        "format code (week of month) cannot appear in date input format"
-                            .try_to_string()?,
-                    ))
-                }
-                Field::WeekOfYear => {
-                    return Err(Error::ParseError(
-                        "format code (week of year) cannot appear in date input format"
-                            .try_to_string()?,
-                    ))
                 }
             }
+            Ok(())
+        })();
+        if let Err(e) = result {
+            return Err(match current {
+                Some((field, s)) => input_error(e, input.as_ref(), s, Some(field)),
+                None => e,
+            });
         }
 
-        if !FX {
+        if !is_exact!() {
             s = eat_whitespaces(s);
         }
 
         if !s.is_empty() {
-            return Err(Error::ParseError(
-                "format picture ends before converting entire input string".try_to_string()?,
-            ));
+            return Err(input_error(Error::TrailingInput, input.as_ref(), s, None));
         }
 
-        if T::HAS_DATE {
-            match (is_year_set, is_month_set) {
-                (true, true) => {}
-                (true, false) => {
-                    let datetime_now = get_now();
-                    dt.month = datetime_now.month();
+        let result = (|| -> Result<T> {
+            if is_bc == Some(true) {
+                if dt.year < 0 {
+                    return Err(Error::SignedYearPrecludesEra);
                 }
-                (false, false) => {
-                    let datetime_now = get_now();
-                    dt.year = datetime_now.year();
-                    dt.month = datetime_now.month();
+                if is_year_set {
+                    dt.year = -dt.year;
                 }
-                (false, true) => {
-                    let datetime_now = get_now();
-                    dt.year = datetime_now.year();
+                if let Some(year) = iso_year.as_mut() {
+                    *year = -*year;
                 }
             }
-        }
 
-        if let Some(d) = doy {
-            let is_leap_year = is_leap_year(dt.year);
-            if d == 0 || (!is_leap_year && d > 365) || (is_leap_year && d > 366) {
-                return Err(Error::ParseError(
-                    "day of year must be between 1 and 365 (366 for leap year)".try_to_string()?,
-                ));
+            if let Some(secs) = unix_time {
+                if is_year_set
+                    || is_month_set
+                    || is_day_set
+                    || is_hour24_set.is_some()
+                    || is_min_set
+                    || is_sec_set
+                    || dt.ampm.is_some()
+                    || is_bc.is_some()
+                    || julian.is_some()
+                    || seconds.is_some()
+                    || doy.is_some()
+                    || iso_year.is_some()
+                    || iso_week.is_some()
+                    || week_of_year.is_some()
+                    || week_year.is_some()
+                {
+                    return Err(Error::ConflictingConventions);
+                }
+
+                let days = secs.div_euclid(SECONDS_PER_DAY as i64);
+                let date = match i32::try_from(days) {
+                    Ok(days) => Date::try_from_days(days)?,
+                    Err(_) => return Err(Error::DateOutOfRange),
+                };
+                let (year, month, day) = date.extract();
+                dt.year = year;
+                dt.month = month;
+                dt.day = day;
+                is_year_set = true;
+                is_month_set = true;
+                is_day_set = true;
+
+                if T::HAS_TIME {
+                    let secs = secs.rem_euclid(SECONDS_PER_DAY as i64) as u32;
+                    dt.hour = secs / SECONDS_PER_HOUR;
+                    dt.minute = secs / SECONDS_PER_MINUTE % MINUTES_PER_HOUR;
+                    dt.sec = secs % SECONDS_PER_MINUTE;
+                }
             }
-            let (month, day) = the_month_day_of_days(d, is_leap_year);
-            match (is_month_set, is_day_set) {
-                (true, true) => {
-                    if month != dt.month || day != dt.day {
-                        return Err(Error::ParseError(
-                            "day of year conflicts with Julian date".try_to_string()?,
-                        ));
+
+            if let Some(j) = julian {
+                let (year, month, day) = julian2date(j);
+                if is_year_set && year != dt.year {
+                    return Err(Error::ConflictsWithJulian(DateTimeField::Year));
+                }
+                if is_month_set && month != dt.month {
+                    return Err(Error::ConflictsWithJulian(DateTimeField::Month));
+                }
+                if is_day_set && day != dt.day {
+                    return Err(Error::ConflictsWithJulian(DateTimeField::Day));
+                }
+                if let Some(d) = doy.take() {
+                    if d != the_day_of_year(year, month, day) {
+                        return Err(Error::ConflictsWithJulian(DateTimeField::DayOfYear));
                     }
                 }
-                (true, false) => {
-                    if month != dt.month {
-                        return Err(Error::ParseError(
-                            "day of year conflicts with Julian date".try_to_string()?,
-                        ));
+
+                dt.year = year;
+                dt.month = month;
+                dt.day = day;
+                is_year_set = true;
+                is_month_set = true;
+                is_day_set = true;
+            }
+
+            if let Some(secs) = seconds {
+                let hour = secs / SECONDS_PER_HOUR;
+                let minute = secs / SECONDS_PER_MINUTE % MINUTES_PER_HOUR;
+                let sec = secs % SECONDS_PER_MINUTE;
+                let hour_conflicts = match (is_hour24_set, &dt.ampm) {
+                    (Some(_), _) => hour != dt.hour,
+                    (None, Some(AmPm::Am)) => hour >= 12,
+                    (None, Some(AmPm::Pm)) => hour < 12,
+                    (None, None) => false,
+                };
+                if hour_conflicts {
+                    return Err(Error::ConflictsWithSecondsInDay(DateTimeField::Hour));
+                }
+                if is_min_set && minute != dt.minute {
+                    return Err(Error::ConflictsWithSecondsInDay(DateTimeField::Minute));
+                }
+                if is_sec_set && sec != dt.sec {
+                    return Err(Error::ConflictsWithSecondsInDay(DateTimeField::Second));
+                }
+
+                dt.hour = hour;
+                dt.minute = minute;
+                dt.sec = sec;
+            }
+
+            // Builds the date from ISO week date, the day of week defaults to Monday
+            if iso_year.is_some() || iso_week.is_some() {
+                if is_year_set || is_month_set || is_day_set || doy.is_some() {
+                    return Err(Error::ConflictingConventions);
+                }
+
+                let year = match iso_year {
+                    Some(year) => year,
+                    None => {
+                        let now = get_now();
+                        Date::try_from_ymd(now.year(), now.month(), now.day())?.date_to_iso_year()
                     }
-                    dt.day = day;
+                };
+                let week = iso_week.unwrap_or(1);
+                // Changes to 1..=7 (Mon..=Sun)
+                let week_day = dow.take().map_or(1, |d| (d as u32 + 5) % 7 + 1);
+
+                let date = Date::try_from_iso_week_date(year, week, week_day)?;
+                if date.iso_week() != week {
+                    return Err(Error::WeekNotInYear);
+                }
+
+                let (year, month, day) = date.extract();
+                dt.year = year;
+                dt.month = month;
+                dt.day = day;
+                is_year_set = true;
+                is_month_set = true;
+            }
+
+            // Builds the date from the week of year, the day of week defaults to the first day of week
+            if let Some((week, first_day, four_days)) = week_of_year {
+                if is_month_set
+                    || is_day_set
+                    || doy.is_some()
+                    || (is_year_set && week_year.is_some())
+                {
+                    return Err(Error::ConflictingConventions);
                 }
-                (false, true) => {
-                    if day != dt.day {
-                        return Err(Error::ParseError(
-                            "day of year conflicts with Julian date".try_to_string()?,
-                        ));
+
+                let year = match week_year {
+                    Some(year) => year,
+                    None if is_year_set => dt.year,
+                    None => get_now().year(),
+                };
+                // Days from the first day of week
+                let offset = |d: WeekDay| (d as i32 + 7 - first_day as i32) % 7;
+                let first_offset = offset(Date::try_from_ymd(year, 1, 1)?.day_of_week());
+                let first_week_start = match (four_days, first_offset) {
+                    (true, 0..=3) => -first_offset,
+                    _ => (7 - first_offset) % 7,
+                };
+                let days = first_week_start + (week as i32 - 1) * 7 + dow.map_or(0, offset);
+                let days_of_year = if is_leap_year(year) { 366 } else { 365 };
+                if !(0..days_of_year).contains(&days) {
+                    return Err(Error::WeekNotInYear);
+                }
+
+                dt.year = year;
+                is_year_set = true;
+                doy = Some(days as u32 + 1);
+            } else if week_year.is_some() {
+                return Err(Error::ConflictingConventions);
+            }
+
+            if T::HAS_DATE {
+                match (is_year_set, is_month_set) {
+                    (true, true) => {}
+                    (true, false) => {
+                        let datetime_now = get_now();
+                        dt.month = datetime_now.month();
+                    }
+                    (false, false) => {
+                        let datetime_now = get_now();
+                        dt.year = datetime_now.year();
+                        dt.month = datetime_now.month();
+                    }
+                    (false, true) => {
+                        let datetime_now = get_now();
+                        dt.year = datetime_now.year();
                     }
-                    dt.month = month;
                 }
-                (false, false) => {
-                    dt.month = month;
-                    dt.day = day;
+            }
+
+            if let Some(d) = doy {
+                let is_leap_year = is_leap_year(dt.year);
+                if d == 0 || (!is_leap_year && d > 365) || (is_leap_year && d > 366) {
+                    return Err(Error::InvalidDayOfYear);
+                }
+                let (month, day) = the_month_day_of_days(d, is_leap_year);
+                match (is_month_set, is_day_set) {
+                    (true, true) => {
+                        if month != dt.month || day != dt.day {
+                            return Err(Error::ConflictsWithJulian(DateTimeField::DayOfYear));
+                        }
+                    }
+                    (true, false) => {
+                        if month != dt.month {
+                            return Err(Error::ConflictsWithJulian(DateTimeField::DayOfYear));
+                        }
+                        dt.day = day;
+                    }
+                    (false, true) => {
+                        if day != dt.day {
+                            return Err(Error::ConflictsWithJulian(DateTimeField::DayOfYear));
+                        }
+                        dt.month = month;
+                    }
+                    (false, false) => {
+                        dt.month = month;
+                        dt.day = day;
+                    }
                 }
             }
-        }
 
-        // Check if parsed day of week conflicts with the date
-        if let Some(d) = dow {
-            let date = Date::try_from(&dt)?;
-            if date.day_of_week() != d {
-                return Err(Error::ParseError(
-                    "day of week conflicts with Julian date".try_to_string()?,
-                ));
+            // Check if parsed day of week conflicts with the date
+            if let Some(d) = dow {
+                let date = Date::try_from(&dt)?;
+                if date.day_of_week() != d {
+                    return Err(Error::ConflictsWithJulian(DateTimeField::DayOfWeek));
+                }
+            }
+
+            T::try_from(dt)
+        })();
+        result.map_err(|e| {
+            let at = match e {
+                Error::InvalidMonth => month_at,
+                Error::InvalidDay | Error::InvalidDate => day_at,
+                Error::TimeOutOfRange | Error::InvalidHour12 => hour_at,
+                Error::InvalidMinute => minute_at,
+                Error::InvalidSecond => second_at,
+                Error::InvalidFraction => fraction_at,
+                _ => None,
+            };
+            match at {
+                Some((field, s)) => input_error(e, input.as_ref(), s, Some(field)),
+                None => e,
+            }
+        })
+    }
+}
+
+/// Turns a parse error of the field matched from the remaining input `s` into
+/// an `Error::InvalidInput`, which tells the position of the field in the input.
+#[inline]
+fn input_error(e: Error, input: &str, s: &[u8], field: Option<&Field>) -> Error {
+    InputError::locate(
+        e,
+        input,
+        input.len() - s.len(),
+        field.and_then(Field::input_field),
+    )
+}
+
+/// Expands the format model of 'DL', 'DS' or 'TS' into fields,
+/// the fill mode toggled by the model does not affect the following fields.
+#[inline]
+fn expand_model(fields: &mut FieldVec, model: &str) -> Result<()> {
+    let mut fill = false;
+    for field in FormatParser::new(model.as_bytes()) {
+        match field {
+            Field::Invalid
+            | Field::FormatExact
+            | Field::LongDate
+            | Field::ShortDate
+            | Field::ShortTime => {
+                return Err(Error::InvalidFormat);
             }
+            Field::FillMode => fill = !fill,
+            _ => {}
         }
+        fields.push(field)?;
+    }
 
-        T::try_from(dt)
+    if fill {
+        fields.push(Field::FillMode)?;
     }
+    Ok(())
 }
 
 fn write_u32<W: fmt::Write>(mut w: W, value: u32, width: usize) -> Result<()> {
@@ -1779,6 +3409,209 @@ fn write_u32<W: fmt::Write>(mut w: W, value: u32, width: usize) -> Result<()> {
     Ok(())
 }
 
+/// Writes '-' for a negative value, otherwise a blank unless in FM mode.
+#[inline]
+fn write_sign<W: fmt::Write>(mut w: W, negative: bool, fill: bool) -> Result<()> {
+    if negative {
+        w.write_char('-')?;
+    } else if !fill {
+        w.write_char(' ')?;
+    }
+    Ok(())
+}
+
+/// Gets the English ordinal suffix of a number, e.g. 'st' of 21 and 'th' of 11.
+#[inline]
+fn ordinal_suffix(number: u32, style: NameStyle) -> &'static str {
+    let index = match (number % 100, number % 10) {
+        (11..=13, _) => 0,
+        (_, n @ 1..=3) => n as usize,
+        _ => 0,
+    };
+    ORDINAL_SUFFIX_TABLE[(style == NameStyle::Upper) as usize][index]
+}
+
+/// English words of a number, separated by blanks and hyphens.
+struct SpelledNumber {
+    words: StackVec<&'static str, SPELLED_MAX_WORDS>,
+}
+
+impl SpelledNumber {
+    #[inline]
+    fn new() -> Self {
+        SpelledNumber {
+            words: StackVec::new(),
+        }
+    }
+
+    #[inline]
+    fn push_word(&mut self, word: &'static str) {
+        if !self.words.is_empty() {
+            self.words.push(" ");
+        }
+        self.words.push(word);
+    }
+
+    /// Pushes a number below 1000, e.g. 'three hundred twenty-one'.
+    #[inline]
+    fn push_hundreds(&mut self, number: u32) {
+        let (hundreds, rest) = (number / 100, number % 100);
+        if hundreds > 0 {
+            self.push_word(SPELLED_ONES_TABLE[0][hundreds as usize]);
+            self.push_word(SPELLED_SCALE_TABLE[0][0]);
+        }
+        if rest >= 20 {
+            self.push_word(SPELLED_TENS_TABLE[0][rest as usize / 10]);
+            if rest % 10 > 0 {
+                self.words.push("-");
+                self.words.push(SPELLED_ONES_TABLE[0][rest as usize % 10]);
+            }
+        } else if rest > 0 {
+            self.push_word(SPELLED_ONES_TABLE[0][rest as usize]);
+        }
+    }
+
+    #[inline]
+    fn push_number(&mut self, number: u32) {
+        debug_assert!(number < 1_000_000_000);
+        if number == 0 {
+            self.push_word(SPELLED_ONES_TABLE[0][0]);
+            return;
+        }
+
+        let (millions, thousands, rest) = (number / 1_000_000, number / 1000 % 1000, number % 1000);
+        if millions > 0 {
+            self.push_hundreds(millions);
+            self.push_word(SPELLED_SCALE_TABLE[0][2]);
+        }
+        if thousands > 0 {
+            self.push_hundreds(thousands);
+            self.push_word(SPELLED_SCALE_TABLE[0][1]);
+        }
+        self.push_hundreds(rest);
+    }
+
+    /// Pushes a year as it is spoken, e.g. 'twenty twenty-one', 'nineteen hundred' and 'two thousand five'.
+    #[inline]
+    fn push_year(&mut self, year: u32) {
+        let (high, low) = (year / 100, year % 100);
+        if high == 0 || (high % 10 == 0 && low < 10) {
+            self.push_number(year);
+        } else {
+            self.push_hundreds(high);
+            if low == 0 {
+                self.push_word(SPELLED_SCALE_TABLE[0][0]);
+            } else {
+                self.push_hundreds(low);
+            }
+        }
+    }
+
+    /// Turns the last word into its ordinal form, e.g. 'twenty-one' into 'twenty-first'.
+    #[inline]
+    fn make_ordinal(&mut self) {
+        if let Some(last) = self.words.last_mut() {
+            let ordinal = SPELLED_ONES_TABLE[0]
+                .iter()
+                .position(|word| word == last)
+                .map(|i| SPELLED_ONES_TABLE[1][i])
+                .or_else(|| {
+                    SPELLED_TENS_TABLE[0]
+                        .iter()
+                        .position(|word| word == last)
+                        .map(|i| SPELLED_TENS_TABLE[1][i])
+                })
+                .or_else(|| {
+                    SPELLED_SCALE_TABLE[0]
+                        .iter()
+                        .position(|word| word == last)
+                        .map(|i| SPELLED_SCALE_TABLE[1][i])
+                });
+            if let Some(ordinal) = ordinal {
+                *last = ordinal;
+            }
+        }
+    }
+
+    /// Writes the words in the case of `style`, capitalized words are hyphenated as 'Twenty-One'.
+    #[inline]
+    fn write<W: fmt::Write>(&self, mut w: W, style: NameStyle) -> Result<()> {
+        for word in self.words.iter() {
+            match style {
+                NameStyle::Upper | NameStyle::AbbrUpper => {
+                    for ch in word.chars() {
+                        w.write_char(ch.to_ascii_uppercase())?;
+                    }
+                }
+                NameStyle::Capital | NameStyle::AbbrCapital => {
+                    let mut chars = word.chars();
+                    if let Some(first) = chars.next() {
+                        w.write_char(first.to_ascii_uppercase())?;
+                        w.write_str(chars.as_str())?;
+                    }
+                }
+                NameStyle::Lower | NameStyle::AbbrLower => w.write_str(word)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Strips the leading zeros of a zero-padded number, keeping at least one digit.
+#[inline]
+fn trim_leading_zeros(s: &str) -> &str {
+    let trimmed = s.trim_start_matches('0');
+    if trimmed.is_empty() {
+        &s[s.len() - 1..]
+    } else {
+        trimmed
+    }
+}
+
+/// Writes a name in the case of `style`, the name is written as it is in capital styles.
+#[inline]
+fn write_name<W: fmt::Write>(mut w: W, name: &str, style: NameStyle) -> Result<()> {
+    match style {
+        NameStyle::Upper | NameStyle::AbbrUpper => {
+            for ch in name.chars().flat_map(char::to_uppercase) {
+                w.write_char(ch)?;
+            }
+        }
+        NameStyle::Lower | NameStyle::AbbrLower => {
+            for ch in name.chars().flat_map(char::to_lowercase) {
+                w.write_char(ch)?;
+            }
+        }
+        NameStyle::Capital | NameStyle::AbbrCapital => w.write_str(name)?,
+    }
+    Ok(())
+}
+
+/// Pads `name` with trailing blanks to `width`.
+#[inline]
+fn write_padding<W: fmt::Write>(mut w: W, name: &str, width: usize) -> Result<()> {
+    for _ in name.chars().count()..width {
+        w.write_char(' ')?;
+    }
+    Ok(())
+}
+
+/// Skips `n` characters of the input.
+#[inline]
+fn skip_chars(s: &[u8], n: usize) -> &[u8] {
+    let mut chars = 0;
+    for (i, ch) in s.iter().enumerate() {
+        // Counts the first bytes of UTF-8 characters
+        if ch & 0xC0 != 0x80 {
+            if chars == n {
+                return &s[i..];
+            }
+            chars += 1;
+        }
+    }
+    &s[s.len()..]
+}
+
 #[inline]
 fn expect_char(s: &[u8], expected: u8) -> bool {
     matches!(s.first(), Some(ch) if *ch == expected)
@@ -1792,18 +3625,12 @@ fn parse_number(input: &[u8], max_len: usize) -> Result<(bool, i32, &[u8])> {
             b'-' => (true, &input[1..]),
             _ => (false, input),
         },
-        None => {
-            return Err(Error::ParseError(
-                "the input is inconsistent with the format".try_to_string()?,
-            ))
-        }
+        None => return Err(Error::InputTooShort),
     };
 
     let (digits, s) = eat_digits(s, max_len);
     if digits.is_empty() {
-        return Err(Error::ParseError(
-            "a non-numeric character was found where a numeric was expected".try_to_string()?,
-        ));
+        return Err(Error::NonNumeric);
     }
 
     let int = digits
@@ -1815,6 +3642,37 @@ fn parse_number(input: &[u8], max_len: usize) -> Result<(bool, i32, &[u8])> {
     Ok((negative, int, s))
 }
 
+#[inline]
+fn parse_number_exact(input: &[u8], len: usize) -> Result<(bool, i32, &[u8])> {
+    let (negative, int, s) = parse_number(input, len)?;
+    let sign_len = matches!(input.first(), Some(b'+') | Some(b'-')) as usize;
+    if input.len() - s.len() - sign_len != len {
+        return Err(Error::NumberLengthMismatch);
+    }
+    Ok((negative, int, s))
+}
+
+/// Parses the seconds since the Unix Epoch, which may be negative.
+#[inline]
+fn parse_unix_time(input: &[u8]) -> Result<(i64, &[u8])> {
+    let (negative, s) = match input.first() {
+        Some(b'+') => (false, &input[1..]),
+        Some(b'-') => (true, &input[1..]),
+        _ => (false, input),
+    };
+
+    let (digits, s) = eat_digits(s, 12);
+    if digits.is_empty() {
+        return Err(Error::NonNumeric);
+    }
+
+    let secs = digits
+        .iter()
+        .fold(0, |secs, &i| secs * 10 + (i - b'0') as i64);
+
+    Ok((if negative { -secs } else { secs }, s))
+}
+
 #[inline]
 fn eat_digits(s: &[u8], max_len: usize) -> (&[u8], &[u8]) {
     let i = s
@@ -1835,9 +3693,20 @@ fn eat_whitespaces(s: &[u8]) -> &[u8] {
 fn parse_year<'a, T: FnMut() -> chrono::NaiveDateTime>(
     input: &'a [u8],
     max_len: usize,
+    exact: bool,
     get_now: &mut T,
 ) -> Result<(bool, i32, &'a [u8])> {
     // todo do not allow sign element 's' before y/yy/yyy in the format string
+    if exact {
+        let (negative, year, rem) = parse_number_exact(input, max_len)?;
+        if max_len == 4 {
+            return Ok((negative, year, rem));
+        }
+        let current_year = get_now().year();
+        let result_year = current_year - current_year % YEAR_MODIFIER[max_len - 1] as i32 + year;
+        return Ok((negative, result_year, rem));
+    }
+
     match max_len {
         2 => {
             let input_len = input.len();
@@ -1859,57 +3728,181 @@ fn parse_year<'a, T: FnMut() -> chrono::NaiveDateTime>(
                 current_year - current_year % YEAR_MODIFIER[max_len - 1] as i32 + year;
             Ok((negative, result_year, rem))
         }
-        _ => parse_number(input, max_len),
+        _ => parse_number(input, max_len),
+    }
+}
+
+/// Parses the year of 'SYYYY', the sign may be replaced by a blank.
+#[inline]
+fn parse_signed_year(input: &[u8], exact: bool) -> Result<(bool, i32, &[u8])> {
+    if exact {
+        let s = match input.first() {
+            Some(b' ') => &input[1..],
+            _ => input,
+        };
+        parse_number_exact(s, 4)
+    } else {
+        parse_number(input, 4)
+    }
+}
+
+/// Parses the year of 'Y,YYY'.
+#[inline]
+fn parse_comma_year(input: &[u8], exact: bool) -> Result<(bool, i32, &[u8])> {
+    let (negative, thousands, s) = if exact {
+        parse_number_exact(input, 1)?
+    } else {
+        parse_number(input, 1)?
+    };
+    if !expect_char(s, b',') {
+        return Err(Error::LiteralMismatch);
+    }
+    let (_, rest, s) = parse_number_exact(&s[1..], 3)?;
+    if rest < 0 {
+        return Err(Error::LiteralMismatch);
+    }
+
+    let year = thousands.abs() * 1000 + rest;
+    Ok((negative, if negative { -year } else { year }, s))
+}
+
+/// Maps a two-digit year as POSIX `strptime` does, 69-99 are in 1969-1999 and 00-68 are in 2000-2068.
+#[inline]
+const fn posix_year(year: i32) -> i32 {
+    if year < 69 {
+        year + 2000
+    } else {
+        year + 1900
+    }
+}
+
+/// Parses the year of 'RR' or 'RRRR'.
+/// A year of one or two digits is mapped into the century chosen by Oracle's 'RR' rule,
+/// while a longer year is taken as is.
+#[inline]
+fn parse_round_year<'a, T: FnMut() -> chrono::NaiveDateTime>(
+    input: &'a [u8],
+    max_len: usize,
+    exact: bool,
+    get_now: &mut T,
+) -> Result<(bool, i32, &'a [u8])> {
+    let (negative, year, rem) = if exact && max_len == 2 {
+        parse_number_exact(input, max_len)?
+    } else {
+        parse_number(input, 4)?
+    };
+
+    let sign_len = matches!(input.first(), Some(b'+') | Some(b'-')) as usize;
+    let len = input.len() - rem.len() - sign_len;
+    if exact && len != 2 && len != max_len {
+        return Err(Error::NumberLengthMismatch);
+    }
+
+    if len > 2 || negative {
+        return Ok((negative, year, rem));
+    }
+
+    Ok((negative, round_year(year, get_now().year()), rem))
+}
+
+/// Maps a two-digit year into a century according to Oracle's 'RR' rule:
+///
+/// | Current year | 00-49           | 50-99           |
+/// |--------------|-----------------|-----------------|
+/// | 00-49        | current century | prior century   |
+/// | 50-99        | next century    | current century |
+#[inline]
+pub(crate) fn round_year(year: i32, current_year: i32) -> i32 {
+    let century = current_year - current_year % 100;
+    match (current_year % 100 < 50, year < 50) {
+        (true, true) | (false, false) => century + year,
+        (true, false) => century - 100 + year,
+        (false, true) => century + 100 + year,
+    }
+}
+
+/// Matches quoted text of the format model, ignoring case.
+/// Unless in FX mode, a run of whitespaces in the text matches any whitespaces of the input.
+#[inline]
+fn parse_literal<'a>(s: &'a [u8], text: &str, exact: bool) -> Result<&'a [u8]> {
+    let text = text.as_bytes();
+    if exact {
+        return if CaseInsensitive::starts_with(s, text) {
+            Ok(&s[text.len()..])
+        } else {
+            Err(Error::LiteralMismatch)
+        };
+    }
+
+    let mut s = s;
+    let mut text = text;
+    while let Some(&ch) = text.first() {
+        if ch.is_ascii_whitespace() {
+            text = eat_whitespaces(text);
+            s = eat_whitespaces(s);
+        } else if matches!(s.first(), Some(c) if c.eq_ignore_ascii_case(&ch)) {
+            text = &text[1..];
+            s = &s[1..];
+        } else {
+            return Err(Error::LiteralMismatch);
+        }
+    }
+    Ok(s)
+}
+
+/// Parses the era indicator, returns true for BC.
+#[inline]
+fn parse_era<'a>(s: &'a [u8], style: &EraStyle) -> Result<(bool, &'a [u8])> {
+    let (ad, bc): (&[u8], &[u8]) = match style {
+        EraStyle::UpperDot | EraStyle::LowerDot => (b"A.D.", b"B.C."),
+        EraStyle::Upper | EraStyle::Lower => (b"AD", b"BC"),
+    };
+
+    if CaseInsensitive::starts_with(s, ad) {
+        Ok((false, &s[ad.len()..]))
+    } else if CaseInsensitive::starts_with(s, bc) {
+        Ok((true, &s[bc.len()..]))
+    } else {
+        Err(Error::EraRequired)
     }
 }
 
 #[inline]
-fn parse_ampm<'a>(s: &'a [u8], style: &'a AmPmStyle) -> Result<(Option<AmPm>, &'a [u8])> {
+fn parse_ampm<'a>(
+    s: &'a [u8],
+    style: &AmPmStyle,
+    locale: &Locale,
+) -> Result<(Option<AmPm>, &'a [u8])> {
     if s.is_empty() {
         return Ok((None, s));
     }
-    match style {
-        AmPmStyle::LowerDot | AmPmStyle::UpperDot => {
-            if CaseInsensitive::starts_with(s, b"A.M.") {
-                Ok((Some(AmPm::Am), &s[4..]))
-            } else if CaseInsensitive::starts_with(s, b"P.M.") {
-                Ok((Some(AmPm::Pm), &s[4..]))
-            } else {
-                Err(Error::ParseError(
-                    "AM/A.M. or PM/P.M. required".try_to_string()?,
-                ))
-            }
-        }
-        AmPmStyle::Upper | AmPmStyle::Lower => {
-            if CaseInsensitive::starts_with(s, b"AM") {
-                Ok((Some(AmPm::Am), &s[2..]))
-            } else if CaseInsensitive::starts_with(s, b"PM") {
-                Ok((Some(AmPm::Pm), &s[2..]))
-            } else {
-                Err(Error::ParseError(
-                    "AM/A.M. or PM/P.M. required".try_to_string()?,
-                ))
-            }
-        }
+
+    let [am, pm] = locale.am_pm_names(style.is_dot());
+    if let Some(len) = starts_with_name(s, am) {
+        Ok((Some(AmPm::Am), &s[len..]))
+    } else if let Some(len) = starts_with_name(s, pm) {
+        Ok((Some(AmPm::Pm), &s[len..]))
+    } else {
+        Err(Error::AmPmRequired)
     }
 }
 
 #[inline]
-fn parse_fraction(s: &[u8], max_len: usize) -> Result<(u32, &[u8])> {
+fn parse_fraction(s: &[u8], max_len: usize, exact: bool) -> Result<(u32, &[u8])> {
     match s.first() {
-        Some(ch) => {
-            if *ch == b'-' {
-                return Err(Error::ParseError(
-                    "the fractional seconds must be between 0 and 999999".try_to_string()?,
-                ));
-            }
+        Some(b'-') => {
+            return Err(Error::InvalidFraction);
         }
-        None => {
+        None if !exact => {
             return Ok((0, s));
         }
+        _ => {}
     }
 
     let (digits, s) = eat_digits(s, max_len);
+    if exact && digits.len() != max_len {
+        return Err(Error::NumberLengthMismatch);
+    }
     let int = digits
         .iter()
         .fold(0, |int, &i| int * 10 + (i - b'0') as i32);
@@ -1919,53 +3912,107 @@ fn parse_fraction(s: &[u8], max_len: usize) -> Result<(u32, &[u8])> {
     ))
 }
 
+/// Parses a full or abbreviated month name of the locale ignoring case, the longest match wins.
+/// Returns the month, the length of the name in characters and the remaining input.
 #[inline]
-fn parse_month_name(s: &[u8]) -> Result<(Month, &[u8])> {
-    for (index, mon) in MONTH_NAME_TABLE[Capital as usize].iter().enumerate() {
-        if CaseInsensitive::starts_with(s, mon.as_bytes()) {
-            return Ok((Month::from(index + 1), &s[mon.len()..]));
-        }
+pub(crate) fn parse_month_name<'a>(
+    s: &'a [u8],
+    locale: &Locale,
+) -> Result<(Month, usize, &'a [u8])> {
+    match parse_name(s, locale.month_names(false))
+        .into_iter()
+        .chain(parse_name(s, locale.month_names(true)))
+        .max_by_key(|(_, _, len)| *len)
+    {
+        Some((index, name, len)) => Ok((Month::from(index + 1), name.chars().count(), &s[len..])),
+        None => Err(Error::InvalidMonth),
     }
+}
 
-    for (index, mon) in MONTH_NAME_TABLE[AbbrCapital as usize].iter().enumerate() {
-        if CaseInsensitive::starts_with(s, mon.as_bytes()) {
-            return Ok((Month::from(index + 1), &s[mon.len()..]));
-        }
+/// Parses a Roman numeral month ignoring case, the longest match wins, e.g. 'XII' over 'XI' and 'X'.
+#[inline]
+fn parse_roman_month(s: &[u8]) -> Result<(Month, &[u8])> {
+    let matched = ROMAN_MONTH_TABLE[0]
+        .iter()
+        .enumerate()
+        .filter(|(_, roman)| CaseInsensitive::starts_with(s, roman.as_bytes()))
+        .max_by_key(|(_, roman)| roman.len());
+
+    match matched {
+        Some((index, roman)) => Ok((Month::from(index + 1), &s[roman.len()..])),
+        None => Err(Error::InvalidMonth),
     }
+}
 
-    Err(Error::ParseError("not a valid month".try_to_string()?))
+/// Parses a day name of the locale ignoring case, the longest match wins.
+/// Returns the day, the length of the name in characters and the remaining input.
+#[inline]
+fn parse_week_day_name<'a>(
+    s: &'a [u8],
+    style: NameStyle,
+    locale: &Locale,
+) -> Result<(WeekDay, usize, &'a [u8])> {
+    match parse_name(s, locale.day_names(style.is_abbr())) {
+        Some((index, name, len)) => Ok((WeekDay::from(index + 1), name.chars().count(), &s[len..])),
+        None => Err(Error::InvalidDayOfWeek),
+    }
 }
 
+/// Parses a full or abbreviated day name of the locale ignoring case, the longest match wins.
+/// Returns the day and the remaining input.
 #[inline]
-fn parse_week_day_name(s: &[u8], style: NameStyle) -> Result<(WeekDay, &[u8])> {
-    match style {
-        Capital | NameStyle::Lower | NameStyle::Upper => {
-            for (index, day) in DAY_NAME_TABLE[Capital as usize].iter().enumerate() {
-                if CaseInsensitive::starts_with(s, day.as_bytes()) {
-                    return Ok((WeekDay::from(index + 1), &s[day.len()..]));
-                }
-            }
-        }
-        AbbrCapital | NameStyle::AbbrLower | NameStyle::AbbrUpper => {
-            for (index, day) in DAY_NAME_TABLE[AbbrCapital as usize].iter().enumerate() {
-                if CaseInsensitive::starts_with(s, day.as_bytes()) {
-                    return Ok((WeekDay::from(index + 1), &s[day.len()..]));
-                }
-            }
-        }
+pub(crate) fn parse_day_name<'a>(s: &'a [u8], locale: &Locale) -> Result<(WeekDay, &'a [u8])> {
+    match parse_name(s, locale.day_names(false))
+        .into_iter()
+        .chain(parse_name(s, locale.day_names(true)))
+        .max_by_key(|(_, _, len)| *len)
+    {
+        Some((index, _, len)) => Ok((WeekDay::from(index + 1), &s[len..])),
+        None => Err(Error::InvalidDayOfWeek),
     }
+}
 
-    Err(Error::ParseError(
-        "not a valid day of the week".try_to_string()?,
-    ))
+/// Finds the longest name the input starts with ignoring case,
+/// returns the index and the name, and the length of the matched input in bytes.
+#[inline]
+fn parse_name(s: &[u8], names: &[&'static str]) -> Option<(usize, &'static str, usize)> {
+    names
+        .iter()
+        .enumerate()
+        .filter_map(|(index, name)| starts_with_name(s, name).map(|len| (index, *name, len)))
+        .max_by_key(|(_, _, len)| *len)
+}
+
+/// Checks if the input starts with the name ignoring case,
+/// returns the length of the matched input in bytes.
+#[inline]
+fn starts_with_name(s: &[u8], name: &str) -> Option<usize> {
+    if name.is_ascii() {
+        return if CaseInsensitive::starts_with(s, name.as_bytes()) {
+            Some(name.len())
+        } else {
+            None
+        };
+    }
+
+    // The input is always split at character boundaries
+    let text = std::str::from_utf8(s).ok()?;
+    let mut chars = text.chars();
+    let mut len = 0;
+    for expected in name.chars() {
+        let ch = chars.next()?;
+        if !ch.to_lowercase().eq(expected.to_lowercase()) {
+            return None;
+        }
+        len += ch.len_utf8();
+    }
+    Some(len)
 }
 
 #[inline]
 fn parse_week_day_number(s: &[u8]) -> Result<(WeekDay, &[u8])> {
     if s.is_empty() {
-        return Err(Error::ParseError(
-            "not a valid day of the week".try_to_string()?,
-        ));
+        return Err(Error::InvalidDayOfWeek);
     }
 
     let num = s[0] - b'0';
@@ -1973,9 +4020,7 @@ fn parse_week_day_number(s: &[u8]) -> Result<(WeekDay, &[u8])> {
         return Ok((WeekDay::from(num as usize), &s[1..]));
     }
 
-    Err(Error::ParseError(
-        "not a valid day of the week".try_to_string()?,
-    ))
+    Err(Error::InvalidDayOfWeek)
 }
 
 pub struct LazyFormat<T: DateTimeFormat> {
@@ -2022,6 +4067,515 @@ mod tests {
         assert_eq!(parser.next(), Some(Field::Dot));
         assert_eq!(parser.next(), Some(Field::Fraction(Some(9))));
         assert_eq!(parser.next(), None);
+
+        let mut parser = FormatParser::new(b"FXyyyy fxff FMdd fmFF");
+        assert_eq!(parser.next(), Some(Field::FormatExact));
+        assert_eq!(parser.next(), Some(Field::Year(4)));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::FormatExact));
+        assert_eq!(parser.next(), Some(Field::Fraction(None)));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::FillMode));
+        assert_eq!(parser.next(), Some(Field::Day));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::FillMode));
+        assert_eq!(parser.next(), Some(Field::Fraction(None)));
+        assert_eq!(parser.next(), None);
+
+        let mut parser = FormatParser::new(b"RM rm Rm RRRM R");
+        assert_eq!(parser.next(), Some(Field::RomanMonth(NameStyle::Upper)));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::RomanMonth(NameStyle::Lower)));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::RomanMonth(NameStyle::Upper)));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::RoundYear(2)));
+        assert_eq!(parser.next(), Some(Field::RomanMonth(NameStyle::Upper)));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::Invalid));
+
+        let mut parser = FormatParser::new(b"AD a.d. bC B.C. am");
+        assert_eq!(parser.next(), Some(Field::Era(EraStyle::Upper)));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::Era(EraStyle::LowerDot)));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::Era(EraStyle::Upper)));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::Era(EraStyle::UpperDot)));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::AmPm(AmPmStyle::Lower)));
+        assert_eq!(parser.next(), None);
+
+        let mut parser = FormatParser::new(b"CC SCC SYYYY Y,YYY C");
+        assert_eq!(parser.next(), Some(Field::Century));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::SignedCentury));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::SignedYear));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::CommaYear));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::Invalid));
+        assert_eq!(parser.next(), None);
+
+        let mut parser = FormatParser::new(b"J SSSSS sss");
+        assert_eq!(parser.next(), Some(Field::Julian));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::SecondsPastMidnight));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::Second));
+        assert_eq!(parser.next(), Some(Field::Invalid));
+        assert_eq!(parser.next(), None);
+
+        // A run of spaces longer than a blank can hold is split
+        let model = format!("DD{}MM", " ".repeat(300));
+        let mut parser = FormatParser::new(model.as_bytes());
+        assert_eq!(parser.next(), Some(Field::Day));
+        assert_eq!(parser.next(), Some(Field::Blank(255)));
+        assert_eq!(parser.next(), Some(Field::Blank(45)));
+        assert_eq!(parser.next(), Some(Field::Month));
+        assert_eq!(parser.next(), None);
+
+        let mut parser = FormatParser::new(b"IYYY-IW iyy iy i Q");
+        assert_eq!(parser.next(), Some(Field::IsoYear(4)));
+        assert_eq!(parser.next(), Some(Field::Hyphen));
+        assert_eq!(parser.next(), Some(Field::IsoWeek));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::IsoYear(3)));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::IsoYear(2)));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::IsoYear(1)));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::Quarter));
+        assert_eq!(parser.next(), None);
+
+        let mut parser =
+            FormatParser::with_dialect(b"FMDDth MS US OF tz SSSS at_FMMon", Dialect::Postgres);
+        assert_eq!(parser.next(), Some(Field::FillMode));
+        assert_eq!(parser.next(), Some(Field::Day));
+        assert_eq!(
+            parser.next(),
+            Some(Field::Suffix(Suffix::Ordinal, NameStyle::Lower))
+        );
+        assert_eq!(parser.next(), Some(Field::FillMode));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::Fraction(Some(3))));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::Fraction(Some(6))));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::TimeZoneOffset));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::TimeZone));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::SecondsPastMidnight));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::Literal("a".to_string())));
+        assert_eq!(parser.next(), Some(Field::Literal("t".to_string())));
+        assert_eq!(parser.next(), Some(Field::Literal("_".to_string())));
+        assert_eq!(parser.next(), Some(Field::FillMode));
+        assert_eq!(
+            parser.next(),
+            Some(Field::MonthName(NameStyle::AbbrCapital))
+        );
+        assert_eq!(parser.next(), Some(Field::FillMode));
+        assert_eq!(parser.next(), None);
+
+        let mut parser = FormatParser::new(b"MS");
+        assert_eq!(parser.next(), Some(Field::Invalid));
+
+        let mut parser = FormatParser::with_dialect(b"%Y-%m at %-d%e%^a%F%%%Q", Dialect::Strftime);
+        assert_eq!(parser.next(), Some(Field::Year(4)));
+        assert_eq!(parser.next(), Some(Field::Literal("-".to_string())));
+        assert_eq!(parser.next(), Some(Field::Month));
+        assert_eq!(parser.next(), Some(Field::Literal(" at ".to_string())));
+        assert_eq!(parser.next(), Some(Field::FillMode));
+        assert_eq!(parser.next(), Some(Field::Day));
+        assert_eq!(parser.next(), Some(Field::FillMode));
+        assert_eq!(parser.next(), Some(Field::SpacePadding));
+        assert_eq!(parser.next(), Some(Field::Day));
+        assert_eq!(parser.next(), Some(Field::SpacePadding));
+        assert_eq!(parser.next(), Some(Field::FillMode));
+        assert_eq!(parser.next(), Some(Field::DayName(NameStyle::AbbrUpper)));
+        assert_eq!(parser.next(), Some(Field::FillMode));
+        assert_eq!(parser.next(), Some(Field::Year(4)));
+        assert_eq!(parser.next(), Some(Field::Literal("-".to_string())));
+        assert_eq!(parser.next(), Some(Field::Month));
+        assert_eq!(parser.next(), Some(Field::Literal("-".to_string())));
+        assert_eq!(parser.next(), Some(Field::Day));
+        assert_eq!(parser.next(), Some(Field::Literal("%".to_string())));
+        assert_eq!(parser.next(), Some(Field::Invalid));
+        assert_eq!(parser.next(), None);
+
+        let mut parser = FormatParser::with_dialect(b"%D at %e%r%q", Dialect::MySql);
+        assert_eq!(parser.next(), Some(Field::FillMode));
+        assert_eq!(parser.next(), Some(Field::Day));
+        assert_eq!(
+            parser.next(),
+            Some(Field::Suffix(Suffix::Ordinal, NameStyle::Lower))
+        );
+        assert_eq!(parser.next(), Some(Field::FillMode));
+        assert_eq!(parser.next(), Some(Field::Literal(" at ".to_string())));
+        assert_eq!(parser.next(), Some(Field::FillMode));
+        assert_eq!(parser.next(), Some(Field::Day));
+        assert_eq!(parser.next(), Some(Field::FillMode));
+        assert_eq!(parser.next(), Some(Field::Hour12));
+        assert_eq!(parser.next(), Some(Field::Literal(":".to_string())));
+        assert_eq!(parser.next(), Some(Field::Minute));
+        assert_eq!(parser.next(), Some(Field::Literal(":".to_string())));
+        assert_eq!(parser.next(), Some(Field::Second));
+        assert_eq!(parser.next(), Some(Field::Literal(" ".to_string())));
+        assert_eq!(parser.next(), Some(Field::AmPm(AmPmStyle::Upper)));
+        assert_eq!(parser.next(), Some(Field::Literal("q".to_string())));
+        assert_eq!(parser.next(), None);
+
+        let mut parser = FormatParser::new(b"DL ds Ts DSP DS");
+        assert_eq!(parser.next(), Some(Field::LongDate));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::ShortDate));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::ShortTime));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::DayOfWeek));
+        assert_eq!(
+            parser.next(),
+            Some(Field::Suffix(Suffix::Spelled, NameStyle::Upper))
+        );
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::ShortDate));
+        assert_eq!(parser.next(), None);
+
+        let mut parser = FormatParser::new(b"DDTH Ddsp ddSpTh MMthsp DDTHH24 YEAR Syear THH");
+        assert_eq!(parser.next(), Some(Field::Day));
+        assert_eq!(
+            parser.next(),
+            Some(Field::Suffix(Suffix::Ordinal, NameStyle::Upper))
+        );
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::Day));
+        assert_eq!(
+            parser.next(),
+            Some(Field::Suffix(Suffix::Spelled, NameStyle::Capital))
+        );
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::Day));
+        assert_eq!(
+            parser.next(),
+            Some(Field::Suffix(Suffix::SpelledOrdinal, NameStyle::Lower))
+        );
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::Month));
+        assert_eq!(
+            parser.next(),
+            Some(Field::Suffix(Suffix::SpelledOrdinal, NameStyle::Upper))
+        );
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::Day));
+        assert_eq!(parser.next(), Some(Field::T));
+        assert_eq!(parser.next(), Some(Field::Hour24));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::YearName(NameStyle::Upper)));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(
+            parser.next(),
+            Some(Field::SignedYearName(NameStyle::Capital))
+        );
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::T));
+        assert_eq!(parser.next(), Some(Field::Hour12));
+        assert_eq!(parser.next(), None);
+
+        let mut parser = FormatParser::new(r#"yyyy"年"\"\ "a\"b\\c"\"oops"#.as_bytes());
+        assert_eq!(parser.next(), Some(Field::Year(4)));
+        assert_eq!(parser.next(), Some(Field::Literal("年".to_string())));
+        assert_eq!(parser.next(), Some(Field::Literal("\"".to_string())));
+        assert_eq!(parser.next(), Some(Field::Backslash));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::Literal(r#"a"b\c"#.to_string())));
+        assert_eq!(parser.next(), Some(Field::Literal("\"".to_string())));
+        assert_eq!(parser.next(), Some(Field::Invalid));
+    }
+
+    #[test]
+    fn test_parse_error_position() {
+        use InputField::{DateTime, Literal, Separator};
+
+        fn input_error<T: DateTimeFormat + fmt::Debug>(
+            input: &str,
+            fmt: &str,
+        ) -> (usize, Option<InputField>, Option<char>) {
+            match Formatter::try_new(fmt).unwrap().parse::<_, T>(input) {
+                Err(Error::InvalidInput(e)) => (e.position(), e.field(), e.found()),
+                result => panic!("unexpected result {:?}", result),
+            }
+        }
+
+        assert_eq!(
+            input_error::<Date>("2021-AB-01", "YYYY-MM-DD"),
+            (5, Some(DateTime(DateTimeField::Month)), Some('A'))
+        );
+        assert_eq!(
+            input_error::<Date>("2021-XX-01", "YYYY-MON-DD"),
+            (5, Some(DateTime(DateTimeField::Month)), Some('X'))
+        );
+        assert_eq!(
+            input_error::<Timestamp>("2021-03-03 05:06 XM", "YYYY-MM-DD HH:MI AM"),
+            (17, Some(DateTime(DateTimeField::Meridian)), Some('X'))
+        );
+        assert_eq!(
+            input_error::<Timestamp>("2021-03-03 17:06 PM", "YYYY-MM-DD HH:MI AM"),
+            (11, Some(DateTime(DateTimeField::Hour)), Some('1'))
+        );
+        assert_eq!(
+            input_error::<Timestamp>("2021-03-03 17;06", "YYYY-MM-DD HH24:MI"),
+            (13, Some(Separator(':')), Some(';'))
+        );
+        let e = Timestamp::parse("2021-03-03 17;06", "YYYY-MM-DD HH24:MI").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "literal does not match format string at position 13 while matching the separator ':', found ';'"
+        );
+        assert_eq!(
+            input_error::<Date>("2021年03月", "YYYY\"年\"MM\"日\""),
+            (9, Some(Literal), Some('月'))
+        );
+        assert_eq!(
+            input_error::<Date>("2021-03-03 xyz", "YYYY-MM-DD"),
+            (11, None, Some('x'))
+        );
+        assert_eq!(
+            input_error::<IntervalDT>("1 02:0x:04", "DD HH24:MI:SS"),
+            (6, Some(Separator(':')), Some('x'))
+        );
+
+        let e = Date::parse("2021-AB-01", "YYYY-MM-DD").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "not a valid month at position 5 while matching the month, found 'A'"
+        );
+
+        // Range errors found once all fields are matched are located at the field
+        assert_eq!(
+            input_error::<Timestamp>("2021-13-01", "YYYY-MM-DD"),
+            (5, Some(DateTime(DateTimeField::Month)), Some('1'))
+        );
+        assert_eq!(
+            input_error::<Timestamp>("2021-10-32", "YYYY-MM-DD"),
+            (8, Some(DateTime(DateTimeField::Day)), Some('3'))
+        );
+        assert_eq!(
+            input_error::<Date>("2021-02-29", "YYYY-MM-DD"),
+            (8, Some(DateTime(DateTimeField::Day)), Some('2'))
+        );
+        assert_eq!(
+            input_error::<Timestamp>("2021-10-01 25", "YYYY-MM-DD HH24"),
+            (11, Some(DateTime(DateTimeField::Hour)), Some('2'))
+        );
+        assert_eq!(
+            input_error::<Timestamp>("2021-10-01 12:61", "YYYY-MM-DD HH24:MI"),
+            (14, Some(DateTime(DateTimeField::Minute)), Some('6'))
+        );
+        assert_eq!(
+            input_error::<Time>("12:00:61", "HH24:MI:SS"),
+            (6, Some(DateTime(DateTimeField::Second)), Some('6'))
+        );
+        assert_eq!(
+            input_error::<IntervalDT>("1 12:61:00", "DD HH24:MI:SS"),
+            (5, Some(DateTime(DateTimeField::Minute)), Some('6'))
+        );
+        let e = Timestamp::parse("2021-13-01", "YYYY-MM-DD").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "not a valid month at position 5 while matching the month, found '1'"
+        );
+
+        // Errors which do not come from a position of the input are not located
+        assert_eq!(
+            Date::parse("2021-04 060", "YYYY-MM DDD").unwrap_err(),
+            Error::ConflictsWithJulian(DateTimeField::DayOfYear)
+        );
+    }
+
+    #[test]
+    fn test_error_codes() {
+        fn codes<T: DateTimeFormat + fmt::Debug>(input: &str, fmt: &str) -> (u32, &'static str) {
+            let e = Formatter::try_new(fmt)
+                .unwrap()
+                .parse::<_, T>(input)
+                .unwrap_err();
+            (e.oracle_code(), e.sqlstate())
+        }
+
+        assert_eq!(codes::<Date>("2021-13-01", "YYYY-MM-DD"), (1843, "22008"));
+        assert_eq!(codes::<Date>("2021-02-30", "YYYY-MM-DD"), (1839, "22008"));
+        assert_eq!(codes::<Date>("2021-1X-01", "YYYY-MM-DD"), (1861, "22007"));
+        assert_eq!(codes::<Date>("X021-10-01", "YYYY-MM-DD"), (1858, "22007"));
+        assert_eq!(codes::<Date>("2021-10-01 1", "YYYY-MM-DD"), (1830, "22007"));
+        assert_eq!(
+            codes::<Date>("2021-10-01", "YYYY-MM-DD YYYY"),
+            (1812, "22007")
+        );
+        assert_eq!(
+            codes::<Date>("2021-10-01", "YYYY-MM-DD MM"),
+            (1816, "22007")
+        );
+        assert_eq!(codes::<Date>("2021 21", "YYYY CC"), (1820, "22007"));
+        let e = Formatter::try_new_with("YYYY OF", Dialect::Postgres)
+            .unwrap()
+            .parse::<_, Timestamp>("2021 +08")
+            .unwrap_err();
+        assert_eq!((e.oracle_code(), e.sqlstate()), (1820, "0A000"));
+        assert_eq!(
+            codes::<Timestamp>("2021-10-01 13", "YYYY-MM-DD HH12"),
+            (1849, "22008")
+        );
+        assert_eq!(
+            codes::<Timestamp>("2021-10-01 12:60", "YYYY-MM-DD HH24:MI"),
+            (1851, "22008")
+        );
+        assert_eq!(
+            codes::<Timestamp>("2021-10-01 10 XM", "YYYY-MM-DD HH AM"),
+            (1855, "22007")
+        );
+        assert_eq!(
+            codes::<Timestamp>("43200 10", "SSSSS HH24"),
+            (1836, "22007")
+        );
+        assert_eq!(codes::<Date>("2459489 2020", "J YYYY"), (1831, "22007"));
+        assert_eq!(
+            codes::<Date>("2459489 2021-10-02", "J YYYY-MM-DD"),
+            (1834, "22007")
+        );
+        assert_eq!(
+            codes::<Timestamp>("43200 12:01", "SSSSS HH24:MI"),
+            (1837, "22007")
+        );
+        assert_eq!(
+            Error::ConflictsWithJulian(DateTimeField::Day).to_string(),
+            "day of month conflicts with Julian date"
+        );
+        assert_eq!(
+            codes::<IntervalDT>("1 25:00:00", "DD HH24:MI:SS"),
+            (1850, "22008")
+        );
+
+        assert_eq!(Error::IntervalOutOfRange.sqlstate(), "22015");
+        assert_eq!(Error::DivideByZero.oracle_code(), 1476);
+        assert_eq!(Error::DivideByZero.sqlstate(), "22012");
+        assert_eq!(Error::InvalidFormat.oracle_code(), 1821);
+    }
+
+    #[test]
+    fn test_located_error() {
+        // The located error is kept inline, so that a rejected input does not allocate
+        assert!(std::mem::size_of::<Error>() <= 64);
+
+        let fmt = Formatter::try_new("YYYY-MM-DD HH12").unwrap();
+        let errors = [
+            ("2021-AB-01 01", Error::InvalidMonth),
+            ("2021-10-01 13", Error::InvalidHour12),
+            ("X021-10-01 01", Error::NonNumeric),
+            ("2021-10-01 0", Error::InvalidHour12),
+        ];
+        for (input, expected) in errors.iter() {
+            match fmt.parse::<_, Timestamp>(input) {
+                Err(Error::InvalidInput(e)) => assert_eq!(&e.error(), expected),
+                result => panic!("unexpected result {:?}", result),
+            }
+        }
+        match Formatter::try_new("YYYY-MM-DD HH12 YYYY")
+            .unwrap()
+            .parse::<_, Timestamp>("2021-10-01 01 2021")
+        {
+            Err(Error::InvalidInput(e)) => {
+                assert_eq!(e.error(), Error::DuplicateField(DateTimeField::Year));
+                assert_eq!(e.position(), 14);
+            }
+            result => panic!("unexpected result {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_long_format_model() {
+        let ts = Date::try_from_ymd(2021, 3, 3)
+            .unwrap()
+            .and_time(Time::try_from_hms(17, 6, 7, 89000).unwrap());
+
+        let fmt = "YYYY-MM-DD HH24:MI:SS.FF6 ".repeat(10);
+        let formatter = Formatter::try_new(&fmt).unwrap();
+        assert!(matches!(formatter.fields, FieldVec::Heap(_)));
+        assert_eq!(formatter.fields.len(), 140);
+        let mut s = String::new();
+        formatter.format(ts, &mut s).unwrap();
+        assert_eq!(s, "2021-03-03 17:06:07.089000 ".repeat(10));
+
+        let fmt = format!("YYYY-MM-DD{}HH24:MI:SS.FF6", " \"-\"".repeat(40));
+        let formatter = Formatter::try_new(&fmt).unwrap();
+        assert!(matches!(formatter.fields, FieldVec::Heap(_)));
+        let s = format!("2021-03-03{}17:06:07.089", " -".repeat(40));
+        assert_eq!(formatter.parse::<_, Timestamp>(&s).unwrap(), ts);
+
+        let fmt = format!(
+            "{}\"{}\" DL TS",
+            "DD-MON-YYYY ".repeat(6),
+            "text ".repeat(100)
+        );
+        let formatter = Formatter::try_new(&fmt).unwrap();
+        let mut s = String::new();
+        formatter.format(ts, &mut s).unwrap();
+        assert_eq!(
+            s,
+            format!(
+                "{}{} Wednesday, March 3, 2021 05:06:07 PM",
+                "03-MAR-2021 ".repeat(6),
+                "text ".repeat(100)
+            )
+        );
+
+        let formatter = Formatter::try_new("YYYY-MM-DD").unwrap();
+        assert!(matches!(formatter.fields, FieldVec::Inline(_)));
+    }
+
+    #[test]
+    fn test_check_format_model() {
+        fn check(fmt: &str) {
+            let checked = check_format_model(fmt.as_bytes(), 0, false);
+            let formatter = Formatter::try_new(fmt).ok();
+            assert_eq!(checked, formatter.map(|f| f.fields.len()), "{}", fmt);
+        }
+
+        // Every model of up to three characters must be checked as `Formatter::try_new` does
+        let chars = "AaBbCcDdFfHhIiJjLlMmNnOoPpQqRrSsTtWwXxYy0129 -:/,.;\\\"";
+        for a in chars.chars() {
+            check(&a.to_string());
+            for b in chars.chars() {
+                check(&format!("{}{}", a, b));
+                for c in chars.chars() {
+                    check(&format!("{}{}{}", a, b, c));
+                }
+            }
+        }
+
+        for fmt in [
+            "YYYY-MM-DD HH24:MI:SS.FF6",
+            "Y,YYY YEAR Year SYEAR SYYYY SCC CC",
+            "RRRR RR RRR RM rm Rm",
+            "MONTH Month mon Mon MONDAY",
+            "A.D. a.d. B.C. AD bc A.M. p.m. PM am",
+            "A.D B.C",
+            "DDTH DDSP DDSPTH DdThSp DDTHH HH12TH HH24SP SSSSS",
+            "DY Day DAY DL DS TS DSP FX FM FF FF9 FF0",
+            "IYYY IYY IY I IW WW W J Q",
+            "\"quoted \\\" text\" \\\"",
+            "\"unterminated",
+            "YYYY-MM-DD HH24:MI:SS YYYY-MM-DD HH24:MI:SS YYYY-MM-DD HH24",
+            "YYYY-MM-DD HH24:MI:SS YYYY-MM-DD HH24:MI:SS YYYY-MM-DD HH24:MI",
+            "YYYY-MM-DD HH24:MI:SS YYYY-MM-DD HH24:MI:SS YYYY-MM-DD HH24:MI:SS",
+            "DL DL DL DL",
+            "DS DS DS DS DS DS DS DS",
+        ] {
+            check(fmt);
+        }
     }
 
     #[test]
@@ -2100,6 +4654,114 @@ mod tests {
         println!("{}", res);
     }
 
+    #[test]
+    fn test_round_year() {
+        assert_eq!(round_year(0, 2023), 2000);
+        assert_eq!(round_year(49, 2023), 2049);
+        assert_eq!(round_year(50, 2023), 1950);
+        assert_eq!(round_year(99, 2000), 1999);
+        assert_eq!(round_year(0, 2049), 2000);
+        assert_eq!(round_year(49, 2050), 2149);
+        assert_eq!(round_year(50, 2050), 2050);
+        assert_eq!(round_year(99, 2099), 2099);
+        assert_eq!(round_year(0, 2099), 2100);
+
+        let mut get_now = || {
+            chrono::NaiveDate::from_ymd_opt(2060, 1, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+        };
+        let round = |input: &[u8], max_len, exact, get_now: &mut _| {
+            parse_round_year(input, max_len, exact, get_now).map(|(_, year, _)| year)
+        };
+        assert_eq!(round(b"20", 2, false, &mut get_now).unwrap(), 2120);
+        assert_eq!(round(b"5", 2, false, &mut get_now).unwrap(), 2105);
+        assert_eq!(round(b"75", 2, false, &mut get_now).unwrap(), 2075);
+        assert_eq!(round(b"1975", 2, false, &mut get_now).unwrap(), 1975);
+        assert_eq!(round(b"20", 4, false, &mut get_now).unwrap(), 2120);
+        assert_eq!(round(b"0020", 4, false, &mut get_now).unwrap(), 20);
+        assert_eq!(round(b"20", 2, true, &mut get_now).unwrap(), 2120);
+        assert_eq!(round(b"20", 4, true, &mut get_now).unwrap(), 2120);
+        assert_eq!(round(b"2020", 4, true, &mut get_now).unwrap(), 2020);
+        assert!(round(b"5", 2, true, &mut get_now).is_err());
+        assert!(round(b"020", 4, true, &mut get_now).is_err());
+    }
+
+    #[test]
+    fn test_sunday_year_week() {
+        fn year_week(year: i32, month: u32, day: u32) -> (i32, u32) {
+            NaiveDateTime {
+                year,
+                month,
+                day,
+                ..NaiveDateTime::new()
+            }
+            .sunday_year_week(None)
+            .unwrap()
+        }
+
+        assert_eq!(year_week(2021, 3, 3), (2021, 9));
+        assert_eq!(year_week(2021, 1, 1), (2020, 52));
+        assert_eq!(year_week(1, 1, 1), (-1, 53));
+        assert_eq!(year_week(-1, 1, 1), (-2, 52));
+    }
+
+    #[test]
+    fn test_spelled_number() {
+        fn spell(number: u32, ordinal: bool) -> String {
+            let mut spelled = SpelledNumber::new();
+            spelled.push_number(number);
+            if ordinal {
+                spelled.make_ordinal();
+            }
+            let mut s = String::new();
+            spelled.write(&mut s, NameStyle::Lower).unwrap();
+            s
+        }
+
+        fn spell_year(year: u32) -> String {
+            let mut spelled = SpelledNumber::new();
+            spelled.push_year(year);
+            let mut s = String::new();
+            spelled.write(&mut s, NameStyle::Capital).unwrap();
+            s
+        }
+
+        assert_eq!(spell(0, false), "zero");
+        assert_eq!(spell(0, true), "zeroth");
+        assert_eq!(spell(12, true), "twelfth");
+        assert_eq!(spell(20, true), "twentieth");
+        assert_eq!(spell(21, false), "twenty-one");
+        assert_eq!(spell(21, true), "twenty-first");
+        assert_eq!(spell(100, true), "one hundredth");
+        assert_eq!(spell(366, false), "three hundred sixty-six");
+        assert_eq!(
+            spell(86_399, false),
+            "eighty-six thousand three hundred ninety-nine"
+        );
+        assert_eq!(
+            spell(5_373_484, true),
+            "five million three hundred seventy-three thousand four hundred eighty-fourth"
+        );
+
+        assert_eq!(spell_year(1), "One");
+        assert_eq!(spell_year(987), "Nine Eighty-Seven");
+        assert_eq!(spell_year(1900), "Nineteen Hundred");
+        assert_eq!(spell_year(1999), "Nineteen Ninety-Nine");
+        assert_eq!(spell_year(2000), "Two Thousand");
+        assert_eq!(spell_year(2005), "Two Thousand Five");
+        assert_eq!(spell_year(2010), "Twenty Ten");
+        assert_eq!(spell_year(4712), "Forty-Seven Twelve");
+
+        for (number, suffix) in [(0, "th"), (1, "st"), (2, "nd"), (3, "rd"), (4, "th")] {
+            assert_eq!(ordinal_suffix(number, NameStyle::Lower), suffix);
+        }
+        for (number, suffix) in [(11, "TH"), (12, "TH"), (13, "TH"), (21, "ST"), (112, "TH")] {
+            assert_eq!(ordinal_suffix(number, NameStyle::Upper), suffix);
+        }
+    }
+
     #[test]
     fn test_write_u32() {
         fn assert(val: u32, expected: &str, width: usize) {
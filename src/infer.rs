@@ -0,0 +1,622 @@
+//! Inference of format models from samples.
+
+use crate::error::Result;
+use crate::{DateOrder, Formatter, Locale, Time, Timestamp};
+use std::cmp::Reverse;
+
+/// A format model inferred from samples by [`Formatter::infer`].
+#[derive(Debug)]
+pub struct FormatCandidate {
+    model: String,
+    formatter: Formatter,
+    matches: usize,
+}
+
+impl FormatCandidate {
+    /// Gets the Oracle format model, e.g. 'DD/MM/YYYY HH24:MI:SS'.
+    #[inline]
+    pub fn model(&self) -> &str {
+        &self.model
+    }
+
+    /// Gets the formatter of the model.
+    #[inline]
+    pub const fn formatter(&self) -> &Formatter {
+        &self.formatter
+    }
+
+    /// Converts into the formatter of the model.
+    #[inline]
+    pub fn into_formatter(self) -> Formatter {
+        self.formatter
+    }
+
+    /// Gets the number of samples parsed by the model.
+    #[inline]
+    pub const fn matches(&self) -> usize {
+        self.matches
+    }
+}
+
+impl Formatter {
+    /// Infers the Oracle format models of a column from samples of its values, and returns
+    /// the candidates ranked by how many samples each parses, the candidates which parse
+    /// no sample are left out.
+    ///
+    /// The samples are grouped by their shapes, the numbers are taken as the year, month and
+    /// day, or the hour, minute and second by the separators and the ranges of the values,
+    /// so '13/10/2021' is read as 'DD/MM/YYYY' while '10/01/2021' may be either
+    /// 'DD/MM/YYYY' or 'MM/DD/YYYY', of which the one of the given `order` comes first
+    /// as in `Timestamp::parse_auto`. A year of two digits is read as 'RR', and the years of
+    /// samples with both two and four digits as either 'YYYY' or 'RR'.
+    /// English month and day names, meridian indicators and digits without delimiters,
+    /// e.g. '20211001133005', are recognized as well.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use sqldatetime::{DateOrder, Formatter, Timestamp};
+    ///
+    /// let samples = ["10/01/2021 13:30:05", "10/13/2021 08:00:00", "N/A"];
+    /// let candidates = Formatter::infer(&samples, DateOrder::DayMonthYear).unwrap();
+    /// assert_eq!(candidates[0].model(), "MM/DD/YYYY HH24:MI:SS");
+    /// assert_eq!(candidates[0].matches(), 2);
+    ///
+    /// let ts: Timestamp = candidates[0].formatter().parse("12/31/2021 23:59:59").unwrap();
+    /// ```
+    pub fn infer<S: AsRef<str>>(samples: &[S], order: DateOrder) -> Result<Vec<FormatCandidate>> {
+        let mut groups: Vec<Group> = Vec::new();
+        for sample in samples {
+            let tokens = tokenize(sample.as_ref().trim());
+            if tokens.is_empty() {
+                continue;
+            }
+            match groups.iter_mut().find(|group| group.has_shape(&tokens)) {
+                Some(group) => group.add(&tokens),
+                None => groups.push(Group::new(&tokens)),
+            }
+        }
+        // The models of the most common shapes come first among the ties
+        groups.sort_by_key(|group| Reverse(group.count));
+
+        let mut models: Vec<(String, bool)> = Vec::new();
+        for group in groups.iter() {
+            for model in group.models(order) {
+                if !models.contains(&model) {
+                    models.push(model);
+                }
+            }
+        }
+
+        let mut candidates = Vec::new();
+        for (model, has_date) in models {
+            // A model the parser rejects is no candidate, it must not fail the other ones
+            let formatter = match Formatter::try_new(&model) {
+                Ok(formatter) => formatter,
+                Err(_) => continue,
+            };
+            let matches = samples
+                .iter()
+                .filter(|sample| {
+                    let sample = sample.as_ref().trim();
+                    if has_date {
+                        formatter.parse::<_, Timestamp>(sample).is_ok()
+                    } else {
+                        formatter.parse::<_, Time>(sample).is_ok()
+                    }
+                })
+                .count();
+            if matches > 0 {
+                candidates.push(FormatCandidate {
+                    model,
+                    formatter,
+                    matches,
+                });
+            }
+        }
+        candidates.sort_by_key(|candidate| Reverse(candidate.matches));
+        Ok(candidates)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token<'a> {
+    Number(&'a str),
+    Word(&'a str),
+    Separator(char),
+    Blank,
+}
+
+/// Splits a sample into runs of digits, letters and spaces, and single separators.
+fn tokenize(sample: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = sample;
+    while let Some(ch) = rest.chars().next() {
+        let run = |is: fn(&char) -> bool| {
+            rest.char_indices()
+                .find(|(_, ch)| !is(ch))
+                .map_or(rest.len(), |(index, _)| index)
+        };
+        let (token, len) = if ch.is_ascii_digit() {
+            let len = run(char::is_ascii_digit);
+            (Token::Number(&rest[..len]), len)
+        } else if ch.is_alphabetic() {
+            let len = run(|ch| ch.is_alphabetic());
+            (Token::Word(&rest[..len]), len)
+        } else if ch.is_whitespace() {
+            (Token::Blank, run(|ch| ch.is_whitespace()))
+        } else {
+            (Token::Separator(ch), ch.len_utf8())
+        };
+        tokens.push(token);
+        rest = &rest[len..];
+    }
+    tokens
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Shape {
+    /// A number, with the length if it is digits of several fields without delimiters
+    Number(Option<usize>),
+    MonthName,
+    DayName,
+    AmPm,
+    Text(String),
+    Separator(char),
+    Blank,
+}
+
+impl Shape {
+    fn new(tokens: &[Token<'_>], index: usize) -> Self {
+        match tokens[index] {
+            Token::Number(digits) => {
+                let is_fraction = index > 0 && tokens[index - 1] == Token::Separator('.');
+                if digits.len() > 4 && !is_fraction {
+                    Shape::Number(Some(digits.len()))
+                } else {
+                    Shape::Number(None)
+                }
+            }
+            Token::Word(word) => {
+                let is_name =
+                    |names: &[&str]| names.iter().any(|name| name.eq_ignore_ascii_case(word));
+                let english = &Locale::ENGLISH;
+                if is_name(english.month_names(false)) || is_name(english.month_names(true)) {
+                    Shape::MonthName
+                } else if is_name(english.day_names(false)) || is_name(english.day_names(true)) {
+                    Shape::DayName
+                } else if is_name(&["AM", "PM"]) {
+                    Shape::AmPm
+                } else {
+                    Shape::Text(word.to_string())
+                }
+            }
+            Token::Separator(ch) => Shape::Separator(ch),
+            Token::Blank => Shape::Blank,
+        }
+    }
+}
+
+/// The range of a number, or the spelling of a word, at a position in the samples.
+#[derive(Debug, Clone)]
+struct Stats {
+    min: u64,
+    max: u64,
+    min_len: usize,
+    max_len: usize,
+    /// The first word seen, whose case is kept in the model
+    word: String,
+    /// Whether any name is spelled in full
+    full_name: bool,
+}
+
+impl Stats {
+    fn new(token: &Token<'_>) -> Self {
+        let mut stats = Stats {
+            min: u64::MAX,
+            max: 0,
+            min_len: usize::MAX,
+            max_len: 0,
+            word: String::new(),
+            full_name: false,
+        };
+        if let Token::Word(word) = token {
+            stats.word = word.to_string();
+        }
+        stats.add(token);
+        stats
+    }
+
+    fn add(&mut self, token: &Token<'_>) {
+        match token {
+            Token::Number(digits) => {
+                let value = digits.parse().unwrap_or(u64::MAX);
+                self.min = self.min.min(value);
+                self.max = self.max.max(value);
+                self.min_len = self.min_len.min(digits.len());
+                self.max_len = self.max_len.max(digits.len());
+            }
+            Token::Word(word) => self.full_name |= word.chars().count() > 3,
+            _ => {}
+        }
+    }
+
+    #[inline]
+    fn is_within(&self, min: u64, max: u64) -> bool {
+        self.min >= min && self.max <= max && self.max_len <= 2
+    }
+
+    /// Gets the models of a year of these lengths, 'YYYY' for three or four digits
+    /// and 'RR' for two digits, both if the lengths are mixed.
+    fn year_models(&self) -> Vec<&'static str> {
+        let mut models = Vec::new();
+        if self.min_len >= 2 && self.max_len <= 4 {
+            if self.max_len > 2 {
+                models.push("YYYY");
+            }
+            if self.min_len == 2 {
+                models.push("RR");
+            }
+        }
+        models
+    }
+
+    /// Writes the name in the case of the first word, e.g. 'MON', 'Mon' or 'mon'.
+    fn name(&self, model: &mut String, abbr: &str, full: &str) {
+        let name = if self.full_name { full } else { abbr };
+        let mut chars = self.word.chars();
+        let (first, rest) = match chars.next() {
+            Some(ch) if ch.is_uppercase() => (true, chars.any(char::is_uppercase)),
+            _ => (false, false),
+        };
+        if self.full_name {
+            model.push_str("FM");
+        }
+        for (index, ch) in name.chars().enumerate() {
+            if (index == 0 && first) || rest {
+                model.push(ch.to_ascii_uppercase());
+            } else {
+                model.push(ch.to_ascii_lowercase());
+            }
+        }
+        if self.full_name {
+            model.push_str("FM");
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Role {
+    Year,
+    Month,
+    Day,
+}
+
+/// Gets the orders of the year, month and day, the year comes first or last,
+/// and the day and month come in the given `order` first when the year is last.
+fn date_orders(order: DateOrder) -> [[Role; 3]; 3] {
+    let day_month = [Role::Day, Role::Month, Role::Year];
+    let month_day = [Role::Month, Role::Day, Role::Year];
+    match order {
+        DateOrder::DayMonthYear => [[Role::Year, Role::Month, Role::Day], day_month, month_day],
+        DateOrder::MonthDayYear => [[Role::Year, Role::Month, Role::Day], month_day, day_month],
+    }
+}
+
+/// Samples of the same shape.
+#[derive(Debug)]
+struct Group {
+    shapes: Vec<Shape>,
+    stats: Vec<Stats>,
+    count: usize,
+}
+
+impl Group {
+    fn new(tokens: &[Token<'_>]) -> Self {
+        Group {
+            shapes: (0..tokens.len())
+                .map(|index| Shape::new(tokens, index))
+                .collect(),
+            stats: tokens.iter().map(Stats::new).collect(),
+            count: 1,
+        }
+    }
+
+    fn has_shape(&self, tokens: &[Token<'_>]) -> bool {
+        self.shapes.len() == tokens.len()
+            && (0..tokens.len()).all(|index| self.shapes[index] == Shape::new(tokens, index))
+    }
+
+    fn add(&mut self, tokens: &[Token<'_>]) {
+        for (stats, token) in self.stats.iter_mut().zip(tokens) {
+            stats.add(token);
+        }
+        self.count += 1;
+    }
+
+    #[inline]
+    fn is_separator(&self, index: usize, ch: char) -> bool {
+        self.shapes.get(index) == Some(&Shape::Separator(ch))
+    }
+
+    /// Gets the models which may parse the samples, and whether they have a date.
+    fn models(&self, order: DateOrder) -> Vec<(String, bool)> {
+        let mut roles: Vec<Option<String>> = vec![None; self.shapes.len()];
+        let mut times = Vec::new();
+        let mut dates = Vec::new();
+        let mut has_compact_time = false;
+        let mut has_compact_date = false;
+
+        for (index, shape) in self.shapes.iter().enumerate() {
+            match shape {
+                Shape::Number(Some(len)) => {
+                    let model = match len {
+                        6 => "HH24MISS",
+                        8 => "YYYYMMDD",
+                        12 => "YYYYMMDDHH24MI",
+                        14 => "YYYYMMDDHH24MISS",
+                        _ => return Vec::new(),
+                    };
+                    has_compact_date |= *len >= 8;
+                    has_compact_time |= *len != 8;
+                    roles[index] = Some(model.to_string());
+                }
+                Shape::Number(None) => {
+                    let follows_time = index >= 2
+                        && self.is_separator(index - 1, '.')
+                        && (times.last() == Some(&(index - 2))
+                            || (has_compact_time && roles[index - 2].is_some()));
+                    if follows_time {
+                        roles[index] = Some("FF".to_string());
+                    } else if self.is_separator(index + 1, ':')
+                        || (index > 0 && self.is_separator(index - 1, ':'))
+                    {
+                        times.push(index);
+                    } else {
+                        dates.push(index);
+                    }
+                }
+                Shape::MonthName => dates.push(index),
+                _ => {}
+            }
+        }
+
+        // The hour, minute and second
+        let has_ampm = self.shapes.contains(&Shape::AmPm);
+        match times.len() {
+            0 => {}
+            2 | 3 => {
+                for (n, &index) in times.iter().enumerate() {
+                    let stats = &self.stats[index];
+                    let model = match n {
+                        0 if has_ampm && stats.is_within(1, 12) => "HH12",
+                        0 if !has_ampm && stats.is_within(0, 23) => "HH24",
+                        1 if stats.is_within(0, 59) => "MI",
+                        2 if stats.is_within(0, 59) => "SS",
+                        _ => return Vec::new(),
+                    };
+                    roles[index] = Some(model.to_string());
+                }
+            }
+            _ => return Vec::new(),
+        }
+
+        // The year, month and day in any order the values allow
+        let has_date = has_compact_date || !dates.is_empty();
+        let mut date_roles = Vec::new();
+        match dates.len() {
+            0 => date_roles.push(roles.clone()),
+            3 => {
+                for order in date_orders(order).iter() {
+                    let year = if order[0] == Role::Year {
+                        dates[0]
+                    } else {
+                        dates[2]
+                    };
+                    for year in self.stats[year].year_models() {
+                        let mut roles = roles.clone();
+                        let valid = dates.iter().zip(order.iter()).all(|(&index, role)| {
+                            let stats = &self.stats[index];
+                            let model = match (&self.shapes[index], role) {
+                                (Shape::MonthName, Role::Month) => {
+                                    let mut model = String::new();
+                                    stats.name(&mut model, "Mon", "Month");
+                                    model
+                                }
+                                (Shape::MonthName, _) => return false,
+                                (_, Role::Year) => year.to_string(),
+                                (_, Role::Month) if stats.is_within(1, 12) => "MM".to_string(),
+                                (_, Role::Day) if stats.is_within(1, 31) => "DD".to_string(),
+                                _ => return false,
+                            };
+                            roles[index] = Some(model);
+                            true
+                        });
+                        if valid {
+                            date_roles.push(roles);
+                        }
+                    }
+                }
+            }
+            _ => return Vec::new(),
+        }
+        if !has_date && times.is_empty() && !has_compact_time {
+            return Vec::new();
+        }
+
+        date_roles
+            .into_iter()
+            .filter_map(|roles| self.model(&roles).map(|model| (model, has_date)))
+            .collect()
+    }
+
+    /// Writes the model of the samples with the roles of the numbers and month names.
+    fn model(&self, roles: &[Option<String>]) -> Option<String> {
+        let mut model = String::new();
+        for (index, shape) in self.shapes.iter().enumerate() {
+            let stats = &self.stats[index];
+            match shape {
+                Shape::Number(_) | Shape::MonthName => model.push_str(roles[index].as_ref()?),
+                Shape::DayName => stats.name(&mut model, "Dy", "Day"),
+                Shape::AmPm => stats.name(&mut model, "Am", "Am"),
+                Shape::Text(text) if text == "T" => model.push('T'),
+                Shape::Text(text) => push_quoted(&mut model, text.chars()),
+                Shape::Separator(ch) => match ch {
+                    '-' | '/' | ':' | ',' | '.' | ';' => model.push(*ch),
+                    _ => push_quoted(&mut model, Some(*ch)),
+                },
+                Shape::Blank => model.push(' '),
+            }
+        }
+        Some(model)
+    }
+}
+
+/// Pushes the text as a quoted literal of the model, escaping the quotes and backslashes.
+fn push_quoted(model: &mut String, text: impl IntoIterator<Item = char>) {
+    model.push('"');
+    for ch in text {
+        if ch == '"' || ch == '\\' {
+            model.push('\\');
+        }
+        model.push(ch);
+    }
+    model.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Date, DateOrder, Formatter, Timestamp};
+
+    fn models(samples: &[&str]) -> Vec<(String, usize)> {
+        models_in(samples, DateOrder::DayMonthYear)
+    }
+
+    fn models_in(samples: &[&str], order: DateOrder) -> Vec<(String, usize)> {
+        Formatter::infer(samples, order)
+            .unwrap()
+            .iter()
+            .map(|candidate| (candidate.model().to_string(), candidate.matches()))
+            .collect()
+    }
+
+    #[test]
+    fn test_infer_format() {
+        assert_eq!(
+            models(&["2021-10-01", "2021-12-31"]),
+            vec![("YYYY-MM-DD".to_string(), 2)]
+        );
+
+        // The day and month are told apart by the values
+        assert_eq!(
+            models(&["01/10/2021", "13/10/2021"]),
+            vec![("DD/MM/YYYY".to_string(), 2)]
+        );
+        assert_eq!(
+            models(&["10/01/2021", "10/13/2021"]),
+            vec![("MM/DD/YYYY".to_string(), 2)]
+        );
+        assert_eq!(
+            models(&["10/01/2021", "11/02/2021"]),
+            vec![("DD/MM/YYYY".to_string(), 2), ("MM/DD/YYYY".to_string(), 2)]
+        );
+        // The ties are broken by the order of the day and month
+        assert_eq!(
+            models_in(&["10/01/2021", "11/02/2021"], DateOrder::MonthDayYear),
+            vec![("MM/DD/YYYY".to_string(), 2), ("DD/MM/YYYY".to_string(), 2)]
+        );
+
+        // The candidates are ranked by the samples parsed
+        assert_eq!(
+            models(&[
+                "2021-10-01 13:30:05",
+                "2021-10-02 08:00",
+                "2021-10-03 09:15:00",
+                "-"
+            ]),
+            vec![
+                ("YYYY-MM-DD HH24:MI:SS".to_string(), 3),
+                ("YYYY-MM-DD HH24:MI".to_string(), 1)
+            ]
+        );
+
+        assert_eq!(
+            models(&["01-OCT-2021", "31-DEC-2021"]),
+            vec![("DD-MON-YYYY".to_string(), 2)]
+        );
+        assert_eq!(
+            models(&["Friday, 1 October 2021 1:30 PM"]),
+            vec![("FMDayFM, DD FMMonthFM YYYY HH12:MI AM".to_string(), 1)]
+        );
+        assert_eq!(
+            models(&["2021-10-01T13:30:05.123456"]),
+            vec![("YYYY-MM-DDTHH24:MI:SS.FF".to_string(), 1)]
+        );
+        assert_eq!(
+            models(&["20211001133005", "20211231235959.5"]),
+            vec![
+                ("YYYYMMDDHH24MISS.FF".to_string(), 2),
+                ("YYYYMMDDHH24MISS".to_string(), 1)
+            ]
+        );
+        assert_eq!(
+            models(&["13:30:05", "08:00:00"]),
+            vec![("HH24:MI:SS".to_string(), 2)]
+        );
+        assert_eq!(
+            models(&["21.10.01 at 13:30"]),
+            vec![
+                ("RR.MM.DD \"at\" HH24:MI".to_string(), 1),
+                ("DD.MM.RR \"at\" HH24:MI".to_string(), 1)
+            ]
+        );
+
+        // Years of two and four digits may be either 'YYYY' or 'RR'
+        assert_eq!(
+            models(&["1999-10-01", "99-10-01"]),
+            vec![("YYYY-MM-DD".to_string(), 2), ("RR-MM-DD".to_string(), 2)]
+        );
+        assert_eq!(
+            models(&["01-OCT-99", "31-DEC-99"]),
+            vec![("DD-MON-RR".to_string(), 2)]
+        );
+
+        assert!(models(&[]).is_empty());
+        assert!(models(&["", "N/A", "1-2"]).is_empty());
+        assert!(models(&["13/13/2021"]).is_empty());
+
+        // The quotes and backslashes of the separators are escaped
+        assert_eq!(
+            models(&["2021\\10\\01"]),
+            vec![(r#"YYYY"\\"MM"\\"DD"#.to_string(), 1)]
+        );
+        assert_eq!(
+            models(&["2021\"10\"01"]),
+            vec![(r#"YYYY"\""MM"\""DD"#.to_string(), 1)]
+        );
+    }
+
+    #[test]
+    fn test_infer_format_parse() {
+        let samples = vec!["01-Oct-2021 01:30:05 pm".to_string()];
+        let candidates = Formatter::infer(&samples, DateOrder::DayMonthYear).unwrap();
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].model(), "DD-Mon-YYYY HH12:MI:SS am");
+
+        let fmt = candidates.into_iter().next().unwrap().into_formatter();
+        assert_eq!(
+            fmt.parse::<_, Timestamp>(&samples[0]).unwrap(),
+            Date::try_from_ymd(2021, 10, 1)
+                .unwrap()
+                .and_hms(13, 30, 5, 0)
+                .unwrap()
+        );
+        let mut s = String::new();
+        fmt.format(
+            Date::try_from_ymd(2021, 12, 31).unwrap().and_zero_time(),
+            &mut s,
+        )
+        .unwrap();
+        assert_eq!(s, "31-Dec-2021 12:00:00 am");
+    }
+}
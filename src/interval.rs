@@ -7,6 +7,7 @@ use crate::common::{
 use crate::error::{Error, Result};
 use crate::format::{LazyFormat, NaiveDateTime};
 use crate::interval::Sign::{Negative, Positive};
+use crate::iso8601::{self, IsoDuration};
 use crate::{Date, Time};
 use crate::{DateTime, Formatter};
 use std::cmp::Ordering;
@@ -139,6 +140,33 @@ impl IntervalYM {
         fmt.parse(input)
     }
 
+    /// Parses `IntervalYM` from an ISO 8601 duration as Oracle `TO_YMINTERVAL` does,
+    /// '[-]P[years Y][months M]', e.g. 'P1Y2M' or '-P14M'.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use sqldatetime::{Error, IntervalYM};
+    ///
+    /// let interval = IntervalYM::parse_iso8601("-P1Y2M").unwrap();
+    /// assert_eq!(interval, -IntervalYM::try_from_ym(1, 2).unwrap());
+    /// assert_eq!(interval.iso8601().to_string(), "-P1Y2M");
+    /// assert_eq!(
+    ///     IntervalYM::parse_iso8601("P1Y2DT3H").unwrap_err(),
+    ///     Error::MixedIntervalFields("day-time", "year-month")
+    /// );
+    /// ```
+    #[inline]
+    pub fn parse_iso8601<S: AsRef<str>>(input: S) -> Result<Self> {
+        iso8601::parse_interval_ym(input.as_ref())
+    }
+
+    /// Formats `IntervalYM` as an ISO 8601 duration, e.g. 'P1Y2M', omitting a zero part.
+    #[inline]
+    pub fn iso8601(self) -> impl Display {
+        IsoDuration::YearMonth(self)
+    }
+
     #[inline]
     pub(crate) const fn negate(self) -> IntervalYM {
         unsafe { IntervalYM::from_months_unchecked(-self.months()) }
@@ -443,6 +471,34 @@ impl IntervalDT {
         fmt.parse(input)
     }
 
+    /// Parses `IntervalDT` from an ISO 8601 duration as Oracle `TO_DSINTERVAL` does,
+    /// '[-]P[days D][T[hours H][minutes M][seconds[.frac] S]]', e.g. 'P3DT4H5M6.5S' or 'PT36H'.
+    /// The fraction may have any length and is truncated to microseconds.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use sqldatetime::{Error, IntervalDT};
+    ///
+    /// let interval = IntervalDT::parse_iso8601("P3DT4H5M6.5S").unwrap();
+    /// assert_eq!(interval, IntervalDT::try_from_dhms(3, 4, 5, 6, 500000).unwrap());
+    /// assert_eq!(interval.iso8601().to_string(), "P3DT4H5M6.5S");
+    /// assert_eq!(
+    ///     IntervalDT::parse_iso8601("P1MT2H").unwrap_err(),
+    ///     Error::MixedIntervalFields("year-month", "day-time")
+    /// );
+    /// ```
+    #[inline]
+    pub fn parse_iso8601<S: AsRef<str>>(input: S) -> Result<Self> {
+        iso8601::parse_interval_dt(input.as_ref())
+    }
+
+    /// Formats `IntervalDT` as an ISO 8601 duration, e.g. 'P3DT4H5M6.5S', omitting the zero parts.
+    #[inline]
+    pub fn iso8601(self) -> impl Display {
+        IsoDuration::DayTime(self)
+    }
+
     #[inline]
     pub(crate) const fn negate(self) -> IntervalDT {
         unsafe { IntervalDT::from_usecs_unchecked(-self.usecs()) }
@@ -713,9 +769,13 @@ mod tests {
         assert!(IntervalYM::parse("11", "ss").is_err());
 
         assert_eq!(
-            IntervalYM::parse("xxxx", "yy-mm").err().unwrap(),
-            Error::ParseError("the interval is invalid".to_string())
-        )
+            IntervalYM::parse("xxxx", "yy-mm")
+                .err()
+                .unwrap()
+                .to_string(),
+            "a non-numeric character was found where a numeric was expected \
+             at position 0 while matching the year, found 'x'"
+        );
         // todo invalid fields
     }
 
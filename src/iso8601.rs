@@ -0,0 +1,1047 @@
+//! ISO 8601 and RFC 3339 representations of dates, times, timestamps and intervals.
+//!
+//! The parsers are [`Date::parse_iso8601`], [`Time::parse_iso8601`],
+//! [`Timestamp::parse_iso8601`], [`IntervalYM::parse_iso8601`] and [`IntervalDT::parse_iso8601`],
+//! and the formatters are the `iso8601` methods of the types and [`Timestamp::rfc3339`].
+//!
+//! With the `serde` feature, the modules of this module serialize and deserialize the values as
+//! ISO 8601 strings in `#[serde(with = "...")]` attributes, e.g.
+//! `#[serde(with = "sqldatetime::iso8601::rfc3339")]`.
+
+use crate::common::{
+    from_astronomical_year, to_astronomical_year, MONTHS_PER_YEAR, USECONDS_PER_DAY,
+    USECONDS_PER_HOUR, USECONDS_PER_MINUTE, USECONDS_PER_SECOND,
+};
+use crate::error::{Error, Result};
+use crate::literal::{count_digits, take_number};
+use crate::{Date, IntervalDT, IntervalYM, Sign, Time, Timestamp};
+use std::fmt::{self, Display};
+
+/// The fraction digits beyond this are ignored.
+const FRACTION_MAX_DIGITS: usize = 18;
+
+/// The forms of an ISO 8601 date after the year.
+enum DateForm {
+    /// Month and day
+    Calendar(u32, u32),
+    /// ISO week and day of week (1..=7 == Mon..Sun)
+    Week(u32, u32),
+    /// Day of year
+    Ordinal(u32),
+}
+
+/// Parses an ISO 8601 date in the calendar, week or ordinal form, extended or basic,
+/// e.g. '2021-10-01', '20211001', '2021-W39-5', '2021W395', '2021-274' or '2021274'.
+pub(crate) fn parse_date(input: &str) -> Result<Date> {
+    let mut s = input.as_bytes();
+    let date = take_date(&mut s).ok_or(Error::InvalidLiteral("ISO 8601 date"))??;
+    if !s.is_empty() {
+        return Err(Error::InvalidLiteral("ISO 8601 date"));
+    }
+    Ok(date)
+}
+
+/// Parses an ISO 8601 time with an optional 'T' designator and an optional UTC offset,
+/// e.g. '12:30:00.5', 'T123000,5Z' or '12:30+08:00'.
+pub(crate) fn parse_time(input: &str) -> Result<(Time, Option<IntervalDT>)> {
+    let mut s = input.as_bytes();
+    if let [b'T' | b't', rest @ ..] = s {
+        s = rest;
+    }
+    let time = take_time(&mut s).ok_or(Error::InvalidLiteral("ISO 8601 time"))??;
+    let offset = take_offset(&mut s).ok_or(Error::InvalidLiteral("ISO 8601 time"))??;
+    if !s.is_empty() {
+        return Err(Error::InvalidLiteral("ISO 8601 time"));
+    }
+    Ok((time, offset))
+}
+
+/// Parses an ISO 8601 or RFC 3339 timestamp, whose date and time are separated by 'T' or a space,
+/// e.g. '2021-10-01T12:30:00.5Z' or '20211001T123000+0800'. A date alone is taken as midnight.
+pub(crate) fn parse_timestamp(input: &str) -> Result<(Timestamp, Option<IntervalDT>)> {
+    let mut s = input.as_bytes();
+    let date = take_date(&mut s).ok_or(Error::InvalidLiteral("ISO 8601 timestamp"))??;
+    let (time, offset) = match s {
+        [] => (Time::ZERO, None),
+        [b'T' | b't' | b' ', rest @ ..] => {
+            s = rest;
+            let time = take_time(&mut s).ok_or(Error::InvalidLiteral("ISO 8601 timestamp"))??;
+            let offset =
+                take_offset(&mut s).ok_or(Error::InvalidLiteral("ISO 8601 timestamp"))??;
+            if !s.is_empty() {
+                return Err(Error::InvalidLiteral("ISO 8601 timestamp"));
+            }
+            (time, offset)
+        }
+        _ => return Err(Error::InvalidLiteral("ISO 8601 timestamp")),
+    };
+    Ok((date.and_time(time), offset))
+}
+
+/// Takes a date from `s`, returning `None` if it does not match the grammar.
+pub(crate) fn take_date(s: &mut &[u8]) -> Option<Result<Date>> {
+    let negative = s.first() == Some(&b'-');
+    if let [b'-' | b'+', rest @ ..] = *s {
+        *s = rest;
+    }
+    let year = take_number(s, 4, 4)? as i32;
+    let year = from_astronomical_year(if negative { -year } else { year });
+
+    let form = match *s {
+        [b'-', b'W', ..] => {
+            *s = &s[2..];
+            let week = take_number(s, 2, 2)?;
+            if s.first() != Some(&b'-') {
+                return None;
+            }
+            *s = &s[1..];
+            DateForm::Week(week, take_number(s, 1, 1)?)
+        }
+        [b'-', ..] => {
+            *s = &s[1..];
+            match count_digits(s) {
+                2 => {
+                    let month = take_number(s, 2, 2)?;
+                    if s.first() != Some(&b'-') {
+                        return None;
+                    }
+                    *s = &s[1..];
+                    if count_digits(s) != 2 {
+                        return None;
+                    }
+                    DateForm::Calendar(month, take_number(s, 2, 2)?)
+                }
+                3 => DateForm::Ordinal(take_number(s, 3, 3)?),
+                _ => return None,
+            }
+        }
+        [b'W', ..] => {
+            *s = &s[1..];
+            if count_digits(s) != 3 {
+                return None;
+            }
+            DateForm::Week(take_number(s, 2, 2)?, take_number(s, 1, 1)?)
+        }
+        _ => match count_digits(s) {
+            4 => DateForm::Calendar(take_number(s, 2, 2)?, take_number(s, 2, 2)?),
+            3 => DateForm::Ordinal(take_number(s, 3, 3)?),
+            _ => return None,
+        },
+    };
+
+    Some(resolve_date(year, form))
+}
+
+/// Resolves the year and the rest of a date to a `Date`.
+fn resolve_date(year: i32, form: DateForm) -> Result<Date> {
+    match form {
+        DateForm::Calendar(month, day) => Date::try_from_ymd(year, month, day),
+        DateForm::Week(week, week_day) => {
+            if !(1..=53).contains(&week) {
+                return Err(Error::InvalidWeekOfYear(1));
+            }
+            if !(1..=7).contains(&week_day) {
+                return Err(Error::InvalidDayOfWeek);
+            }
+            let date = Date::try_from_iso_week_date(year, week, week_day)?;
+            if date.iso_week() != week {
+                return Err(Error::WeekNotInYear);
+            }
+            Ok(date)
+        }
+        DateForm::Ordinal(day) => {
+            if day == 0 {
+                return Err(Error::InvalidDayOfYear);
+            }
+            let date = Date::try_from_ymd(year, 1, 1)?.add_days(day as i32 - 1)?;
+            if date.extract().0 != year {
+                return Err(Error::InvalidDayOfYear);
+            }
+            Ok(date)
+        }
+    }
+}
+
+/// Takes a time from `s`, extended as 'hh:mm:ss' or basic as 'hhmmss', where the minutes and
+/// seconds may be omitted and the last part may have a fraction of any length after '.' or ','.
+pub(crate) fn take_time(s: &mut &[u8]) -> Option<Result<Time>> {
+    let hour = take_number(s, 2, 2)?;
+    let mut parts = [hour, 0, 0];
+    let mut count = 1;
+
+    let extended = s.first() == Some(&b':');
+    while count < 3 {
+        if extended {
+            if s.first() != Some(&b':') {
+                break;
+            }
+            *s = &s[1..];
+        } else if count_digits(s) < 2 {
+            break;
+        }
+        parts[count] = take_number(s, 2, 2)?;
+        count += 1;
+    }
+
+    let unit = [USECONDS_PER_HOUR, USECONDS_PER_MINUTE, USECONDS_PER_SECOND][count - 1];
+    let fraction = match s {
+        [b'.' | b',', rest @ ..] => {
+            *s = rest;
+            take_fraction(s, unit)?
+        }
+        _ => 0,
+    };
+
+    let [hour, minute, sec] = parts;
+    Some(
+        Time::try_from_hms(hour, minute, sec, 0)
+            .and_then(|time| Time::try_from_usecs(time.usecs() + fraction)),
+    )
+}
+
+/// Takes at least one digit of a fraction of the `unit` from `s`, and returns the microseconds
+/// it makes, truncating the rest.
+fn take_fraction(s: &mut &[u8], unit: i64) -> Option<i64> {
+    let digits = count_digits(s);
+    if digits == 0 {
+        return None;
+    }
+    let significant = digits.min(FRACTION_MAX_DIGITS);
+    let numerator = s[..significant]
+        .iter()
+        .fold(0u128, |number, &ch| number * 10 + (ch - b'0') as u128);
+    *s = &s[digits..];
+    Some((numerator * unit as u128 / 10u128.pow(significant as u32)) as i64)
+}
+
+/// Takes the optional UTC offset from `s`: 'Z', '±hh:mm', '±hhmm' or '±hh'.
+pub(crate) fn take_offset(s: &mut &[u8]) -> Option<Result<Option<IntervalDT>>> {
+    let negative = match s.first() {
+        None => return Some(Ok(None)),
+        Some(b'Z' | b'z') => {
+            *s = &s[1..];
+            return Some(Ok(Some(IntervalDT::ZERO)));
+        }
+        Some(b'+') => false,
+        Some(b'-') => true,
+        _ => return None,
+    };
+    *s = &s[1..];
+
+    let hour = take_number(s, 2, 2)?;
+    let minute = match s {
+        [b':', rest @ ..] => {
+            *s = rest;
+            take_number(s, 2, 2)?
+        }
+        [b'0'..=b'9', ..] => take_number(s, 2, 2)?,
+        _ => 0,
+    };
+    if count_digits(s) != 0 {
+        return None;
+    }
+
+    if hour > 23 {
+        return Some(Err(Error::TimeOutOfRange));
+    }
+    if minute > 59 {
+        return Some(Err(Error::InvalidMinute));
+    }
+    let usecs = hour as i64 * USECONDS_PER_HOUR + minute as i64 * USECONDS_PER_MINUTE;
+    let usecs = if negative { -usecs } else { usecs };
+    Some(Ok(Some(unsafe { IntervalDT::from_usecs_unchecked(usecs) })))
+}
+
+/// Formats a date, time or timestamp in the ISO 8601 extended form, with the fraction of seconds
+/// only when it is not zero, and 'Z' after the time for UTC.
+pub(crate) struct Iso8601 {
+    pub(crate) date: Option<Date>,
+    pub(crate) time: Option<Time>,
+    pub(crate) utc: bool,
+}
+
+impl Display for Iso8601 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(date) = self.date {
+            let (year, month, day) = date.extract();
+            let year = to_astronomical_year(year);
+            if year < 0 {
+                write!(f, "-{:04}-{:02}-{:02}", -year, month, day)?;
+            } else {
+                write!(f, "{:04}-{:02}-{:02}", year, month, day)?;
+            }
+        }
+        if let Some(time) = self.time {
+            if self.date.is_some() {
+                f.write_str("T")?;
+            }
+            let (hour, minute, sec, usec) = time.extract();
+            write!(f, "{:02}:{:02}:{:02}", hour, minute, sec)?;
+            write_fraction(f, usec)?;
+            if self.utc {
+                f.write_str("Z")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Writes the microseconds as a fraction of seconds without trailing zeros, nothing if zero.
+fn write_fraction(f: &mut fmt::Formatter<'_>, usec: u32) -> fmt::Result {
+    if usec == 0 {
+        return Ok(());
+    }
+    let (mut fraction, mut width) = (usec, 6);
+    while fraction % 10 == 0 {
+        fraction /= 10;
+        width -= 1;
+    }
+    write!(f, ".{:0width$}", fraction, width = width)
+}
+
+/// The designators of an ISO 8601 duration in order, the date ones before 'T'
+/// and the time ones after.
+const DURATION_DESIGNATORS: [u8; 6] = [b'Y', b'M', b'D', b'H', b'M', b'S'];
+
+/// The parts of an ISO 8601 duration, in the order of `DURATION_DESIGNATORS`,
+/// and the microseconds of the seconds.
+struct Duration {
+    negative: bool,
+    parts: [Option<u64>; 6],
+    usec: i64,
+}
+
+/// Parses an ISO 8601 duration in the form of Oracle `TO_YMINTERVAL` and `TO_DSINTERVAL`,
+/// '[-]P[nY][nM][nD][T[nH][nM][n[.frac]S]]', where only the seconds may have a fraction.
+fn parse_duration(input: &str) -> Result<Duration> {
+    let mut s = input.as_bytes();
+    take_duration(&mut s)
+        .filter(|_| s.is_empty())
+        .ok_or(Error::InvalidLiteral("ISO 8601 duration"))?
+}
+
+/// Takes a duration from `s`, returning `None` if it does not match the grammar.
+fn take_duration(s: &mut &[u8]) -> Option<Result<Duration>> {
+    let negative = s.first() == Some(&b'-');
+    if let [b'-' | b'+', rest @ ..] = *s {
+        *s = rest;
+    }
+    if s.first() != Some(&b'P') {
+        return None;
+    }
+    *s = &s[1..];
+
+    let mut duration = Duration {
+        negative,
+        parts: [None; 6],
+        usec: 0,
+    };
+    let mut overflow = false;
+    // The index of the next designator allowed, the time ones start from 3 after 'T'
+    let mut next = 0;
+    let mut time = false;
+    while !s.is_empty() {
+        if s[0] == b'T' && !time {
+            *s = &s[1..];
+            next = 3;
+            time = true;
+            continue;
+        }
+
+        let digits = count_digits(s);
+        if digits == 0 {
+            return None;
+        }
+        let value = s[..digits].iter().try_fold(0u64, |value, &ch| {
+            value.checked_mul(10)?.checked_add((ch - b'0') as u64)
+        });
+        *s = &s[digits..];
+        let usec = match s {
+            [b'.' | b',', rest @ ..] => {
+                *s = rest;
+                Some(take_fraction(s, USECONDS_PER_SECOND)?)
+            }
+            _ => None,
+        };
+
+        let index = (next..if time { 6 } else { 3 })
+            .find(|&i| s.first() == Some(&DURATION_DESIGNATORS[i]))?;
+        *s = &s[1..];
+        if usec.is_some() && index != 5 {
+            return None;
+        }
+        overflow |= value.is_none();
+        duration.parts[index] = Some(value.unwrap_or(u64::MAX));
+        if let Some(usec) = usec {
+            duration.usec = usec;
+        }
+        next = index + 1;
+    }
+
+    // 'P' and 'T' must be followed by at least one part
+    let parts = if time {
+        &duration.parts[3..]
+    } else {
+        &duration.parts[..]
+    };
+    if parts.iter().all(Option::is_none) {
+        return None;
+    }
+    if overflow {
+        return Some(Err(Error::IntervalOutOfRange));
+    }
+    Some(Ok(duration))
+}
+
+/// Parses an `IntervalYM` from an ISO 8601 duration of years and months, e.g. 'P1Y2M'.
+pub(crate) fn parse_interval_ym(input: &str) -> Result<IntervalYM> {
+    let duration = parse_duration(input)?;
+    if duration.parts[2..].iter().any(Option::is_some) {
+        return Err(Error::MixedIntervalFields("day-time", "year-month"));
+    }
+    let [years, months, ..] = duration.parts;
+    let months = (years.unwrap_or(0) as u128 * MONTHS_PER_YEAR as u128
+        + months.unwrap_or(0) as u128)
+        .min(i32::MAX as u128) as i32;
+    let interval = IntervalYM::try_from_months(months)?;
+    Ok(if duration.negative {
+        -interval
+    } else {
+        interval
+    })
+}
+
+/// Parses an `IntervalDT` from an ISO 8601 duration of days, hours, minutes and seconds,
+/// e.g. 'P3DT4H5M6.5S'.
+pub(crate) fn parse_interval_dt(input: &str) -> Result<IntervalDT> {
+    let duration = parse_duration(input)?;
+    if duration.parts[..2].iter().any(Option::is_some) {
+        return Err(Error::MixedIntervalFields("year-month", "day-time"));
+    }
+    let units = [
+        USECONDS_PER_DAY,
+        USECONDS_PER_HOUR,
+        USECONDS_PER_MINUTE,
+        USECONDS_PER_SECOND,
+    ];
+    let usecs = duration.parts[2..]
+        .iter()
+        .zip(units)
+        .map(|(part, unit)| part.unwrap_or(0) as u128 * unit as u128)
+        .sum::<u128>()
+        + duration.usec as u128;
+    let interval = IntervalDT::try_from_usecs(usecs.min(i64::MAX as u128) as i64)?;
+    Ok(if duration.negative {
+        -interval
+    } else {
+        interval
+    })
+}
+
+/// Formats an interval as an ISO 8601 duration, e.g. 'P1Y2M' or '-P3DT4H5M6.5S',
+/// omitting the zero parts, where zero is 'P0M' or 'PT0S'.
+pub(crate) enum IsoDuration {
+    YearMonth(IntervalYM),
+    DayTime(IntervalDT),
+}
+
+impl Display for IsoDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            IsoDuration::YearMonth(interval) => {
+                let (sign, year, month) = interval.extract();
+                if sign == Sign::Negative {
+                    f.write_str("-")?;
+                }
+                f.write_str("P")?;
+                if year != 0 {
+                    write!(f, "{}Y", year)?;
+                }
+                if month != 0 || year == 0 {
+                    write!(f, "{}M", month)?;
+                }
+            }
+            IsoDuration::DayTime(interval) => {
+                let (sign, day, hour, minute, sec, usec) = interval.extract();
+                if sign == Sign::Negative {
+                    f.write_str("-")?;
+                }
+                f.write_str("P")?;
+                if day != 0 {
+                    write!(f, "{}D", day)?;
+                }
+                if interval.usecs() % USECONDS_PER_DAY != 0 || day == 0 {
+                    f.write_str("T")?;
+                    if hour != 0 {
+                        write!(f, "{}H", hour)?;
+                    }
+                    if minute != 0 {
+                        write!(f, "{}M", minute)?;
+                    }
+                    if sec != 0 || usec != 0 || interval.usecs() == 0 {
+                        write!(f, "{}", sec)?;
+                        write_fraction(f, usec)?;
+                        f.write_str("S")?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+mod visitor {
+    use crate::error::Result;
+    use serde_crate::de::{self, Visitor};
+    use std::fmt;
+    use std::marker::PhantomData;
+
+    /// Deserializes a value from a string by a parse function.
+    pub(super) struct StrVisitor<T> {
+        pub(super) expecting: &'static str,
+        pub(super) parse: fn(&str) -> Result<T>,
+        pub(super) marker: PhantomData<T>,
+    }
+
+    impl<'de, T> Visitor<'de> for StrVisitor<T> {
+        type Value = T;
+
+        #[inline]
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str(self.expecting)
+        }
+
+        #[inline]
+        fn visit_str<E>(self, v: &str) -> std::result::Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            (self.parse)(v).map_err(de::Error::custom)
+        }
+    }
+}
+
+/// Serializes and deserializes a `Date` as an ISO 8601 date, e.g. '2021-10-01',
+/// accepting any form of `Date::parse_iso8601`.
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod date {
+    use super::visitor::StrVisitor;
+    use crate::Date;
+    use serde_crate::{Deserializer, Serializer};
+    use std::marker::PhantomData;
+
+    /// Serializes a `Date` as an ISO 8601 date.
+    #[inline]
+    pub fn serialize<S: Serializer>(date: &Date, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&date.iso8601())
+    }
+
+    /// Deserializes a `Date` from an ISO 8601 date.
+    #[inline]
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Date, D::Error> {
+        deserializer.deserialize_str(StrVisitor {
+            expecting: "an ISO 8601 date",
+            parse: super::parse_date,
+            marker: PhantomData,
+        })
+    }
+}
+
+/// Serializes and deserializes a `Time` as an ISO 8601 time without an offset, e.g. '12:30:00.5',
+/// rejecting a time with an offset, which is ambiguous as a local time.
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod time {
+    use super::visitor::StrVisitor;
+    use crate::{Error, Time};
+    use serde_crate::{Deserializer, Serializer};
+    use std::marker::PhantomData;
+
+    /// Serializes a `Time` as an ISO 8601 time.
+    #[inline]
+    pub fn serialize<S: Serializer>(time: &Time, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&time.iso8601())
+    }
+
+    /// Deserializes a `Time` from an ISO 8601 time without an offset.
+    #[inline]
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Time, D::Error> {
+        deserializer.deserialize_str(StrVisitor {
+            expecting: "an ISO 8601 time without an offset",
+            parse: |input| match Time::parse_iso8601(input)? {
+                (time, None) => Ok(time),
+                (_, Some(_)) => Err(Error::InvalidLiteral("local ISO 8601 time")),
+            },
+            marker: PhantomData,
+        })
+    }
+}
+
+/// Serializes and deserializes a `Timestamp` as an ISO 8601 timestamp without an offset,
+/// e.g. '2021-10-01T12:30:00.5', rejecting a timestamp with an offset, which is ambiguous
+/// as a local time. See [`rfc3339`] for timestamps with offsets.
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod timestamp {
+    use super::visitor::StrVisitor;
+    use crate::{Error, Timestamp};
+    use serde_crate::{Deserializer, Serializer};
+    use std::marker::PhantomData;
+
+    /// Serializes a `Timestamp` as an ISO 8601 timestamp.
+    #[inline]
+    pub fn serialize<S: Serializer>(ts: &Timestamp, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&ts.iso8601())
+    }
+
+    /// Deserializes a `Timestamp` from an ISO 8601 timestamp without an offset.
+    #[inline]
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Timestamp, D::Error> {
+        deserializer.deserialize_str(StrVisitor {
+            expecting: "an ISO 8601 timestamp without an offset",
+            parse: |input| match Timestamp::parse_iso8601(input)? {
+                (ts, None) => Ok(ts),
+                (_, Some(_)) => Err(Error::InvalidLiteral("local ISO 8601 timestamp")),
+            },
+            marker: PhantomData,
+        })
+    }
+}
+
+/// Serializes and deserializes a `Timestamp` in UTC as an RFC 3339 timestamp,
+/// e.g. '2021-10-01T12:30:00.5Z', normalizing an offset to UTC.
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod rfc3339 {
+    use super::visitor::StrVisitor;
+    use crate::Timestamp;
+    use serde_crate::{Deserializer, Serializer};
+    use std::marker::PhantomData;
+
+    /// Serializes a `Timestamp` in UTC as an RFC 3339 timestamp.
+    #[inline]
+    pub fn serialize<S: Serializer>(ts: &Timestamp, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&ts.rfc3339())
+    }
+
+    /// Deserializes a `Timestamp` from an ISO 8601 timestamp, normalized to UTC.
+    #[inline]
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Timestamp, D::Error> {
+        deserializer.deserialize_str(StrVisitor {
+            expecting: "an RFC 3339 timestamp",
+            parse: |input| Timestamp::parse_iso8601_utc(input),
+            marker: PhantomData,
+        })
+    }
+}
+
+/// Serializes and deserializes an `IntervalYM` as an ISO 8601 duration, e.g. 'P1Y2M'.
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod interval_ym {
+    use super::visitor::StrVisitor;
+    use crate::IntervalYM;
+    use serde_crate::{Deserializer, Serializer};
+    use std::marker::PhantomData;
+
+    /// Serializes an `IntervalYM` as an ISO 8601 duration.
+    #[inline]
+    pub fn serialize<S: Serializer>(
+        interval: &IntervalYM,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&interval.iso8601())
+    }
+
+    /// Deserializes an `IntervalYM` from an ISO 8601 duration.
+    #[inline]
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<IntervalYM, D::Error> {
+        deserializer.deserialize_str(StrVisitor {
+            expecting: "an ISO 8601 duration of years and months",
+            parse: super::parse_interval_ym,
+            marker: PhantomData,
+        })
+    }
+}
+
+/// Serializes and deserializes an `IntervalDT` as an ISO 8601 duration, e.g. 'P3DT4H5M6.5S'.
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod interval_dt {
+    use super::visitor::StrVisitor;
+    use crate::IntervalDT;
+    use serde_crate::{Deserializer, Serializer};
+    use std::marker::PhantomData;
+
+    /// Serializes an `IntervalDT` as an ISO 8601 duration.
+    #[inline]
+    pub fn serialize<S: Serializer>(
+        interval: &IntervalDT,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&interval.iso8601())
+    }
+
+    /// Deserializes an `IntervalDT` from an ISO 8601 duration.
+    #[inline]
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<IntervalDT, D::Error> {
+        deserializer.deserialize_str(StrVisitor {
+            expecting: "an ISO 8601 duration of days and time",
+            parse: super::parse_interval_dt,
+            marker: PhantomData,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Date, Error, IntervalDT, IntervalYM, Time, Timestamp};
+
+    #[test]
+    fn test_parse_iso8601() {
+        let date = Date::try_from_ymd(2021, 10, 1).unwrap();
+        for input in [
+            "2021-10-01",
+            "20211001",
+            "2021-W39-5",
+            "2021W395",
+            "2021-274",
+            "2021274",
+            "+2021-10-01",
+        ] {
+            assert_eq!(Date::parse_iso8601(input).unwrap(), date, "{}", input);
+        }
+        assert_eq!(
+            Date::parse_iso8601("2020-W53-7").unwrap(),
+            Date::try_from_ymd(2021, 1, 3).unwrap()
+        );
+        assert_eq!(
+            Date::parse_iso8601("2020-366").unwrap(),
+            Date::try_from_ymd(2020, 12, 31).unwrap()
+        );
+        assert_eq!(
+            Date::parse_iso8601("0000-01-01").unwrap(),
+            Date::try_from_ymd(-1, 1, 1).unwrap()
+        );
+        assert_eq!(Date::parse_iso8601("-4711-01-01").unwrap(), Date::MIN);
+
+        assert_eq!(
+            Date::parse_iso8601("2021-W53-1").unwrap_err(),
+            Error::WeekNotInYear
+        );
+        assert_eq!(
+            Date::parse_iso8601("2021-W54-1").unwrap_err(),
+            Error::InvalidWeekOfYear(1)
+        );
+        assert_eq!(
+            Date::parse_iso8601("2021-366").unwrap_err(),
+            Error::InvalidDayOfYear
+        );
+        assert_eq!(
+            Date::parse_iso8601("2021-13-01").unwrap_err(),
+            Error::InvalidMonth
+        );
+        for input in [
+            "2021-10",
+            "2021-1001",
+            "202110-01",
+            "21-10-01",
+            "2021-10-01T",
+        ] {
+            assert_eq!(
+                Date::parse_iso8601(input).unwrap_err(),
+                Error::InvalidLiteral("ISO 8601 date"),
+                "{}",
+                input
+            );
+        }
+
+        let time = Time::try_from_hms(12, 30, 5, 123456).unwrap();
+        for input in [
+            "12:30:05.123456",
+            "T12:30:05,1234567891",
+            "123005.123456",
+            "t123005.1234569",
+        ] {
+            assert_eq!(
+                Time::parse_iso8601(input).unwrap(),
+                (time, None),
+                "{}",
+                input
+            );
+        }
+        assert_eq!(
+            Time::parse_iso8601("12:30").unwrap().0,
+            Time::try_from_hms(12, 30, 0, 0).unwrap()
+        );
+        assert_eq!(
+            Time::parse_iso8601("12.5").unwrap().0,
+            Time::try_from_hms(12, 30, 0, 0).unwrap()
+        );
+        assert_eq!(
+            Time::parse_iso8601("1230,25").unwrap().0,
+            Time::try_from_hms(12, 30, 15, 0).unwrap()
+        );
+        assert_eq!(
+            Time::parse_iso8601("01:00:00+08:00").unwrap(),
+            (
+                Time::try_from_hms(1, 0, 0, 0).unwrap(),
+                Some(IntervalDT::try_from_dhms(0, 8, 0, 0, 0).unwrap())
+            )
+        );
+        assert_eq!(
+            Time::parse_iso8601_utc("01:00:00+08:00").unwrap(),
+            Time::try_from_hms(17, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            Time::parse_iso8601("24:00:00").unwrap_err(),
+            Error::TimeOutOfRange
+        );
+        for input in ["12:30:", "12:3005", "12:30:05.", "1230+8", "12:30:05 Z"] {
+            assert_eq!(
+                Time::parse_iso8601(input).unwrap_err(),
+                Error::InvalidLiteral("ISO 8601 time"),
+                "{}",
+                input
+            );
+        }
+
+        let ts = date.and_hms(12, 30, 5, 500000).unwrap();
+        for input in [
+            "2021-10-01T12:30:05.5",
+            "2021-10-01 12:30:05.5Z",
+            "20211001t123005,5z",
+            "2021-W39-5T12:30:05.50",
+        ] {
+            assert_eq!(Timestamp::parse_iso8601(input).unwrap().0, ts, "{}", input);
+        }
+        assert_eq!(
+            Timestamp::parse_iso8601("2021-10-01").unwrap(),
+            (date.and_zero_time(), None)
+        );
+        assert_eq!(
+            Timestamp::parse_iso8601("2021-10-01T12:30:05.5-0230").unwrap(),
+            (
+                ts,
+                Some(IntervalDT::try_from_dhms(0, 2, 30, 0, 0).unwrap().negate())
+            )
+        );
+        assert_eq!(
+            Timestamp::parse_iso8601_utc("2021-10-01T20:30:05.5+08").unwrap(),
+            ts
+        );
+        assert_eq!(
+            Timestamp::parse_iso8601_utc("2021-10-01T01:00:00+08:00").unwrap(),
+            Date::try_from_ymd(2021, 9, 30)
+                .unwrap()
+                .and_hms(17, 0, 0, 0)
+                .unwrap()
+        );
+        assert_eq!(
+            Timestamp::parse_iso8601("2021-10-01T12:00+24:00").unwrap_err(),
+            Error::TimeOutOfRange
+        );
+        assert_eq!(
+            Timestamp::parse_iso8601_utc("-4711-01-01T00:00:00+01:00").unwrap_err(),
+            Error::DateOutOfRange
+        );
+        for input in ["2021-10-01T", "2021-10-01X12:00", "2021-10-01T12:00:00ZZ"] {
+            assert_eq!(
+                Timestamp::parse_iso8601(input).unwrap_err(),
+                Error::InvalidLiteral("ISO 8601 timestamp"),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_format_iso8601() {
+        let date = Date::try_from_ymd(2021, 10, 1).unwrap();
+        assert_eq!(date.iso8601().to_string(), "2021-10-01");
+        assert_eq!(Date::MIN.iso8601().to_string(), "-4711-01-01");
+        assert_eq!(
+            Date::try_from_ymd(-1, 12, 31)
+                .unwrap()
+                .iso8601()
+                .to_string(),
+            "0000-12-31"
+        );
+
+        assert_eq!(Time::ZERO.iso8601().to_string(), "00:00:00");
+        assert_eq!(
+            Time::try_from_hms(12, 30, 5, 120000)
+                .unwrap()
+                .iso8601()
+                .to_string(),
+            "12:30:05.12"
+        );
+        assert_eq!(Time::MAX.iso8601().to_string(), "23:59:59.999999");
+
+        let ts = date.and_hms(12, 30, 5, 1).unwrap();
+        assert_eq!(ts.iso8601().to_string(), "2021-10-01T12:30:05.000001");
+        assert_eq!(ts.rfc3339().to_string(), "2021-10-01T12:30:05.000001Z");
+        assert_eq!(
+            Timestamp::parse_iso8601_utc(ts.rfc3339().to_string()).unwrap(),
+            ts
+        );
+        assert_eq!(
+            Date::parse_iso8601(Date::MIN.iso8601().to_string()).unwrap(),
+            Date::MIN
+        );
+    }
+
+    #[test]
+    fn test_iso8601_duration() {
+        let ym = |input| IntervalYM::parse_iso8601(input);
+        assert_eq!(ym("P1Y2M").unwrap(), IntervalYM::try_from_ym(1, 2).unwrap());
+        assert_eq!(ym("P14M").unwrap(), IntervalYM::try_from_ym(1, 2).unwrap());
+        assert_eq!(ym("-P1Y").unwrap(), -IntervalYM::try_from_ym(1, 0).unwrap());
+        assert_eq!(ym("+P0Y").unwrap(), IntervalYM::ZERO);
+        assert_eq!(ym("P178000000Y").unwrap(), IntervalYM::MAX);
+        assert_eq!(ym("-P178000000Y").unwrap(), IntervalYM::MIN);
+        assert_eq!(ym("P178000000Y1M").unwrap_err(), Error::IntervalOutOfRange);
+        assert_eq!(
+            ym("P99999999999999999999Y").unwrap_err(),
+            Error::IntervalOutOfRange
+        );
+        assert_eq!(
+            ym("P1Y2M3D").unwrap_err(),
+            Error::MixedIntervalFields("day-time", "year-month")
+        );
+        assert_eq!(
+            ym("PT1H").unwrap_err(),
+            Error::MixedIntervalFields("day-time", "year-month")
+        );
+        for input in [
+            "P", "1Y", "P1", "P1M1Y", "P1Y1Y", "P1.5Y", "P-1Y", "P1YT", "p1y",
+        ] {
+            assert_eq!(
+                ym(input).unwrap_err(),
+                Error::InvalidLiteral("ISO 8601 duration"),
+                "{}",
+                input
+            );
+        }
+
+        let dt = |input| IntervalDT::parse_iso8601(input);
+        assert_eq!(
+            dt("P3DT4H5M6.5S").unwrap(),
+            IntervalDT::try_from_dhms(3, 4, 5, 6, 500000).unwrap()
+        );
+        assert_eq!(
+            dt("PT36H").unwrap(),
+            IntervalDT::try_from_dhms(1, 12, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            dt("-PT0,0000019S").unwrap(),
+            -IntervalDT::try_from_usecs(1).unwrap()
+        );
+        assert_eq!(
+            dt("P1D").unwrap(),
+            IntervalDT::try_from_dhms(1, 0, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            dt("PT90M").unwrap(),
+            IntervalDT::try_from_dhms(0, 1, 30, 0, 0).unwrap()
+        );
+        assert_eq!(dt("-P100000000D").unwrap(), IntervalDT::MIN);
+        assert_eq!(dt("P100000000DT1S").unwrap_err(), Error::IntervalOutOfRange);
+        assert_eq!(
+            dt("P1Y2D").unwrap_err(),
+            Error::MixedIntervalFields("year-month", "day-time")
+        );
+        for input in ["PT", "P1DT", "PT1S2M", "PT1.5M", "P1DT1D", "PT1H ", "P1.S"] {
+            assert_eq!(
+                dt(input).unwrap_err(),
+                Error::InvalidLiteral("ISO 8601 duration"),
+                "{}",
+                input
+            );
+        }
+        assert_eq!(dt("P1Y").unwrap_err().oracle_code(), 1867);
+
+        for (interval, expected) in [
+            (IntervalYM::try_from_ym(1, 2).unwrap(), "P1Y2M"),
+            (IntervalYM::try_from_ym(1, 0).unwrap(), "P1Y"),
+            (-IntervalYM::try_from_ym(0, 3).unwrap(), "-P3M"),
+            (IntervalYM::ZERO, "P0M"),
+        ] {
+            assert_eq!(interval.iso8601().to_string(), expected);
+            assert_eq!(ym(expected).unwrap(), interval);
+        }
+        for (interval, expected) in [
+            (
+                IntervalDT::try_from_dhms(3, 4, 5, 6, 500000).unwrap(),
+                "P3DT4H5M6.5S",
+            ),
+            (IntervalDT::try_from_dhms(2, 0, 0, 0, 0).unwrap(), "P2D"),
+            (
+                -IntervalDT::try_from_dhms(0, 0, 30, 0, 0).unwrap(),
+                "-PT30M",
+            ),
+            (
+                IntervalDT::try_from_dhms(1, 0, 0, 0, 1).unwrap(),
+                "P1DT0.000001S",
+            ),
+            (IntervalDT::ZERO, "PT0S"),
+        ] {
+            assert_eq!(interval.iso8601().to_string(), expected);
+            assert_eq!(dt(expected).unwrap(), interval);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_iso8601() {
+        use serde_crate::{Deserialize, Serialize};
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        #[serde(crate = "serde_crate")]
+        struct Event {
+            #[serde(with = "crate::iso8601::date")]
+            date: Date,
+            #[serde(with = "crate::iso8601::time")]
+            time: Time,
+            #[serde(with = "crate::iso8601::timestamp")]
+            local: Timestamp,
+            #[serde(with = "crate::iso8601::rfc3339")]
+            utc: Timestamp,
+            #[serde(with = "crate::iso8601::interval_ym")]
+            months: IntervalYM,
+            #[serde(with = "crate::iso8601::interval_dt")]
+            duration: IntervalDT,
+        }
+
+        let date = Date::try_from_ymd(2021, 10, 1).unwrap();
+        let event = Event {
+            date,
+            time: Time::try_from_hms(12, 30, 0, 500000).unwrap(),
+            local: date.and_hms(12, 30, 0, 0).unwrap(),
+            utc: date.and_hms(4, 30, 0, 0).unwrap(),
+            months: IntervalYM::try_from_ym(1, 2).unwrap(),
+            duration: IntervalDT::try_from_dhms(3, 4, 5, 6, 500000).unwrap(),
+        };
+        let json = serde_json::to_string(&event).unwrap();
+        assert_eq!(
+            json,
+            r#"{"date":"2021-10-01","time":"12:30:00.5","local":"2021-10-01T12:30:00","utc":"2021-10-01T04:30:00Z","months":"P1Y2M","duration":"P3DT4H5M6.5S"}"#
+        );
+        assert_eq!(serde_json::from_str::<Event>(&json).unwrap(), event);
+
+        let json = r#"{"date":"2021-W39-5","time":"T123000,5","local":"2021-10-01 12:30:00","utc":"2021-10-01T12:30:00+08:00","months":"P14M","duration":"PT76H5M6,5S"}"#;
+        assert_eq!(serde_json::from_str::<Event>(json).unwrap(), event);
+
+        // The local time and timestamp do not take an offset
+        let json = r#"{"date":"2021-10-01","time":"12:30:00.5+08:00","local":"2021-10-01T12:30:00","utc":"2021-10-01T04:30:00Z","months":"P1Y2M","duration":"P3DT4H5M6.5S"}"#;
+        assert!(serde_json::from_str::<Event>(json).is_err());
+        let json = r#"{"date":"2021-10-01","time":"12:30:00.5","local":"2021-10-01T12:30:00Z","utc":"2021-10-01T04:30:00Z","months":"P1Y2M","duration":"P3DT4H5M6.5S"}"#;
+        let e = serde_json::from_str::<Event>(json).unwrap_err();
+        assert!(e
+            .to_string()
+            .starts_with("not a valid local ISO 8601 timestamp literal"));
+        assert!(serde_json::from_str::<Event>(r#"{"date":"2021-10-01 12:00"}"#).is_err());
+    }
+}
@@ -7,14 +7,19 @@
 
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
-#[macro_use]
 mod util;
 
 mod common;
 mod date;
 mod error;
 mod format;
+mod infer;
 mod interval;
+pub mod iso8601;
+mod literal;
+mod locale;
+mod macros;
+mod rfc2822;
 mod time;
 mod timestamp;
 
@@ -24,15 +29,27 @@ mod oracle;
 mod serialize;
 
 pub use crate::date::{Date, Month, WeekDay};
-pub use crate::error::Error;
-pub use crate::format::Formatter;
+pub use crate::error::{DateTimeField, Error, InputError, InputField};
+pub use crate::format::{Dialect, Formatter};
+pub use crate::infer::FormatCandidate;
 pub use crate::interval::{IntervalDT, IntervalYM, Sign};
+pub use crate::literal::{DateOrder, LiteralForm};
+pub use crate::locale::{Locale, Territory};
 pub use crate::time::Time;
 pub use crate::timestamp::Timestamp;
 
 #[cfg(feature = "oracle")]
 pub use crate::oracle::Date as OracleDate;
 
+/// Items used by the exported macros, not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::format::assert_format_model;
+    pub use crate::literal::{
+        date_literal, interval_dt_literal, interval_ym_literal, time_literal, timestamp_literal,
+    };
+}
+
 /// General trait for all date time types.
 pub trait DateTime {
     /// Extracts year from date time.
@@ -0,0 +1,1033 @@
+//! Date/time literals of SQL dialects.
+
+use crate::common::{
+    HOURS_PER_DAY, MONTHS_PER_YEAR, USECONDS_PER_DAY, USECONDS_PER_HOUR, USECONDS_PER_MINUTE,
+    USECONDS_PER_SECOND,
+};
+use crate::error::{Error, Result};
+use crate::format::{parse_month_name, round_year, AmPm, NaiveDateTime};
+use crate::iso8601;
+use crate::{Date, IntervalDT, IntervalYM, Locale, Time};
+use chrono::{Datelike, Local};
+
+const USECONDS_MAX_DIGITS: usize = 6;
+
+/// The year, month and day of a date literal.
+type DateFields = (i32, u32, u32);
+
+/// The hour, minute, second and microsecond of a time literal.
+type TimeFields = (u32, u32, u32, u32);
+
+/// Maps a two-digit year as MySQL does, 70-99 are in 1970-1999 and 00-69 are in 2000-2069.
+#[inline]
+pub(crate) const fn mysql_year(year: i32) -> i32 {
+    if year < 70 {
+        year + 2000
+    } else {
+        year + 1900
+    }
+}
+
+/// Parses a MySQL DATE or DATETIME literal:
+///
+/// - 'YYYY-MM-DD', 'YY-MM-DD', in which any punctuation character may be used as the delimiter
+///   and the month and day may have one digit.
+/// - 'YYYYMMDD', 'YYMMDD' without delimiters.
+/// - 'YYYY-MM-DD hh:mm:ss[.fraction]', in which the date and time are separated by spaces or 'T',
+///   any punctuation character may be used as the delimiter of the time,
+///   and the minutes and seconds may be omitted.
+/// - 'YYYYMMDDhhmmss[.fraction]', 'YYMMDDhhmmss[.fraction]' without delimiters.
+///
+/// Digits of the fraction beyond microseconds are truncated.
+#[inline]
+pub(crate) fn parse_mysql_datetime(input: &str) -> Result<NaiveDateTime> {
+    match parse_datetime(input.trim().as_bytes()) {
+        Some((dt, _)) => Ok(dt),
+        None => Err(Error::InvalidLiteral("date")),
+    }
+}
+
+/// Parses a MySQL TIME literal:
+///
+/// - 'D hh:mm:ss[.fraction]', 'hh:mm:ss[.fraction]', 'hh:mm', 'D hh:mm', 'D hh' and 'ss'.
+/// - 'hhmmss[.fraction]', 'mmss[.fraction]' and 'ss[.fraction]' without delimiters.
+/// - A DATETIME literal with time, of which the time is taken.
+#[inline]
+pub(crate) fn parse_mysql_time(input: &str) -> Result<NaiveDateTime> {
+    let s = input.trim().as_bytes();
+    let datetime = || match parse_datetime(s) {
+        Some((dt, true)) => Some(dt),
+        _ => None,
+    };
+    match parse_time(s).or_else(datetime) {
+        Some(dt) => Ok(dt),
+        None => Err(Error::InvalidLiteral("time")),
+    }
+}
+
+/// The order of the day and the month in a date separated by slashes, e.g. '01/10/2021'.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateOrder {
+    /// 'DD/MM/YYYY' as used in Europe.
+    DayMonthYear,
+    /// 'MM/DD/YYYY' as used in the United States.
+    MonthDayYear,
+}
+
+/// The form of a literal recognized by `parse_auto`, e.g. [`Timestamp::parse_auto`](crate::Timestamp::parse_auto).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LiteralForm {
+    /// ISO 8601, e.g. '2021-10-01', '2021-10-01T12:00:00.5', '2021-10-01T12:00:00Z', '12:00:00'
+    /// or 'T12:00'.
+    Iso8601,
+    /// SQL standard, e.g. '2021-10-01 12:00:00.5', '2021-10-01 12:00:00+02:00', DATE '2021-10-01',
+    /// TIME '12:00:00' or TIMESTAMP '2021-10-01 12:00:00'.
+    Sql,
+    /// 'DD-MON-YYYY' or 'DD-MON-RR' with an English month name, e.g. '01-OCT-2021', '01-OCT-21'
+    /// or '1-October-2021 12:00:00 PM'.
+    DayMonthName,
+    /// 'DD/MM/YYYY', e.g. '01/10/2021' or '13/10/2021 12:00:00'.
+    DayMonthYear,
+    /// 'MM/DD/YYYY', e.g. '10/01/2021' or '10/13/2021 12:00 PM'.
+    MonthDayYear,
+    /// Digits without delimiters, 'YYYYMMDD', 'YYYYMMDDHHMISS[.fraction]' or 'HHMISS[.fraction]'.
+    Compact,
+}
+
+/// A literal recognized by `parse_auto`.
+pub(crate) struct AutoLiteral {
+    pub dt: NaiveDateTime,
+    pub form: LiteralForm,
+    pub has_date: bool,
+    pub has_time: bool,
+    /// The UTC offset after the time of an ISO 8601 or SQL literal, e.g. 'Z' or '+02:00'.
+    pub offset: Option<IntervalDT>,
+}
+
+/// Parses a literal of any `LiteralForm`, the surrounding spaces are ignored.
+///
+/// A date separated by slashes is read in the given `order`, unless the day is
+/// greater than 12 in which case the order is obvious.
+/// The forms 'DD-MON-YYYY' and 'DD/MM/YYYY' may have a time with a meridian indicator,
+/// e.g. '12:00:00 PM', and the years must have four digits but for 'DD-MON-RR'.
+/// The time of the ISO 8601 and SQL forms may have a UTC offset, which is parsed as
+/// `Timestamp::parse_iso8601` does.
+#[inline]
+pub(crate) fn parse_auto(
+    input: &str,
+    order: DateOrder,
+    literal: &'static str,
+) -> Result<AutoLiteral> {
+    let s = input.trim().as_bytes();
+    let mut auto = match strip_sql_keyword(s) {
+        // TIMESTAMP '2021-10-01' is allowed as well
+        Some(((date, time), s)) => match parse_auto_literal(s, order) {
+            Some(Err(e)) => return Err(e),
+            Some(Ok(auto))
+                if matches!(auto.form, LiteralForm::Iso8601 | LiteralForm::Sql)
+                    && auto.has_date == date
+                    && (auto.has_time == time || date && time) =>
+            {
+                AutoLiteral {
+                    form: LiteralForm::Sql,
+                    ..auto
+                }
+            }
+            _ => return Err(Error::InvalidLiteral(literal)),
+        },
+        None => parse_auto_literal(s, order).ok_or(Error::InvalidLiteral(literal))??,
+    };
+
+    if auto.dt.ampm.is_some() {
+        if !(1..=12).contains(&auto.dt.hour) {
+            return Err(Error::InvalidHour12);
+        }
+        auto.dt.adjust_hour12();
+    }
+    Ok(auto)
+}
+
+/// Strips the keyword and the quotes of a SQL literal, e.g. DATE '2021-10-01',
+/// returns whether the keyword is for a date and a time.
+#[inline]
+fn strip_sql_keyword(s: &[u8]) -> Option<((bool, bool), &[u8])> {
+    let keywords: [(&[u8], (bool, bool)); 3] = [
+        (b"TIMESTAMP", (true, true)),
+        (b"DATE", (true, false)),
+        (b"TIME", (false, true)),
+    ];
+    let (keyword, kind) = keywords.iter().find(|(keyword, _)| {
+        s.len() >= keyword.len() && s[..keyword.len()].eq_ignore_ascii_case(keyword)
+    })?;
+    let s = trim_start(&s[keyword.len()..]);
+    match s {
+        [b'\'', literal @ .., b'\''] => Some((*kind, literal)),
+        _ => None,
+    }
+}
+
+#[inline]
+fn parse_auto_literal(mut s: &[u8], order: DateOrder) -> Option<Result<AutoLiteral>> {
+    let mut dt = NaiveDateTime::new();
+    let mut offset = None;
+
+    macro_rules! try_range {
+        ($result: expr) => {
+            match $result? {
+                Ok(value) => value,
+                Err(e) => return Some(Err(e)),
+            }
+        };
+    }
+
+    // Sets the time of an ISO 8601 literal and takes the UTC offset after it
+    macro_rules! take_iso_time {
+        () => {{
+            let (hour, minute, sec, usec) = try_range!(iso8601::take_time(&mut s)).extract();
+            dt.hour = hour;
+            dt.minute = minute;
+            dt.sec = sec;
+            dt.usec = usec;
+            offset = try_range!(iso8601::take_offset(&mut s));
+        }};
+    }
+
+    let digits = count_digits(s);
+    let (form, has_date, has_time) = match s.get(digits) {
+        Some(b'-') if digits == 4 => {
+            let (year, month, day) = try_range!(iso8601::take_date(&mut s)).extract();
+            dt.year = year;
+            dt.month = month;
+            dt.day = day;
+            match s.first() {
+                None => (LiteralForm::Iso8601, true, false),
+                Some(b'T') => {
+                    s = &s[1..];
+                    take_iso_time!();
+                    (LiteralForm::Iso8601, true, true)
+                }
+                Some(b' ') => {
+                    s = trim_start(s);
+                    take_time(&mut s, &mut dt, false)?;
+                    offset = try_range!(iso8601::take_offset(&mut s));
+                    (LiteralForm::Sql, true, true)
+                }
+                _ => return None,
+            }
+        }
+        Some(b'-') if digits <= 2 => {
+            dt.day = take_number(&mut s, 1, 2)?;
+            s = &s[1..];
+            let (month, _, rest) = parse_month_name(s, &Locale::ENGLISH).ok()?;
+            dt.month = month as u32;
+            s = rest;
+            expect_byte(&mut s, b'-')?;
+            // Oracle's default 'DD-MON-RR' has a year of two digits
+            dt.year = match count_digits(s) {
+                2 => round_year(take_number(&mut s, 2, 2)? as i32, Local::now().year()),
+                _ => take_number(&mut s, 4, 4)? as i32,
+            };
+            let has_time = take_optional_time(&mut s, &mut dt)?;
+            (LiteralForm::DayMonthName, true, has_time)
+        }
+        Some(b'/') if digits <= 2 => {
+            let first = take_number(&mut s, 1, 2)?;
+            s = &s[1..];
+            let second = take_number(&mut s, 1, 2)?;
+            expect_byte(&mut s, b'/')?;
+            dt.year = take_number(&mut s, 4, 4)? as i32;
+            let order = if first > 12 {
+                DateOrder::DayMonthYear
+            } else if second > 12 {
+                DateOrder::MonthDayYear
+            } else {
+                order
+            };
+            let form = match order {
+                DateOrder::DayMonthYear => {
+                    dt.day = first;
+                    dt.month = second;
+                    LiteralForm::DayMonthYear
+                }
+                DateOrder::MonthDayYear => {
+                    dt.month = first;
+                    dt.day = second;
+                    LiteralForm::MonthDayYear
+                }
+            };
+            let has_time = take_optional_time(&mut s, &mut dt)?;
+            (form, true, has_time)
+        }
+        Some(b':') if digits == 2 => {
+            take_iso_time!();
+            (LiteralForm::Iso8601, false, true)
+        }
+        Some(b'T') if digits == 0 => {
+            s = &s[1..];
+            take_iso_time!();
+            (LiteralForm::Iso8601, false, true)
+        }
+        None | Some(b'.') => {
+            let has_date = match digits {
+                6 => false,
+                8 | 14 => true,
+                _ => return None,
+            };
+            if has_date {
+                dt.year = take_number(&mut s, 4, 4)? as i32;
+                dt.month = take_number(&mut s, 2, 2)?;
+                dt.day = take_number(&mut s, 2, 2)?;
+            }
+            let has_time = digits != 8;
+            if has_time {
+                dt.hour = take_number(&mut s, 2, 2)?;
+                dt.minute = take_number(&mut s, 2, 2)?;
+                dt.sec = take_number(&mut s, 2, 2)?;
+                dt.usec = take_fraction(&mut s)?;
+            }
+            (LiteralForm::Compact, has_date, has_time)
+        }
+        _ => return None,
+    };
+
+    if s.is_empty() {
+        Some(Ok(AutoLiteral {
+            dt,
+            form,
+            has_date,
+            has_time,
+            offset,
+        }))
+    } else {
+        None
+    }
+}
+
+/// Takes the time following a date and spaces if any, which may have a meridian indicator.
+#[inline]
+fn take_optional_time(s: &mut &[u8], dt: &mut NaiveDateTime) -> Option<bool> {
+    if s.is_empty() {
+        return Some(false);
+    }
+    let rest = trim_start(s);
+    if rest.len() == s.len() {
+        return None;
+    }
+    *s = rest;
+    take_time(s, dt, true)?;
+    Some(true)
+}
+
+/// Takes a time 'HH:MI[:SS[.fraction]]', and the meridian indicator after spaces
+/// if `meridian` is set.
+#[inline]
+fn take_time(s: &mut &[u8], dt: &mut NaiveDateTime, meridian: bool) -> Option<()> {
+    dt.hour = take_number(s, 1, 2)?;
+    expect_byte(s, b':')?;
+    dt.minute = take_number(s, 2, 2)?;
+    if s.first() == Some(&b':') {
+        *s = &s[1..];
+        dt.sec = take_number(s, 2, 2)?;
+        dt.usec = take_fraction(s)?;
+    }
+
+    if meridian && !s.is_empty() {
+        let rest = trim_start(s);
+        let names: [(&[u8], AmPm); 4] = [
+            (b"AM", AmPm::Am),
+            (b"PM", AmPm::Pm),
+            (b"A.M.", AmPm::Am),
+            (b"P.M.", AmPm::Pm),
+        ];
+        let (name, ampm) = names
+            .iter()
+            .find(|(name, _)| rest.eq_ignore_ascii_case(name))?;
+        dt.ampm = Some(*ampm);
+        *s = &rest[name.len()..];
+    }
+    Some(())
+}
+
+#[inline]
+fn trim_start(s: &[u8]) -> &[u8] {
+    &s[s.iter().take_while(|ch| ch.is_ascii_whitespace()).count()..]
+}
+
+#[inline]
+fn expect_byte(s: &mut &[u8], expected: u8) -> Option<()> {
+    match s.first() {
+        Some(&ch) if ch == expected => {
+            *s = &s[1..];
+            Some(())
+        }
+        _ => None,
+    }
+}
+
+/// Parses a DATE or DATETIME literal, returns the datetime and whether it has time.
+#[inline]
+fn parse_datetime(mut s: &[u8]) -> Option<(NaiveDateTime, bool)> {
+    let mut dt = NaiveDateTime::new();
+
+    let digits = count_digits(s);
+    let has_time = if digits == s.len() || s[digits] == b'.' {
+        let (year_len, has_time) = match digits {
+            6 => (2, false),
+            8 => (4, false),
+            12 => (2, true),
+            14 => (4, true),
+            _ => return None,
+        };
+        dt.year = take_number(&mut s, year_len, year_len)? as i32;
+        if year_len == 2 {
+            dt.year = mysql_year(dt.year);
+        }
+        dt.month = take_number(&mut s, 2, 2)?;
+        dt.day = take_number(&mut s, 2, 2)?;
+        if has_time {
+            dt.hour = take_number(&mut s, 2, 2)?;
+            dt.minute = take_number(&mut s, 2, 2)?;
+            dt.sec = take_number(&mut s, 2, 2)?;
+        }
+        has_time
+    } else {
+        dt.year = take_number(&mut s, 1, 4)? as i32;
+        if digits <= 2 {
+            dt.year = mysql_year(dt.year);
+        }
+        skip_punctuation(&mut s)?;
+        dt.month = take_number(&mut s, 1, 2)?;
+        skip_punctuation(&mut s)?;
+        dt.day = take_number(&mut s, 1, 2)?;
+
+        let has_time = !s.is_empty();
+        if has_time {
+            let spaces = s.iter().take_while(|ch| ch.is_ascii_whitespace()).count();
+            s = match (spaces, s.first()) {
+                (0, Some(b'T')) => &s[1..],
+                (0, _) => return None,
+                _ => &s[spaces..],
+            };
+            dt.hour = take_number(&mut s, 1, 2)?;
+            if skip_punctuation(&mut s).is_some() {
+                dt.minute = take_number(&mut s, 1, 2)?;
+                if skip_punctuation(&mut s).is_some() {
+                    dt.sec = take_number(&mut s, 1, 2)?;
+                }
+            }
+        }
+        has_time
+    };
+
+    if has_time {
+        dt.usec = take_fraction(&mut s)?;
+    }
+    if s.is_empty() {
+        Some((dt, has_time))
+    } else {
+        None
+    }
+}
+
+#[inline]
+fn parse_time(mut s: &[u8]) -> Option<NaiveDateTime> {
+    let mut dt = NaiveDateTime::new();
+
+    let digits = count_digits(s);
+    match s.get(digits) {
+        Some(b' ') if digits <= 2 => {
+            let days = take_number(&mut s, 1, 2)?;
+            s = &s[s.iter().take_while(|&&ch| ch == b' ').count()..];
+            dt.hour = days * HOURS_PER_DAY + take_number(&mut s, 1, 2)?;
+            if s.first() == Some(&b':') {
+                s = &s[1..];
+                dt.minute = take_number(&mut s, 1, 2)?;
+                if s.first() == Some(&b':') {
+                    s = &s[1..];
+                    dt.sec = take_number(&mut s, 1, 2)?;
+                }
+            }
+        }
+        Some(b':') if digits <= 3 => {
+            dt.hour = take_number(&mut s, 1, 3)?;
+            s = &s[1..];
+            dt.minute = take_number(&mut s, 1, 2)?;
+            if s.first() == Some(&b':') {
+                s = &s[1..];
+                dt.sec = take_number(&mut s, 1, 2)?;
+            }
+        }
+        // Without delimiters, the digits are taken from the seconds
+        None | Some(b'.') => {
+            if digits > 6 {
+                return None;
+            }
+            if digits > 4 {
+                dt.hour = take_number(&mut s, digits - 4, digits - 4)?;
+            }
+            if digits > 2 {
+                let len = digits.min(4) - 2;
+                dt.minute = take_number(&mut s, len, len)?;
+            }
+            dt.sec = take_number(&mut s, 1, 2)?;
+        }
+        _ => return None,
+    }
+
+    dt.usec = take_fraction(&mut s)?;
+    if s.is_empty() {
+        Some(dt)
+    } else {
+        None
+    }
+}
+
+#[inline]
+pub(crate) fn count_digits(s: &[u8]) -> usize {
+    s.iter().take_while(|ch| ch.is_ascii_digit()).count()
+}
+
+/// Takes a number of `min` to `max` digits from `s`.
+#[inline]
+pub(crate) fn take_number(s: &mut &[u8], min: usize, max: usize) -> Option<u32> {
+    let len = count_digits(s).min(max);
+    if len < min {
+        return None;
+    }
+    let number = s[..len]
+        .iter()
+        .fold(0, |number, &ch| number * 10 + (ch - b'0') as u32);
+    *s = &s[len..];
+    Some(number)
+}
+
+/// Skips the punctuation characters between two parts of a date or time.
+#[inline]
+fn skip_punctuation(s: &mut &[u8]) -> Option<()> {
+    let len = s.iter().take_while(|ch| ch.is_ascii_punctuation()).count();
+    if len == 0 {
+        return None;
+    }
+    *s = &s[len..];
+    Some(())
+}
+
+/// Takes the optional fraction of seconds, which must follow a '.'.
+#[inline]
+fn take_fraction(s: &mut &[u8]) -> Option<u32> {
+    if s.first() != Some(&b'.') {
+        return Some(0);
+    }
+    *s = &s[1..];
+
+    let digits = count_digits(s);
+    let usec = s[..digits.min(USECONDS_MAX_DIGITS)]
+        .iter()
+        .chain(std::iter::repeat(&b'0'))
+        .take(USECONDS_MAX_DIGITS)
+        .fold(0, |usec, &ch| usec * 10 + (ch - b'0') as u32);
+    *s = &s[digits..];
+    Some(usec)
+}
+
+/// Parses the tokens of a `date!` literal, e.g. `2021-10-01` and `-4712-01-01`,
+/// into the year, month and day, panicking at compile time if the date is not valid.
+#[doc(hidden)]
+pub const fn date_literal(input: &str) -> DateFields {
+    let input = input.as_bytes();
+    let (year, month, day, pos) = date_tokens(input, 0);
+    expect_end(input, pos);
+    (year, month, day)
+}
+
+/// Parses the tokens of a `time!` literal, e.g. `12:00:00.5`,
+/// into the hour, minute, second and microsecond, panicking at compile time if the time is not valid.
+#[doc(hidden)]
+pub const fn time_literal(input: &str) -> TimeFields {
+    let input = input.as_bytes();
+    let (hour, minute, sec, usec, pos) = time_tokens(input, 0);
+    expect_end(input, pos);
+    (hour, minute, sec, usec)
+}
+
+/// Parses the tokens of a `timestamp!` literal, e.g. `2021-10-01 12:00:00.5`,
+/// into the date and time fields, panicking at compile time if the timestamp is not valid.
+#[doc(hidden)]
+pub const fn timestamp_literal(input: &str) -> (DateFields, TimeFields) {
+    let input = input.as_bytes();
+    let (year, month, day, pos) = date_tokens(input, 0);
+    let (hour, minute, sec, usec, pos) = time_tokens(input, pos);
+    expect_end(input, pos);
+    ((year, month, day), (hour, minute, sec, usec))
+}
+
+/// Parses the tokens of an `interval_ym!` literal, e.g. `1-2` and `-1-2`,
+/// into months, panicking at compile time if the interval is not valid.
+#[doc(hidden)]
+pub const fn interval_ym_literal(input: &str) -> i32 {
+    let input = input.as_bytes();
+    let (negative, pos) = sign_token(input, 0);
+    let (year, pos) = number_token(input, pos);
+    let pos = expect_token(input, pos, b'-');
+    let (month, pos) = number_token(input, pos);
+    expect_end(input, pos);
+
+    if !IntervalYM::is_valid_ym(year, month) {
+        panic!("invalid interval literal");
+    }
+    let months = (year * MONTHS_PER_YEAR + month) as i32;
+    if negative {
+        -months
+    } else {
+        months
+    }
+}
+
+/// Parses the tokens of an `interval_dt!` literal, e.g. `1 02:03:04` and `-1 02:03:04.5`,
+/// into microseconds, panicking at compile time if the interval is not valid.
+#[doc(hidden)]
+pub const fn interval_dt_literal(input: &str) -> i64 {
+    let input = input.as_bytes();
+    let (negative, pos) = sign_token(input, 0);
+    let (day, pos) = number_token(input, pos);
+    let (hour, pos) = number_token(input, pos);
+    let pos = expect_token(input, pos, b':');
+    let (minute, pos) = number_token(input, pos);
+    let pos = expect_token(input, pos, b':');
+    let (sec, pos) = number_token(input, pos);
+    let (usec, pos) = fraction_token(input, pos);
+    expect_end(input, pos);
+
+    if !IntervalDT::is_valid(day, hour, minute, sec, usec) {
+        panic!("invalid interval literal");
+    }
+    let usecs = day as i64 * USECONDS_PER_DAY
+        + hour as i64 * USECONDS_PER_HOUR
+        + minute as i64 * USECONDS_PER_MINUTE
+        + sec as i64 * USECONDS_PER_SECOND
+        + usec as i64;
+    if negative {
+        -usecs
+    } else {
+        usecs
+    }
+}
+
+/// Takes the 'YYYY-MM-DD' tokens of a date, in which the year may be negative.
+const fn date_tokens(input: &[u8], pos: usize) -> (i32, u32, u32, usize) {
+    let (negative, pos) = sign_token(input, pos);
+    let (year, pos) = number_token(input, pos);
+    let pos = expect_token(input, pos, b'-');
+    let (month, pos) = number_token(input, pos);
+    let pos = expect_token(input, pos, b'-');
+    let (day, pos) = number_token(input, pos);
+
+    let year = if negative {
+        -(year as i32)
+    } else {
+        year as i32
+    };
+    if !Date::is_valid(year, month, day) {
+        panic!("invalid date literal");
+    }
+    (year, month, day, pos)
+}
+
+/// Takes the 'hh:mm:ss[.fraction]' tokens of a time.
+const fn time_tokens(input: &[u8], pos: usize) -> (u32, u32, u32, u32, usize) {
+    let (hour, pos) = number_token(input, pos);
+    let pos = expect_token(input, pos, b':');
+    let (minute, pos) = number_token(input, pos);
+    let pos = expect_token(input, pos, b':');
+    let (sec, pos) = number_token(input, pos);
+    let (usec, pos) = fraction_token(input, pos);
+
+    if !Time::is_valid(hour, minute, sec, usec) {
+        panic!("invalid time literal");
+    }
+    (hour, minute, sec, usec, pos)
+}
+
+/// Skips the spaces which `stringify!` may put between tokens.
+const fn skip_spaces(input: &[u8], mut pos: usize) -> usize {
+    while pos < input.len() && input[pos].is_ascii_whitespace() {
+        pos += 1;
+    }
+    pos
+}
+
+/// Takes an optional '-' sign.
+const fn sign_token(input: &[u8], pos: usize) -> (bool, usize) {
+    let pos = skip_spaces(input, pos);
+    if pos < input.len() && input[pos] == b'-' {
+        (true, pos + 1)
+    } else {
+        (false, pos)
+    }
+}
+
+const fn expect_token(input: &[u8], pos: usize, ch: u8) -> usize {
+    let pos = skip_spaces(input, pos);
+    if pos < input.len() && input[pos] == ch {
+        pos + 1
+    } else {
+        panic!("malformed date/time literal")
+    }
+}
+
+const fn expect_end(input: &[u8], pos: usize) {
+    if skip_spaces(input, pos) != input.len() {
+        panic!("malformed date/time literal");
+    }
+}
+
+/// Takes a number of at most 9 digits, which cannot overflow `u32`.
+const fn number_token(input: &[u8], pos: usize) -> (u32, usize) {
+    let start = skip_spaces(input, pos);
+    let mut pos = start;
+    let mut value = 0;
+    while pos < input.len() && input[pos].is_ascii_digit() {
+        if pos - start == 9 {
+            panic!("date/time literal field is too large");
+        }
+        value = value * 10 + (input[pos] - b'0') as u32;
+        pos += 1;
+    }
+
+    if pos == start {
+        panic!("malformed date/time literal");
+    }
+    (value, pos)
+}
+
+/// Takes the optional fraction of seconds of at most 6 digits, which must follow a '.'.
+const fn fraction_token(input: &[u8], pos: usize) -> (u32, usize) {
+    let dot = skip_spaces(input, pos);
+    if dot >= input.len() || input[dot] != b'.' {
+        return (0, pos);
+    }
+
+    let start = dot + 1;
+    let mut pos = start;
+    let mut usec = 0;
+    while pos < input.len() && input[pos].is_ascii_digit() {
+        if pos - start == USECONDS_MAX_DIGITS {
+            panic!("the fractional seconds must be between 0 and 999999");
+        }
+        usec = usec * 10 + (input[pos] - b'0') as u32;
+        pos += 1;
+    }
+
+    if pos == start {
+        panic!("malformed date/time literal");
+    }
+    let mut digits = pos - start;
+    while digits < USECONDS_MAX_DIGITS {
+        usec *= 10;
+        digits += 1;
+    }
+    (usec, pos)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Date, DateOrder, Error, LiteralForm, Time, Timestamp};
+
+    fn generate_ts(year: i32, month: u32, day: u32, hour: u32, min: u32, sec: u32) -> Timestamp {
+        Date::try_from_ymd(year, month, day)
+            .unwrap()
+            .and_hms(hour, min, sec, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_mysql_date_literal() {
+        let date = Date::try_from_ymd(2021, 3, 3).unwrap();
+        assert_eq!(Date::parse_mysql("2021-03-03").unwrap(), date);
+        assert_eq!(Date::parse_mysql("2021#03#03").unwrap(), date);
+        assert_eq!(Date::parse_mysql("2021^3^3").unwrap(), date);
+        assert_eq!(Date::parse_mysql("2021--3//3").unwrap(), date);
+        assert_eq!(Date::parse_mysql("21-3-3").unwrap(), date);
+        assert_eq!(Date::parse_mysql("20210303").unwrap(), date);
+        assert_eq!(Date::parse_mysql("210303").unwrap(), date);
+        assert_eq!(Date::parse_mysql(" 2021-03-03 ").unwrap(), date);
+        assert_eq!(Date::parse_mysql("2021-03-03 07:06:05").unwrap(), date);
+        assert_eq!(
+            Date::parse_mysql("700101").unwrap(),
+            Date::try_from_ymd(1970, 1, 1).unwrap()
+        );
+        assert_eq!(
+            Date::parse_mysql("69-1-1").unwrap(),
+            Date::try_from_ymd(2069, 1, 1).unwrap()
+        );
+        assert_eq!(
+            Date::parse_mysql("0069-01-01").unwrap(),
+            Date::try_from_ymd(69, 1, 1).unwrap()
+        );
+
+        assert!(Date::parse_mysql("").is_err());
+        assert!(Date::parse_mysql("2021-03").is_err());
+        assert!(Date::parse_mysql("202103").is_err());
+        assert!(Date::parse_mysql("2021033").is_err());
+        assert!(Date::parse_mysql("2021 03 03").is_err());
+        assert!(Date::parse_mysql("2021-13-01").is_err());
+        assert!(Date::parse_mysql("2021-02-29").is_err());
+        assert!(Date::parse_mysql("2021-03-03x").is_err());
+        assert!(Date::parse_mysql("2021-03-003").is_err());
+    }
+
+    #[test]
+    fn test_mysql_timestamp_literal() {
+        let ts = generate_ts(2021, 3, 3, 7, 6, 5);
+        assert_eq!(Timestamp::parse_mysql("2021-03-03 07:06:05").unwrap(), ts);
+        assert_eq!(Timestamp::parse_mysql("2021-03-03T07:06:05").unwrap(), ts);
+        assert_eq!(Timestamp::parse_mysql("2021^03^03 07+06+05").unwrap(), ts);
+        assert_eq!(Timestamp::parse_mysql("21-3-3   7:6:5").unwrap(), ts);
+        assert_eq!(Timestamp::parse_mysql("20210303070605").unwrap(), ts);
+        assert_eq!(Timestamp::parse_mysql("210303070605").unwrap(), ts);
+        assert_eq!(
+            Timestamp::parse_mysql("2021-03-03 07:06:05.004321").unwrap(),
+            ts.add_interval_dt(crate::IntervalDT::try_from_dhms(0, 0, 0, 0, 4321).unwrap())
+                .unwrap()
+        );
+        assert_eq!(
+            Timestamp::parse_mysql("20210303070605.0043219").unwrap(),
+            Timestamp::parse_mysql("2021-03-03 07:06:05.004321").unwrap()
+        );
+        assert_eq!(
+            Timestamp::parse_mysql("2021-03-03 07:06:05.5").unwrap(),
+            Timestamp::parse_mysql("2021-03-03 07:06:05.500000").unwrap()
+        );
+        assert_eq!(
+            Timestamp::parse_mysql("2021-03-03 07:06").unwrap(),
+            generate_ts(2021, 3, 3, 7, 6, 0)
+        );
+        assert_eq!(
+            Timestamp::parse_mysql("2021-03-03 07").unwrap(),
+            generate_ts(2021, 3, 3, 7, 0, 0)
+        );
+        assert_eq!(
+            Timestamp::parse_mysql("2021-03-03").unwrap(),
+            generate_ts(2021, 3, 3, 0, 0, 0)
+        );
+
+        assert!(Timestamp::parse_mysql("2021-03-03X07:06:05").is_err());
+        assert!(Timestamp::parse_mysql("2021-03-03 24:00:00").is_err());
+        assert!(Timestamp::parse_mysql("2021-03-03 07:60:00").is_err());
+        assert!(Timestamp::parse_mysql("20210303 070605").is_err());
+        assert!(Timestamp::parse_mysql("20210303.5").is_err());
+        assert!(Timestamp::parse_mysql("2021-03-03 07:06:05.1x").is_err());
+    }
+
+    #[test]
+    fn test_mysql_time_literal() {
+        let time = Time::try_from_hms(7, 6, 5, 0).unwrap();
+        assert_eq!(Time::parse_mysql("07:06:05").unwrap(), time);
+        assert_eq!(Time::parse_mysql("7:6:5").unwrap(), time);
+        assert_eq!(Time::parse_mysql("0 07:06:05").unwrap(), time);
+        assert_eq!(Time::parse_mysql("070605").unwrap(), time);
+        assert_eq!(Time::parse_mysql("2021-03-03 07:06:05").unwrap(), time);
+        assert_eq!(
+            Time::parse_mysql("7:6:5.004321").unwrap(),
+            Time::try_from_hms(7, 6, 5, 4321).unwrap()
+        );
+        assert_eq!(
+            Time::parse_mysql("70605.5").unwrap(),
+            Time::try_from_hms(7, 6, 5, 500000).unwrap()
+        );
+        assert_eq!(
+            Time::parse_mysql("07:06").unwrap(),
+            Time::try_from_hms(7, 6, 0, 0).unwrap()
+        );
+        assert_eq!(
+            Time::parse_mysql("0 07").unwrap(),
+            Time::try_from_hms(7, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            Time::parse_mysql("0605").unwrap(),
+            Time::try_from_hms(0, 6, 5, 0).unwrap()
+        );
+        assert_eq!(
+            Time::parse_mysql("5").unwrap(),
+            Time::try_from_hms(0, 0, 5, 0).unwrap()
+        );
+
+        assert!(Time::parse_mysql("1 07:06:05").is_err());
+        assert!(Time::parse_mysql("24:00:00").is_err());
+        assert!(Time::parse_mysql("07:60:00").is_err());
+        assert!(Time::parse_mysql("0760").is_err());
+        assert!(Time::parse_mysql("1234567").is_err());
+        assert!(Time::parse_mysql("07-06-05").is_err());
+        assert!(Time::parse_mysql("2021-03-03").is_err());
+        assert!(Time::parse_mysql("abc").is_err());
+    }
+
+    #[test]
+    fn test_auto_literal() {
+        use DateOrder::{DayMonthYear as Dmy, MonthDayYear as Mdy};
+
+        let date = Date::try_from_ymd(2021, 10, 1).unwrap();
+        let cases = [
+            ("2021-10-01", Dmy, LiteralForm::Iso8601),
+            (" DATE '2021-10-01' ", Dmy, LiteralForm::Sql),
+            ("date'2021-10-01'", Dmy, LiteralForm::Sql),
+            ("01-OCT-2021", Dmy, LiteralForm::DayMonthName),
+            ("01-OCT-21", Dmy, LiteralForm::DayMonthName),
+            ("1-october-2021", Dmy, LiteralForm::DayMonthName),
+            ("01/10/2021", Dmy, LiteralForm::DayMonthYear),
+            ("1/10/2021", Dmy, LiteralForm::DayMonthYear),
+            ("10/01/2021", Mdy, LiteralForm::MonthDayYear),
+            ("20211001", Mdy, LiteralForm::Compact),
+        ];
+        for (input, order, form) in cases.iter() {
+            assert_eq!(Date::parse_auto(input, *order).unwrap(), (date, *form));
+        }
+        // The order is obvious if the day is greater than 12
+        let date = Date::try_from_ymd(2021, 10, 13).unwrap();
+        assert_eq!(
+            Date::parse_auto("13/10/2021", Mdy).unwrap(),
+            (date, LiteralForm::DayMonthYear)
+        );
+        assert_eq!(
+            Date::parse_auto("10/13/2021", Dmy).unwrap(),
+            (date, LiteralForm::MonthDayYear)
+        );
+
+        assert_eq!(
+            Date::parse_auto("2021-10-01 12:00:00", Dmy),
+            Err(Error::InvalidLiteral("date"))
+        );
+        assert_eq!(
+            Date::parse_auto("12:00:00", Dmy),
+            Err(Error::InvalidLiteral("date"))
+        );
+        assert_eq!(
+            Date::parse_auto("DATE '01/10/2021'", Dmy),
+            Err(Error::InvalidLiteral("date"))
+        );
+        assert_eq!(
+            Date::parse_auto("TIME '2021-10-01'", Dmy),
+            Err(Error::InvalidLiteral("date"))
+        );
+        assert_eq!(Date::parse_auto("2021-02-29", Dmy), Err(Error::InvalidDate));
+        assert_eq!(
+            Date::parse_auto("13/13/2021", Dmy),
+            Err(Error::InvalidMonth)
+        );
+        assert!(Date::parse_auto("", Dmy).is_err());
+        assert!(Date::parse_auto("2021-1-01", Dmy).is_err());
+        assert!(Date::parse_auto("01-XYZ-2021", Dmy).is_err());
+        assert!(Date::parse_auto("01/10/21", Dmy).is_err());
+        assert!(Date::parse_auto("01-OCT-021", Dmy).is_err());
+        assert!(Date::parse_auto("2021-10-01Z", Dmy).is_err());
+        assert!(Date::parse_auto("202110011", Dmy).is_err());
+
+        let ts = generate_ts(2021, 10, 1, 13, 30, 5);
+        let cases = [
+            ("2021-10-01T13:30:05", Dmy, LiteralForm::Iso8601),
+            ("2021-10-01 13:30:05", Dmy, LiteralForm::Sql),
+            ("2021-10-01   13:30:05", Dmy, LiteralForm::Sql),
+            ("TIMESTAMP '2021-10-01 13:30:05'", Dmy, LiteralForm::Sql),
+            ("01-Oct-2021 13:30:05", Dmy, LiteralForm::DayMonthName),
+            ("01-OCT-2021 01:30:05 PM", Dmy, LiteralForm::DayMonthName),
+            ("01/10/2021 13:30:05", Dmy, LiteralForm::DayMonthYear),
+            ("10/01/2021 1:30:05 p.m.", Mdy, LiteralForm::MonthDayYear),
+            ("20211001133005", Dmy, LiteralForm::Compact),
+        ];
+        for (input, order, form) in cases.iter() {
+            assert_eq!(Timestamp::parse_auto(input, *order).unwrap(), (ts, *form));
+        }
+        assert_eq!(
+            Timestamp::parse_auto("2021-10-01T13:30", Dmy).unwrap(),
+            (generate_ts(2021, 10, 1, 13, 30, 0), LiteralForm::Iso8601)
+        );
+        // A UTC offset is normalized to UTC
+        assert_eq!(
+            Timestamp::parse_auto("2021-10-01T13:30:05Z", Dmy).unwrap(),
+            (ts, LiteralForm::Iso8601)
+        );
+        assert_eq!(
+            Timestamp::parse_auto("2021-10-01T15:30:05+02:00", Dmy).unwrap(),
+            (ts, LiteralForm::Iso8601)
+        );
+        assert_eq!(
+            Timestamp::parse_auto("2021-10-01 08:00:05-05:30", Dmy).unwrap(),
+            (ts, LiteralForm::Sql)
+        );
+        assert_eq!(
+            Timestamp::parse_auto("TIMESTAMP '2021-10-01 13:30:05+00'", Dmy).unwrap(),
+            (ts, LiteralForm::Sql)
+        );
+        assert_eq!(
+            Timestamp::parse_auto("01-OCT-21 01:30:05 PM", Dmy).unwrap(),
+            (ts, LiteralForm::DayMonthName)
+        );
+        assert_eq!(
+            Timestamp::parse_auto("2021-10-01T13:30:05+24:00", Dmy),
+            Err(Error::TimeOutOfRange)
+        );
+        assert_eq!(
+            Timestamp::parse_auto("10/01/2021 12:00 AM", Mdy).unwrap(),
+            (generate_ts(2021, 10, 1, 0, 0, 0), LiteralForm::MonthDayYear)
+        );
+        assert_eq!(
+            Timestamp::parse_auto("TIMESTAMP '2021-10-01'", Mdy).unwrap(),
+            (generate_ts(2021, 10, 1, 0, 0, 0), LiteralForm::Sql)
+        );
+        assert_eq!(
+            Timestamp::parse_auto("20211001133005.1234567", Dmy).unwrap(),
+            (
+                Timestamp::parse("2021-10-01 13:30:05.123456", "YYYY-MM-DD HH24:MI:SS.FF").unwrap(),
+                LiteralForm::Compact
+            )
+        );
+
+        assert_eq!(
+            Timestamp::parse_auto("01/10/2021 13:30 PM", Dmy),
+            Err(Error::InvalidHour12)
+        );
+        assert_eq!(
+            Timestamp::parse_auto("2021-10-01 24:00:00", Dmy),
+            Err(Error::TimeOutOfRange)
+        );
+        assert_eq!(
+            Timestamp::parse_auto("13:30:05", Dmy),
+            Err(Error::InvalidLiteral("timestamp"))
+        );
+        assert!(Timestamp::parse_auto("2021-10-01 13:30:05 PM", Dmy).is_err());
+        assert!(Timestamp::parse_auto("2021-10-01X13:30:05", Dmy).is_err());
+        assert!(Timestamp::parse_auto("01/10/202113:30:05", Dmy).is_err());
+        assert!(Timestamp::parse_auto("2021-10-01T1:30:5", Dmy).is_err());
+        assert!(Timestamp::parse_auto("20211001 133005", Dmy).is_err());
+        assert!(Timestamp::parse_auto("2021-10-01T13:30:05 Z", Dmy).is_err());
+        assert!(Timestamp::parse_auto("01/10/2021 13:30:05Z", Dmy).is_err());
+
+        let time = Time::try_from_hms(13, 30, 5, 500000).unwrap();
+        assert_eq!(
+            Time::parse_auto("13:30:05.5").unwrap(),
+            (time, LiteralForm::Iso8601)
+        );
+        assert_eq!(
+            Time::parse_auto("T13:30:05.5").unwrap(),
+            (time, LiteralForm::Iso8601)
+        );
+        assert_eq!(
+            Time::parse_auto("TIME '13:30:05.5'").unwrap(),
+            (time, LiteralForm::Sql)
+        );
+        assert_eq!(
+            Time::parse_auto("133005.5").unwrap(),
+            (time, LiteralForm::Compact)
+        );
+        assert_eq!(
+            Time::parse_auto("2021-10-01 13:30:05"),
+            Err(Error::InvalidLiteral("time"))
+        );
+        assert_eq!(
+            Time::parse_auto("T01:30:05.5+12:00").unwrap(),
+            (time, LiteralForm::Iso8601)
+        );
+        assert_eq!(Time::parse_auto("13:60"), Err(Error::InvalidMinute));
+        assert!(Time::parse_auto("1:30 PM").is_err());
+    }
+}
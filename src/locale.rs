@@ -0,0 +1,587 @@
+//! Locale and territory implementation, which provide the month and day names of a language
+//! and the date and time formats of a territory.
+
+use crate::date::{Month, WeekDay};
+use std::sync::RwLock;
+
+static REGISTRY: RwLock<Vec<&'static Locale>> = RwLock::new(Vec::new());
+
+const BUILTIN_TERRITORIES: [&Territory; 6] = [
+    &Territory::AMERICA,
+    &Territory::UNITED_KINGDOM,
+    &Territory::GERMANY,
+    &Territory::FRANCE,
+    &Territory::SPAIN,
+    &Territory::CHINA,
+];
+
+const BUILTIN_LOCALES: [&Locale; 5] = [
+    &Locale::ENGLISH,
+    &Locale::GERMAN,
+    &Locale::FRENCH,
+    &Locale::SPANISH,
+    &Locale::SIMPLIFIED_CHINESE,
+];
+
+/// Month names, day names and meridian indicators of a language, like `NLS_DATE_LANGUAGE` in Oracle.
+///
+/// Names are given in their capitalized form, e.g. 'January' and 'Jan', and are converted to
+/// upper or lower case according to the format model, e.g. 'MONTH' and 'month'.
+#[derive(Debug)]
+pub struct Locale {
+    name: &'static str,
+    month_names: [&'static str; 12],
+    month_abbrs: [&'static str; 12],
+    day_names: [&'static str; 7],
+    day_abbrs: [&'static str; 7],
+    am_pm: [&'static str; 2],
+    am_pm_dot: [&'static str; 2],
+    month_name_max_len: usize,
+    day_name_max_len: usize,
+}
+
+impl Locale {
+    /// English, the default locale.
+    pub const ENGLISH: Locale = Locale::new(
+        "ENGLISH",
+        [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ],
+        [
+            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+        ],
+        [
+            "Sunday",
+            "Monday",
+            "Tuesday",
+            "Wednesday",
+            "Thursday",
+            "Friday",
+            "Saturday",
+        ],
+        ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"],
+        ["AM", "PM"],
+        ["A.M.", "P.M."],
+    );
+
+    /// German
+    pub const GERMAN: Locale = Locale::new(
+        "GERMAN",
+        [
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+        ],
+        [
+            "Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez",
+        ],
+        [
+            "Sonntag",
+            "Montag",
+            "Dienstag",
+            "Mittwoch",
+            "Donnerstag",
+            "Freitag",
+            "Samstag",
+        ],
+        ["So", "Mo", "Di", "Mi", "Do", "Fr", "Sa"],
+        ["AM", "PM"],
+        ["A.M.", "P.M."],
+    );
+
+    /// French
+    pub const FRENCH: Locale = Locale::new(
+        "FRENCH",
+        [
+            "Janvier",
+            "Février",
+            "Mars",
+            "Avril",
+            "Mai",
+            "Juin",
+            "Juillet",
+            "Août",
+            "Septembre",
+            "Octobre",
+            "Novembre",
+            "Décembre",
+        ],
+        [
+            "Janv.", "Févr.", "Mars", "Avr.", "Mai", "Juin", "Juil.", "Août", "Sept.", "Oct.",
+            "Nov.", "Déc.",
+        ],
+        [
+            "Dimanche", "Lundi", "Mardi", "Mercredi", "Jeudi", "Vendredi", "Samedi",
+        ],
+        ["Dim.", "Lun.", "Mar.", "Mer.", "Jeu.", "Ven.", "Sam."],
+        ["AM", "PM"],
+        ["A.M.", "P.M."],
+    );
+
+    /// Spanish
+    pub const SPANISH: Locale = Locale::new(
+        "SPANISH",
+        [
+            "Enero",
+            "Febrero",
+            "Marzo",
+            "Abril",
+            "Mayo",
+            "Junio",
+            "Julio",
+            "Agosto",
+            "Septiembre",
+            "Octubre",
+            "Noviembre",
+            "Diciembre",
+        ],
+        [
+            "Ene", "Feb", "Mar", "Abr", "May", "Jun", "Jul", "Ago", "Sep", "Oct", "Nov", "Dic",
+        ],
+        [
+            "Domingo",
+            "Lunes",
+            "Martes",
+            "Miércoles",
+            "Jueves",
+            "Viernes",
+            "Sábado",
+        ],
+        ["Dom", "Lun", "Mar", "Mié", "Jue", "Vie", "Sáb"],
+        ["AM", "PM"],
+        ["A.M.", "P.M."],
+    );
+
+    /// Simplified Chinese
+    pub const SIMPLIFIED_CHINESE: Locale = Locale::new(
+        "SIMPLIFIED CHINESE",
+        [
+            "一月",
+            "二月",
+            "三月",
+            "四月",
+            "五月",
+            "六月",
+            "七月",
+            "八月",
+            "九月",
+            "十月",
+            "十一月",
+            "十二月",
+        ],
+        [
+            "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+        ],
+        [
+            "星期日",
+            "星期一",
+            "星期二",
+            "星期三",
+            "星期四",
+            "星期五",
+            "星期六",
+        ],
+        ["日", "一", "二", "三", "四", "五", "六"],
+        ["上午", "下午"],
+        ["上午", "下午"],
+    );
+
+    /// Creates a new `Locale`, names of days start from Sunday.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use sqldatetime::{Date, Formatter, Locale};
+    ///
+    /// static DUTCH: Locale = Locale::new(
+    ///     "DUTCH",
+    ///     [
+    ///         "Januari", "Februari", "Maart", "April", "Mei", "Juni", "Juli", "Augustus",
+    ///         "September", "Oktober", "November", "December",
+    ///     ],
+    ///     ["Jan", "Feb", "Mrt", "Apr", "Mei", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dec"],
+    ///     ["Zondag", "Maandag", "Dinsdag", "Woensdag", "Donderdag", "Vrijdag", "Zaterdag"],
+    ///     ["Zo", "Ma", "Di", "Wo", "Do", "Vr", "Za"],
+    ///     ["AM", "PM"],
+    ///     ["A.M.", "P.M."],
+    /// );
+    ///
+    /// let fmt = Formatter::try_new("FMDay DD Month YYYY").unwrap().with_locale(&DUTCH);
+    /// let date = Date::try_from_ymd(2021, 3, 3).unwrap();
+    /// let mut s = String::new();
+    /// fmt.format(date, &mut s).unwrap();
+    /// assert_eq!(s, "Woensdag 3 Maart 2021");
+    /// ```
+    #[inline]
+    pub const fn new(
+        name: &'static str,
+        month_names: [&'static str; 12],
+        month_abbrs: [&'static str; 12],
+        day_names: [&'static str; 7],
+        day_abbrs: [&'static str; 7],
+        am_pm: [&'static str; 2],
+        am_pm_dot: [&'static str; 2],
+    ) -> Locale {
+        Locale {
+            name,
+            month_names,
+            month_abbrs,
+            day_names,
+            day_abbrs,
+            am_pm,
+            am_pm_dot,
+            month_name_max_len: max_char_count(&month_names),
+            day_name_max_len: max_char_count(&day_names),
+        }
+    }
+
+    /// Gets the name of the locale, e.g. 'ENGLISH'.
+    #[inline]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Registers a locale, which can be found by its name afterwards.
+    /// A registered locale replaces the built-in or registered one of the same name.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use sqldatetime::Locale;
+    ///
+    /// static SWEDISH: Locale = Locale::new(
+    ///     "SWEDISH",
+    ///     [
+    ///         "Januari", "Februari", "Mars", "April", "Maj", "Juni", "Juli", "Augusti",
+    ///         "September", "Oktober", "November", "December",
+    ///     ],
+    ///     ["Jan", "Feb", "Mar", "Apr", "Maj", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dec"],
+    ///     ["Söndag", "Måndag", "Tisdag", "Onsdag", "Torsdag", "Fredag", "Lördag"],
+    ///     ["Sö", "Må", "Ti", "On", "To", "Fr", "Lö"],
+    ///     ["FM", "EM"],
+    ///     ["F.M.", "E.M."],
+    /// );
+    ///
+    /// Locale::register(&SWEDISH);
+    /// assert_eq!(Locale::find("swedish").unwrap().name(), "SWEDISH");
+    /// ```
+    #[inline]
+    pub fn register(locale: &'static Locale) {
+        let mut registry = REGISTRY.write().unwrap_or_else(|e| e.into_inner());
+        registry.retain(|l| !l.name.eq_ignore_ascii_case(locale.name));
+        registry.push(locale);
+    }
+
+    /// Finds a registered or built-in locale by name ignoring case, e.g. 'GERMAN' or 'simplified chinese'.
+    #[inline]
+    pub fn find(name: &str) -> Option<&'static Locale> {
+        let registry = REGISTRY.read().unwrap_or_else(|e| e.into_inner());
+        registry
+            .iter()
+            .chain(BUILTIN_LOCALES.iter())
+            .find(|l| l.name.eq_ignore_ascii_case(name))
+            .copied()
+    }
+
+    #[inline]
+    pub(crate) const fn month_name(&self, month: Month, abbr: bool) -> &'static str {
+        if abbr {
+            self.month_abbrs[month as usize - 1]
+        } else {
+            self.month_names[month as usize - 1]
+        }
+    }
+
+    #[inline]
+    pub(crate) const fn day_name(&self, day: WeekDay, abbr: bool) -> &'static str {
+        if abbr {
+            self.day_abbrs[day as usize - 1]
+        } else {
+            self.day_names[day as usize - 1]
+        }
+    }
+
+    /// Gets the meridian indicator of the hour (0..23).
+    #[inline]
+    pub(crate) const fn am_pm(&self, hour: u32, dot: bool) -> &'static str {
+        let names = if dot { &self.am_pm_dot } else { &self.am_pm };
+        if hour < 12 {
+            names[0]
+        } else {
+            names[1]
+        }
+    }
+
+    #[inline]
+    pub(crate) const fn am_pm_names(&self, dot: bool) -> &[&'static str; 2] {
+        if dot {
+            &self.am_pm_dot
+        } else {
+            &self.am_pm
+        }
+    }
+
+    #[inline]
+    pub(crate) const fn month_names(&self, abbr: bool) -> &[&'static str; 12] {
+        if abbr {
+            &self.month_abbrs
+        } else {
+            &self.month_names
+        }
+    }
+
+    #[inline]
+    pub(crate) const fn day_names(&self, abbr: bool) -> &[&'static str; 7] {
+        if abbr {
+            &self.day_abbrs
+        } else {
+            &self.day_names
+        }
+    }
+
+    /// The length of the longest month name, which full month names are padded to
+    #[inline]
+    pub(crate) const fn month_name_max_len(&self) -> usize {
+        self.month_name_max_len
+    }
+
+    /// The length of the longest day name, which full day names are padded to
+    #[inline]
+    pub(crate) const fn day_name_max_len(&self) -> usize {
+        self.day_name_max_len
+    }
+}
+
+impl Default for Locale {
+    #[inline]
+    fn default() -> Self {
+        Locale::ENGLISH
+    }
+}
+
+/// Date and time formats of a territory, like `NLS_TERRITORY` in Oracle,
+/// which 'DL', 'DS' and 'TS' in format models are expanded to.
+#[derive(Debug)]
+pub struct Territory {
+    name: &'static str,
+    long_date: &'static str,
+    short_date: &'static str,
+    short_time: &'static str,
+}
+
+impl Territory {
+    /// America, the default territory.
+    pub const AMERICA: Territory = Territory::new(
+        "AMERICA",
+        "fmDay, Month dd, yyyy",
+        "MM/DD/RRRR",
+        "HH:MI:SS AM",
+    );
+
+    /// United Kingdom
+    pub const UNITED_KINGDOM: Territory = Territory::new(
+        "UNITED KINGDOM",
+        "fmDay dd Month yyyy",
+        "DD/MM/RRRR",
+        "HH24:MI:SS",
+    );
+
+    /// Germany
+    pub const GERMANY: Territory =
+        Territory::new("GERMANY", "fmDay, dd. Month yyyy", "DD.MM.RR", "HH24:MI:SS");
+
+    /// France
+    pub const FRANCE: Territory =
+        Territory::new("FRANCE", "fmDay dd Month yyyy", "DD/MM/RR", "HH24:MI:SS");
+
+    /// Spain
+    pub const SPAIN: Territory = Territory::new(
+        "SPAIN",
+        "fmDay, dd \"de\" Month \"de\" yyyy",
+        "DD/MM/RR",
+        "HH24:MI:SS",
+    );
+
+    /// China
+    pub const CHINA: Territory = Territory::new(
+        "CHINA",
+        "fmyyyy\"年\"mm\"月\"dd\"日\" Day",
+        "YYYY-MM-DD",
+        "HH24:MI:SS",
+    );
+
+    /// Creates a new `Territory` with the format models of
+    /// the long date ('DL'), the short date ('DS') and the short time ('TS').
+    /// The models must not contain 'FX', 'DL', 'DS' or 'TS'.
+    #[inline]
+    pub const fn new(
+        name: &'static str,
+        long_date: &'static str,
+        short_date: &'static str,
+        short_time: &'static str,
+    ) -> Territory {
+        Territory {
+            name,
+            long_date,
+            short_date,
+            short_time,
+        }
+    }
+
+    /// Gets the name of the territory, e.g. 'AMERICA'.
+    #[inline]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Finds a built-in territory by name ignoring case, e.g. 'GERMANY' or 'united kingdom'.
+    #[inline]
+    pub fn find(name: &str) -> Option<&'static Territory> {
+        BUILTIN_TERRITORIES
+            .iter()
+            .find(|t| t.name.eq_ignore_ascii_case(name))
+            .copied()
+    }
+
+    #[inline]
+    pub(crate) const fn long_date(&self) -> &'static str {
+        self.long_date
+    }
+
+    #[inline]
+    pub(crate) const fn short_date(&self) -> &'static str {
+        self.short_date
+    }
+
+    #[inline]
+    pub(crate) const fn short_time(&self) -> &'static str {
+        self.short_time
+    }
+}
+
+impl Default for Territory {
+    #[inline]
+    fn default() -> Self {
+        Territory::AMERICA
+    }
+}
+
+/// Gets the number of characters of the longest name.
+#[inline]
+const fn max_char_count(names: &[&str]) -> usize {
+    let mut max = 0;
+    let mut i = 0;
+    while i < names.len() {
+        let bytes = names[i].as_bytes();
+        let mut count = 0;
+        let mut j = 0;
+        while j < bytes.len() {
+            // Skips UTF-8 continuation bytes
+            if bytes[j] & 0xC0 != 0x80 {
+                count += 1;
+            }
+            j += 1;
+        }
+        if count > max {
+            max = count;
+        }
+        i += 1;
+    }
+    max
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locale() {
+        assert_eq!(Locale::ENGLISH.month_name_max_len(), 9);
+        assert_eq!(Locale::ENGLISH.day_name_max_len(), 9);
+        assert_eq!(Locale::GERMAN.month_name_max_len(), 9);
+        assert_eq!(Locale::GERMAN.day_name_max_len(), 10);
+        assert_eq!(Locale::SPANISH.day_name_max_len(), 9);
+        assert_eq!(Locale::SIMPLIFIED_CHINESE.month_name_max_len(), 3);
+
+        assert_eq!(Locale::ENGLISH.month_name(Month::May, false), "May");
+        assert_eq!(Locale::FRENCH.month_name(Month::February, true), "Févr.");
+        assert_eq!(Locale::GERMAN.day_name(WeekDay::Sunday, false), "Sonntag");
+        assert_eq!(Locale::SPANISH.day_name(WeekDay::Saturday, true), "Sáb");
+        assert_eq!(Locale::SIMPLIFIED_CHINESE.am_pm(13, false), "下午");
+        assert_eq!(Locale::ENGLISH.am_pm(0, true), "A.M.");
+
+        assert_eq!(Locale::find("german").unwrap().name(), "GERMAN");
+        assert_eq!(
+            Locale::find("Simplified Chinese").unwrap().name(),
+            "SIMPLIFIED CHINESE"
+        );
+        assert!(Locale::find("ITALIAN").is_none());
+
+        static ITALIAN: Locale = Locale::new(
+            "ITALIAN",
+            [
+                "Gennaio",
+                "Febbraio",
+                "Marzo",
+                "Aprile",
+                "Maggio",
+                "Giugno",
+                "Luglio",
+                "Agosto",
+                "Settembre",
+                "Ottobre",
+                "Novembre",
+                "Dicembre",
+            ],
+            [
+                "Gen", "Feb", "Mar", "Apr", "Mag", "Giu", "Lug", "Ago", "Set", "Ott", "Nov", "Dic",
+            ],
+            [
+                "Domenica",
+                "Lunedì",
+                "Martedì",
+                "Mercoledì",
+                "Giovedì",
+                "Venerdì",
+                "Sabato",
+            ],
+            ["Dom", "Lun", "Mar", "Mer", "Gio", "Ven", "Sab"],
+            ["AM", "PM"],
+            ["A.M.", "P.M."],
+        );
+        Locale::register(&ITALIAN);
+        assert!(std::ptr::eq(Locale::find("italian").unwrap(), &ITALIAN));
+        assert_eq!(ITALIAN.day_name_max_len(), 9);
+    }
+
+    #[test]
+    fn test_territory() {
+        assert_eq!(Territory::default().name(), "AMERICA");
+        assert_eq!(
+            Territory::find("United Kingdom").unwrap().name(),
+            "UNITED KINGDOM"
+        );
+        assert_eq!(Territory::find("china").unwrap().short_date(), "YYYY-MM-DD");
+        assert!(Territory::find("ATLANTIS").is_none());
+    }
+}
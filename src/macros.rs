@@ -0,0 +1,227 @@
+//! Macros for format models and date/time literals checked at compile time.
+
+/// Creates a [`Formatter`](crate::Formatter) from an Oracle format model,
+/// which is checked at compile time, so a bad model fails the build rather than `unwrap()`.
+///
+/// The model must be a constant string, 'DL', 'DS' and 'TS' are expanded to the formats
+/// of the American territory as `Formatter::try_new` does.
+///
+/// ## Limits
+///
+/// - Only the Oracle dialect is checked, models of other dialects and territories
+///   must be built with `Formatter::try_new_with` and friends.
+/// - The check scans the model with the same const scanner `Formatter::try_new` tokenizes
+///   Oracle models with, so a model accepted by it is accepted by `Formatter::try_new`.
+/// - The `Formatter` itself is still built at run time each time the macro is evaluated,
+///   a formatter used over and over is better kept in a static, e.g. a `once_cell::sync::Lazy`.
+///
+/// ## Example
+///
+/// ```
+/// use sqldatetime::{date, formatter, Date};
+///
+/// let fmt = formatter!("YYYY-MM-DD");
+/// assert_eq!(fmt.parse::<_, Date>("2021-10-01").unwrap(), date!(2021-10-01));
+/// ```
+///
+/// ```compile_fail
+/// let fmt = sqldatetime::formatter!("YYYY-MM-DD HH25");
+/// ```
+#[macro_export]
+macro_rules! formatter {
+    ($fmt:expr) => {{
+        const _: () = $crate::__private::assert_format_model($fmt);
+        $crate::Formatter::try_new($fmt).expect("the format model is checked at compile time")
+    }};
+}
+
+/// Creates a constant [`Date`](crate::Date) from a literal such as `2021-10-01`,
+/// the year -1 is 1 BC.
+///
+/// ## Example
+///
+/// ```
+/// use sqldatetime::{date, Date};
+///
+/// const DATE: Date = date!(2021-10-01);
+/// assert_eq!(DATE, Date::try_from_ymd(2021, 10, 1).unwrap());
+/// assert_eq!(date!(-4712-01-01), Date::MIN);
+/// ```
+///
+/// ```compile_fail
+/// let date = sqldatetime::date!(2021-02-29);
+/// ```
+#[macro_export]
+macro_rules! date {
+    ($($literal:tt)+) => {{
+        const DATE: $crate::Date = {
+            let (year, month, day) = $crate::__private::date_literal(stringify!($($literal)+));
+            unsafe { $crate::Date::from_ymd_unchecked(year, month, day) }
+        };
+        DATE
+    }};
+}
+
+/// Creates a constant [`Time`](crate::Time) from a literal such as `12:00:00.5`.
+///
+/// ## Example
+///
+/// ```
+/// use sqldatetime::{time, Time};
+///
+/// const TIME: Time = time!(12:00:00.5);
+/// assert_eq!(TIME, Time::try_from_hms(12, 0, 0, 500000).unwrap());
+/// ```
+#[macro_export]
+macro_rules! time {
+    ($($literal:tt)+) => {{
+        const TIME: $crate::Time = {
+            let (hour, minute, sec, usec) =
+                $crate::__private::time_literal(stringify!($($literal)+));
+            unsafe { $crate::Time::from_hms_unchecked(hour, minute, sec, usec) }
+        };
+        TIME
+    }};
+}
+
+/// Creates a constant [`Timestamp`](crate::Timestamp) from a literal such as
+/// `2021-10-01 12:00:00.5`.
+///
+/// ## Example
+///
+/// ```
+/// use sqldatetime::{timestamp, Date, Time, Timestamp};
+///
+/// const TIMESTAMP: Timestamp = timestamp!(2021-10-01 12:00:00.5);
+/// let time = Time::try_from_hms(12, 0, 0, 500000).unwrap();
+/// assert_eq!(TIMESTAMP, Date::try_from_ymd(2021, 10, 1).unwrap().and_time(time));
+/// ```
+#[macro_export]
+macro_rules! timestamp {
+    ($($literal:tt)+) => {{
+        const TIMESTAMP: $crate::Timestamp = {
+            let ((year, month, day), (hour, minute, sec, usec)) =
+                $crate::__private::timestamp_literal(stringify!($($literal)+));
+            unsafe {
+                $crate::Date::from_ymd_unchecked(year, month, day)
+                    .and_time($crate::Time::from_hms_unchecked(hour, minute, sec, usec))
+            }
+        };
+        TIMESTAMP
+    }};
+}
+
+/// Creates a constant [`IntervalYM`](crate::IntervalYM) from a literal such as `1-2`,
+/// which may be negative.
+///
+/// ## Example
+///
+/// ```
+/// use sqldatetime::{interval_ym, IntervalYM};
+///
+/// const INTERVAL: IntervalYM = interval_ym!(-1-2);
+/// assert_eq!(INTERVAL, IntervalYM::try_from_months(-14).unwrap());
+/// ```
+#[macro_export]
+macro_rules! interval_ym {
+    ($($literal:tt)+) => {{
+        const INTERVAL: $crate::IntervalYM = {
+            let months = $crate::__private::interval_ym_literal(stringify!($($literal)+));
+            unsafe { $crate::IntervalYM::from_months_unchecked(months) }
+        };
+        INTERVAL
+    }};
+}
+
+/// Creates a constant [`IntervalDT`](crate::IntervalDT) from a literal such as `1 02:03:04`,
+/// which may be negative.
+///
+/// ## Example
+///
+/// ```
+/// use sqldatetime::{interval_dt, IntervalDT};
+///
+/// const INTERVAL: IntervalDT = interval_dt!(1 02:03:04);
+/// assert_eq!(INTERVAL, IntervalDT::try_from_dhms(1, 2, 3, 4, 0).unwrap());
+/// ```
+#[macro_export]
+macro_rules! interval_dt {
+    ($($literal:tt)+) => {{
+        const INTERVAL: $crate::IntervalDT = {
+            let usecs = $crate::__private::interval_dt_literal(stringify!($($literal)+));
+            unsafe { $crate::IntervalDT::from_usecs_unchecked(usecs) }
+        };
+        INTERVAL
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Date, Formatter, IntervalDT, IntervalYM, Time, Timestamp};
+
+    #[test]
+    fn test_formatter_macro() {
+        let fmt = formatter!("DD-MON-YYYY HH24:MI:SS.FF6");
+        let expected = Formatter::try_new("DD-MON-YYYY HH24:MI:SS.FF6").unwrap();
+        let ts = timestamp!(2021-10-01 12:00:00.5);
+        let mut s = String::new();
+        let mut expected_s = String::new();
+        fmt.format(ts, &mut s).unwrap();
+        expected.format(ts, &mut expected_s).unwrap();
+        assert_eq!(s, expected_s);
+        assert_eq!(s, "01-OCT-2021 12:00:00.500000");
+
+        const FMT: &str = "DL";
+        let fmt = formatter!(FMT);
+        let mut s = String::new();
+        fmt.format(date!(2021 - 10 - 01), &mut s).unwrap();
+        assert_eq!(s, "Friday, October 1, 2021");
+    }
+
+    #[test]
+    fn test_date_time_literal_macros() {
+        assert_eq!(
+            date!(2021 - 10 - 01),
+            Date::try_from_ymd(2021, 10, 1).unwrap()
+        );
+        assert_eq!(
+            date!(2024 - 02 - 29),
+            Date::try_from_ymd(2024, 2, 29).unwrap()
+        );
+        assert_eq!(date!(-1 - 12 - 31), Date::try_from_ymd(-1, 12, 31).unwrap());
+        assert_eq!(date!(9999 - 12 - 31), Date::MAX);
+
+        assert_eq!(time!(00:00:00), Time::ZERO);
+        assert_eq!(time!(23:59:59.999999), Time::MAX);
+        assert_eq!(
+            time!(08:09:07.05),
+            Time::try_from_hms(8, 9, 7, 50000).unwrap()
+        );
+
+        assert_eq!(
+            timestamp!(2021-10-01 12:00:00),
+            Timestamp::new(date!(2021 - 10 - 01), time!(12:00:00))
+        );
+        assert_eq!(
+            timestamp!(-4712-01-01 00:00:00.000001),
+            Timestamp::new(Date::MIN, Time::try_from_hms(0, 0, 0, 1).unwrap())
+        );
+
+        assert_eq!(interval_ym!(1 - 2), IntervalYM::try_from_ym(1, 2).unwrap());
+        assert_eq!(
+            interval_ym!(-0 - 11),
+            IntervalYM::try_from_months(-11).unwrap()
+        );
+        assert_eq!(interval_ym!(178000000 - 0), IntervalYM::MAX);
+
+        assert_eq!(
+            interval_dt!(1 02:03:04),
+            IntervalDT::try_from_dhms(1, 2, 3, 4, 0).unwrap()
+        );
+        assert_eq!(
+            interval_dt!(-0 00:00:00.5),
+            IntervalDT::try_from_usecs(-500000).unwrap()
+        );
+        assert_eq!(interval_dt!(-100000000 00:00:00), IntervalDT::MIN);
+    }
+}
@@ -16,7 +16,7 @@ use std::fmt::Display;
 pub struct Date(Timestamp);
 
 impl Date {
-    /// The smallest date that can be represented by `Date`, i.e. `0001-01-01 00:00:00`.
+    /// The smallest date that can be represented by `Date`, i.e. `-4712-01-01 00:00:00`.
     pub const MIN: Self = Date(Timestamp::MIN);
 
     /// The largest date that can be represented by `Date`, i.e. `9999-12-31 23:59:59`.
@@ -759,18 +759,18 @@ mod tests {
                 assert_eq!(format!("{}", date.format("MON").unwrap()), "AUG");
                 assert_eq!(format!("{}", date.format("Mon").unwrap()), "Aug");
                 assert_eq!(format!("{}", date.format("mon").unwrap()), "aug");
-                assert_eq!(format!("{}", date.format("MONTH").unwrap()), "AUGUST");
-                assert_eq!(format!("{}", date.format("MONtH").unwrap()), "AUGUST");
-                assert_eq!(format!("{}", date.format("Month").unwrap()), "August");
-                assert_eq!(format!("{}", date.format("month").unwrap()), "august");
+                assert_eq!(format!("{}", date.format("MONTH").unwrap()), "AUGUST   ");
+                assert_eq!(format!("{}", date.format("MONtH").unwrap()), "AUGUST   ");
+                assert_eq!(format!("{}", date.format("Month").unwrap()), "August   ");
+                assert_eq!(format!("{}", date.format("month").unwrap()), "august   ");
                 assert_eq!(format!("{}", date.format("WW").unwrap()), "32");
                 assert_eq!(format!("{}", date.format("W").unwrap()), "1");
-                assert_eq!(format!("{}", date.format("DAY").unwrap()), "SUNDAY");
-                assert_eq!(format!("{}", date.format("DAy").unwrap()), "SUNDAY");
-                assert_eq!(format!("{}", date.format("Day").unwrap()), "Sunday");
-                assert_eq!(format!("{}", date.format("DaY").unwrap()), "Sunday");
-                assert_eq!(format!("{}", date.format("day").unwrap()), "sunday");
-                assert_eq!(format!("{}", date.format("daY").unwrap()), "sunday");
+                assert_eq!(format!("{}", date.format("DAY").unwrap()), "SUNDAY   ");
+                assert_eq!(format!("{}", date.format("DAy").unwrap()), "SUNDAY   ");
+                assert_eq!(format!("{}", date.format("Day").unwrap()), "Sunday   ");
+                assert_eq!(format!("{}", date.format("DaY").unwrap()), "Sunday   ");
+                assert_eq!(format!("{}", date.format("day").unwrap()), "sunday   ");
+                assert_eq!(format!("{}", date.format("daY").unwrap()), "sunday   ");
                 assert_eq!(format!("{}", date.format("DY").unwrap()), "SUN");
                 assert_eq!(format!("{}", date.format("Dy").unwrap()), "Sun");
                 assert_eq!(format!("{}", date.format("dy").unwrap()), "sun");
@@ -781,14 +781,14 @@ mod tests {
                 assert_eq!(format!("{}", date.format("ss").unwrap()), "09");
 
                 let date = generate_date(1970, 1, 1, 7, 8, 9);
-                assert_eq!(format!("{}", date.format("day").unwrap()), "thursday");
+                assert_eq!(format!("{}", date.format("day").unwrap()), "thursday ");
                 assert_eq!(format!("{}", date.format("D").unwrap()), "5");
                 assert_eq!(format!("{}", date.format("DDD").unwrap()), "001");
                 assert_eq!(format!("{}", date.format("WW").unwrap()), "01");
                 assert_eq!(format!("{}", date.format("W").unwrap()), "1");
 
                 let date = generate_date(1970, 1, 2, 7, 8, 9);
-                assert_eq!(format!("{}", date.format("day").unwrap()), "friday");
+                assert_eq!(format!("{}", date.format("day").unwrap()), "friday   ");
 
                 let date = generate_date(1969, 12, 31, 7, 8, 9);
                 assert_eq!(format!("{}", date.format("day").unwrap()), "wednesday");
@@ -801,20 +801,20 @@ mod tests {
                 assert_eq!(format!("{}", date.format("day").unwrap()), "wednesday");
 
                 let date = generate_date(9999, 11, 14, 7, 8, 9);
-                assert_eq!(format!("{}", date.format("day").unwrap()), "sunday");
+                assert_eq!(format!("{}", date.format("day").unwrap()), "sunday   ");
             }
 
             // Normal
             {
                 let date = generate_date(2000, 1, 1, 0, 0, 0);
                 let fmt = format!("{}", date.format("yyyy-MONTH-dd hh:mi:ss").unwrap());
-                assert_eq!(fmt, "2000-JANUARY-01 12:00:00");
+                assert_eq!(fmt, "2000-JANUARY  -01 12:00:00");
 
                 let fmt = format!("{}", date.format("yyyy-Mon-dd hh:mi:ss").unwrap());
                 assert_eq!(fmt, "2000-Jan-01 12:00:00");
 
                 let fmt = format!("{}", date.format("Day yyyy-Mon-dd hh:mi:ss").unwrap());
-                assert_eq!(fmt, "Saturday 2000-Jan-01 12:00:00");
+                assert_eq!(fmt, "Saturday  2000-Jan-01 12:00:00");
 
                 let fmt = format!("{}", date.format("yyyyMMdd hh24miss").unwrap());
                 assert_eq!(fmt, "20000101 000000");
@@ -887,7 +887,7 @@ mod tests {
                         date.format("DAY DaY DY D DDD W WW WW MM MM yyyy YYYY MI MI")
                             .unwrap()
                     ),
-                    "SUNDAY Sunday SUN 1 115 4 17 17 04 04 2021 2021 04 04"
+                    "SUNDAY    Sunday    SUN 1 115 4 17 17 04 04 2021 2021 04 04"
                 );
 
                 assert_eq!(
@@ -895,7 +895,7 @@ mod tests {
                         "{}",
                         date.format("DAYDaYDYDWWWWWDMMMMyyyyYYYYMIMIDDD").unwrap()
                     ),
-                    "SUNDAYSundaySUN11717410404202120210404115"
+                    "SUNDAY   Sunday   SUN11717410404202120210404115"
                 );
             }
 
@@ -941,6 +941,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_date_julian_seconds() {
+        let date = generate_date(2021, 4, 3, 5, 6, 7);
+        assert_eq!(
+            format!("{}", date.format("J SSSSS").unwrap()),
+            "2459308 18367"
+        );
+        assert_eq!(Date::parse("2459308 18367", "J SSSSS").unwrap(), date);
+        assert_eq!(
+            Date::parse("2459308 2021-04-03 18367 05", "J YYYY-MM-DD SSSSS HH24").unwrap(),
+            date
+        );
+        assert!(Date::parse("2459308 2021-04-04", "J YYYY-MM-DD").is_err());
+        assert!(Date::parse("18367 05:07", "SSSSS HH24:MI").is_err());
+    }
+
     #[test]
     fn test_date_to_sql_date_time() {
         let date = generate_date(1, 1, 1, 0, 0, 0);
@@ -1046,9 +1062,9 @@ mod tests {
         let interval = IntervalDT::try_from_dhms(12345, 12, 3, 5, 6).unwrap();
         assert!(date.add_interval_dt(interval).is_err());
 
-        let date = generate_date(1, 1, 1, 0, 0, 0);
+        let date = generate_date(-4712, 1, 1, 0, 0, 0);
         let interval = IntervalDT::try_from_dhms(5, 4, 3, 2, 1).unwrap();
-        let expect = generate_date(1, 1, 6, 4, 3, 2);
+        let expect = generate_date(-4712, 1, 6, 4, 3, 2);
         assert_eq!(date.add_interval_dt(interval).unwrap(), expect);
 
         let interval = IntervalDT::try_from_dhms(0, 0, 0, 0, 1).unwrap();
@@ -1116,7 +1132,7 @@ mod tests {
 
         // Boundary test
         let upper_date = generate_date(9999, 12, 31, 23, 59, 59);
-        let lower_date = generate_date(1, 1, 1, 0, 0, 0);
+        let lower_date = generate_date(-4712, 1, 1, 0, 0, 0);
         let interval = IntervalYM::try_from_ym(0, 1).unwrap();
 
         assert!(upper_date.add_interval_ym(interval).is_err());
@@ -1200,7 +1216,7 @@ mod tests {
     #[test]
     fn test_date_add_sub_days() {
         let upper_ts = generate_date(9999, 12, 31, 23, 59, 59);
-        let lower_ts = generate_date(1, 1, 1, 0, 0, 0);
+        let lower_ts = generate_date(-4712, 1, 1, 0, 0, 0);
 
         // Out of range
         assert!(lower_ts.add_days(213435445784784.13).is_err());
@@ -1222,7 +1238,7 @@ mod tests {
         // Round
         assert_eq!(
             lower_ts.add_days(1.123456789).unwrap(),
-            generate_date(1, 1, 2, 2, 57, 47)
+            generate_date(-4712, 1, 2, 2, 57, 47)
         );
         assert_eq!(
             upper_ts.sub_days(1.123456789).unwrap(),
@@ -1238,7 +1254,7 @@ mod tests {
         );
         assert_eq!(
             lower_ts.add_days(1.0).unwrap(),
-            generate_date(1, 1, 2, 0, 0, 0)
+            generate_date(-4712, 1, 2, 0, 0, 0)
         );
 
         let date = generate_date(5000, 6, 15, 12, 30, 30);
@@ -1354,7 +1370,7 @@ mod tests {
     #[test]
     fn test_timestamp_add_sub_days() {
         let upper_ts = generate_ts(9999, 12, 31, 23, 59, 59, 999999);
-        let lower_ts = generate_ts(1, 1, 1, 0, 0, 0, 0);
+        let lower_ts = generate_ts(-4712, 1, 1, 0, 0, 0, 0);
         let upper_date = generate_date(9999, 12, 31, 23, 59, 59);
 
         // Out of range
@@ -1377,11 +1393,11 @@ mod tests {
         // Round
         assert_eq!(
             lower_ts.oracle_add_days(1.123456789).unwrap(),
-            generate_date(1, 1, 2, 2, 57, 47)
+            generate_date(-4712, 1, 2, 2, 57, 47)
         );
         assert_eq!(
             lower_ts.oracle_add_days(0.0000104).unwrap(),
-            generate_date(1, 1, 1, 0, 0, 1)
+            generate_date(-4712, 1, 1, 0, 0, 1)
         );
         assert_eq!(
             upper_ts.oracle_sub_days(1.123456789).unwrap(),
@@ -1397,7 +1413,7 @@ mod tests {
         );
         assert_eq!(
             lower_ts.add_days(1.0).unwrap(),
-            generate_date(1, 1, 2, 0, 0, 0)
+            generate_date(-4712, 1, 2, 0, 0, 0)
         );
 
         let ts = generate_ts(5000, 6, 15, 12, 30, 30, 555555);
@@ -1455,7 +1471,7 @@ mod tests {
 
         assert_eq!(
             Date::MIN.add_time(generate_time(1, 2, 3, 4)).unwrap(),
-            generate_ts(1, 1, 1, 1, 2, 3, 4)
+            generate_ts(-4712, 1, 1, 1, 2, 3, 4)
         );
 
         assert_eq!(
@@ -1470,7 +1486,7 @@ mod tests {
     fn test_now() {
         let now = Local::now().naive_local();
         let dt = Date::now().unwrap();
-        assert_eq!(now.year() as i32, dt.year().unwrap());
+        assert_eq!(now.year(), dt.year().unwrap());
         assert_eq!(now.month() as i32, dt.month().unwrap());
         assert_eq!(now.day() as i32, dt.day().unwrap());
         assert_eq!(now.hour() as i32, dt.hour().unwrap());
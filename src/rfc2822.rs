@@ -0,0 +1,504 @@
+//! RFC 2822 and HTTP date representations of timestamps, which are in English.
+
+use crate::common::{USECONDS_PER_HOUR, USECONDS_PER_MINUTE};
+use crate::error::{Error, Result};
+use crate::format::{parse_day_name, parse_month_name};
+use crate::literal::{count_digits, take_number};
+use crate::{Date, IntervalDT, Locale, Month, Time, Timestamp, WeekDay};
+use std::fmt::{self, Display};
+
+/// The time zone names of RFC 2822 and their offsets in hours.
+const ZONE_TABLE: [(&str, i64); 11] = [
+    ("UT", 0),
+    ("GMT", 0),
+    ("Z", 0),
+    ("EST", -5),
+    ("EDT", -4),
+    ("CST", -6),
+    ("CDT", -5),
+    ("MST", -7),
+    ("MDT", -6),
+    ("PST", -8),
+    ("PDT", -7),
+];
+
+/// Parses an RFC 2822 date, which is also RFC 822 and RFC 1123,
+/// e.g. 'Tue, 15 Nov 1994 08:12:31 -0500', and returns the local time and the offset.
+pub(crate) fn parse_rfc2822(input: &str) -> Result<(Timestamp, IntervalDT)> {
+    let mut cursor = Cursor::new(input, "RFC 2822 date");
+    let day_name = cursor.day_name()?;
+    if day_name.is_some() {
+        cursor.expect(b',')?;
+    }
+    cursor.rfc2822(day_name)
+}
+
+/// Parses an HTTP date in UTC, which is an IMF-fixdate, e.g. 'Tue, 15 Nov 1994 08:12:31 GMT',
+/// or in the obsolete RFC 850 form, e.g. 'Tuesday, 15-Nov-94 08:12:31 GMT', or the asctime form,
+/// e.g. 'Tue Nov 15 08:12:31 1994'. Any RFC 2822 date is accepted and normalized to UTC.
+pub(crate) fn parse_http_date(input: &str) -> Result<Timestamp> {
+    let mut cursor = Cursor::new(input, "HTTP date");
+    let day_name = cursor.day_name()?;
+    let (ts, offset) = match day_name {
+        Some(_) if cursor.s.first() != Some(&b',') => cursor.asctime(day_name)?,
+        Some(_) => {
+            cursor.expect(b',')?;
+            if cursor.s.get(count_digits(cursor.s)) == Some(&b'-') {
+                cursor.rfc850(day_name)?
+            } else {
+                cursor.rfc2822(day_name)?
+            }
+        }
+        None => cursor.rfc2822(None)?,
+    };
+    ts.sub_interval_dt(offset)
+}
+
+/// A cursor over a header value, where white space and comments in parentheses may appear
+/// between the tokens.
+struct Cursor<'a> {
+    s: &'a [u8],
+    literal: &'static str,
+}
+
+impl<'a> Cursor<'a> {
+    #[inline]
+    fn new(input: &'a str, literal: &'static str) -> Self {
+        let mut cursor = Cursor {
+            s: input.as_bytes(),
+            literal,
+        };
+        cursor.skip_cfws();
+        cursor
+    }
+
+    #[inline]
+    fn invalid(&self) -> Error {
+        Error::InvalidLiteral(self.literal)
+    }
+
+    /// Takes the rest of an RFC 2822 date after the optional day name:
+    /// 'day month year hour:minute[:second] zone'.
+    fn rfc2822(&mut self, day_name: Option<WeekDay>) -> Result<(Timestamp, IntervalDT)> {
+        let day = self.number(1, 2)?;
+        self.separator()?;
+        let month = self.month()?;
+        self.separator()?;
+        let year = self.year()?;
+        self.separator()?;
+        let time = self.time()?;
+        self.separator()?;
+        let offset = self.zone()?;
+        self.end()?;
+        Ok((resolve(day_name, year, month, day, time)?, offset))
+    }
+
+    /// Takes the rest of an RFC 850 date after the day name and the comma:
+    /// 'day-month-year hour:minute:second zone'.
+    fn rfc850(&mut self, day_name: Option<WeekDay>) -> Result<(Timestamp, IntervalDT)> {
+        let day = self.number(1, 2)?;
+        self.expect(b'-')?;
+        let month = self.month()?;
+        self.expect(b'-')?;
+        let year = self.year()?;
+        self.separator()?;
+        let time = self.time()?;
+        self.separator()?;
+        let offset = self.zone()?;
+        self.end()?;
+        Ok((resolve(day_name, year, month, day, time)?, offset))
+    }
+
+    /// Takes the rest of an asctime date after the day name: 'month day hour:minute:second year',
+    /// which is in UTC.
+    fn asctime(&mut self, day_name: Option<WeekDay>) -> Result<(Timestamp, IntervalDT)> {
+        self.separator()?;
+        let month = self.month()?;
+        self.separator()?;
+        let day = self.number(1, 2)?;
+        self.separator()?;
+        let time = self.time()?;
+        self.separator()?;
+        let year = self.number(4, 4)? as i32;
+        self.end()?;
+        Ok((resolve(day_name, year, month, day, time)?, IntervalDT::ZERO))
+    }
+
+    /// Skips white space and comments, which may nest, and returns whether anything is skipped.
+    fn skip_cfws(&mut self) -> bool {
+        let len = self.s.len();
+        loop {
+            match self.s {
+                [b' ' | b'\t' | b'\r' | b'\n', rest @ ..] => self.s = rest,
+                [b'(', ..] => {
+                    let mut depth = 0;
+                    let mut escaped = false;
+                    let end = self.s.iter().position(|&ch| {
+                        match ch {
+                            _ if escaped => escaped = false,
+                            b'\\' => escaped = true,
+                            b'(' => depth += 1,
+                            b')' => depth -= 1,
+                            _ => {}
+                        }
+                        depth == 0
+                    });
+                    match end {
+                        Some(end) => self.s = &self.s[end + 1..],
+                        None => break,
+                    }
+                }
+                _ => break,
+            }
+        }
+        self.s.len() != len
+    }
+
+    /// Expects white space or a comment between two tokens.
+    #[inline]
+    fn separator(&mut self) -> Result<()> {
+        if self.skip_cfws() {
+            Ok(())
+        } else {
+            Err(self.invalid())
+        }
+    }
+
+    /// Expects the punctuation, which may be surrounded by white space and comments.
+    #[inline]
+    fn expect(&mut self, expected: u8) -> Result<()> {
+        self.skip_cfws();
+        match self.s {
+            [ch, rest @ ..] if *ch == expected => {
+                self.s = rest;
+                self.skip_cfws();
+                Ok(())
+            }
+            _ => Err(self.invalid()),
+        }
+    }
+
+    /// Expects the end of the input after white space and comments.
+    #[inline]
+    fn end(&mut self) -> Result<()> {
+        self.skip_cfws();
+        if self.s.is_empty() {
+            Ok(())
+        } else {
+            Err(self.invalid())
+        }
+    }
+
+    /// Takes a number of `min` to `max` digits, which is not followed by another digit.
+    #[inline]
+    fn number(&mut self, min: usize, max: usize) -> Result<u32> {
+        match take_number(&mut self.s, min, max) {
+            Some(number) if count_digits(self.s) == 0 => Ok(number),
+            _ => Err(self.invalid()),
+        }
+    }
+
+    /// Takes the optional full or abbreviated day name.
+    #[inline]
+    fn day_name(&mut self) -> Result<Option<WeekDay>> {
+        if !matches!(self.s.first(), Some(ch) if ch.is_ascii_alphabetic()) {
+            return Ok(None);
+        }
+        let (day_name, rest) = parse_day_name(self.s, &Locale::ENGLISH)?;
+        self.s = rest;
+        Ok(Some(day_name))
+    }
+
+    /// Takes the full or abbreviated month name.
+    #[inline]
+    fn month(&mut self) -> Result<Month> {
+        let (month, _, rest) = parse_month_name(self.s, &Locale::ENGLISH)?;
+        self.s = rest;
+        Ok(month)
+    }
+
+    /// Takes the year, where the obsolete years of two digits are 1950 to 2049,
+    /// and those of three digits are from 1900.
+    #[inline]
+    fn year(&mut self) -> Result<i32> {
+        let digits = count_digits(self.s);
+        let year = self.number(2, 4)? as i32;
+        Ok(match digits {
+            2 if year < 50 => year + 2000,
+            2 | 3 => year + 1900,
+            _ => year,
+        })
+    }
+
+    /// Takes the time 'hour:minute[:second]'.
+    #[inline]
+    fn time(&mut self) -> Result<Time> {
+        let hour = self.number(2, 2)?;
+        self.expect(b':')?;
+        let minute = self.number(2, 2)?;
+        let rest = self.s;
+        self.skip_cfws();
+        let sec = if self.s.first() == Some(&b':') {
+            self.expect(b':')?;
+            self.number(2, 2)?
+        } else {
+            self.s = rest;
+            0
+        };
+        Time::try_from_hms(hour, minute, sec, 0)
+    }
+
+    /// Takes the zone, '+hhmm', '-hhmm' or a name, where the military zones of one letter
+    /// are taken as UTC as RFC 2822 suggests.
+    fn zone(&mut self) -> Result<IntervalDT> {
+        let negative = match self.s.first() {
+            Some(b'+') => false,
+            Some(b'-') => true,
+            _ => {
+                let len = self
+                    .s
+                    .iter()
+                    .take_while(|ch| ch.is_ascii_alphabetic())
+                    .count();
+                let (name, rest) = self.s.split_at(len);
+                let hours = match ZONE_TABLE
+                    .iter()
+                    .find(|(zone, _)| zone.as_bytes().eq_ignore_ascii_case(name))
+                {
+                    Some((_, hours)) => *hours,
+                    None if len == 1 && !name.eq_ignore_ascii_case(b"J") => 0,
+                    None => return Err(self.invalid()),
+                };
+                self.s = rest;
+                return IntervalDT::try_from_usecs(hours * USECONDS_PER_HOUR);
+            }
+        };
+        self.s = &self.s[1..];
+
+        let number = self.number(4, 4)?;
+        let (hour, minute) = (number / 100, number % 100);
+        if hour > 23 {
+            return Err(Error::TimeOutOfRange);
+        }
+        if minute > 59 {
+            return Err(Error::InvalidMinute);
+        }
+        let usecs = hour as i64 * USECONDS_PER_HOUR + minute as i64 * USECONDS_PER_MINUTE;
+        IntervalDT::try_from_usecs(if negative { -usecs } else { usecs })
+    }
+}
+
+/// Makes the timestamp, checking that the day name, if any, is the day of the date.
+#[inline]
+fn resolve(
+    day_name: Option<WeekDay>,
+    year: i32,
+    month: Month,
+    day: u32,
+    time: Time,
+) -> Result<Timestamp> {
+    let date = Date::try_from_ymd(year, month as u32, day)?;
+    match day_name {
+        Some(day_name) if day_name != date.day_of_week() => Err(Error::InvalidDayOfWeek),
+        _ => Ok(date.and_time(time)),
+    }
+}
+
+/// Formats a timestamp in UTC as an RFC 1123 date, which is the IMF-fixdate of HTTP,
+/// e.g. 'Tue, 15 Nov 1994 08:12:31 GMT', truncating the fraction of seconds.
+/// The year must be from 1 to 9999, which `Timestamp::rfc1123` checks.
+pub(crate) struct Rfc1123(pub(crate) Timestamp);
+
+impl Display for Rfc1123 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (date, time) = self.0.extract();
+        let (year, month, day) = date.extract();
+        let (hour, minute, sec, _) = time.extract();
+        write!(
+            f,
+            "{}, {:02} {} {:04} {:02}:{:02}:{:02} GMT",
+            Locale::ENGLISH.day_name(date.day_of_week(), true),
+            day,
+            Locale::ENGLISH.month_name(Month::from(month as usize), true),
+            year,
+            hour,
+            minute,
+            sec
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Date, Error, IntervalDT, Timestamp};
+
+    fn generate_ts(year: i32, month: u32, day: u32, hour: u32, min: u32, sec: u32) -> Timestamp {
+        Date::try_from_ymd(year, month, day)
+            .unwrap()
+            .and_hms(hour, min, sec, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_parse_rfc2822() {
+        let ts = generate_ts(1994, 11, 15, 8, 12, 31);
+        let utc = IntervalDT::ZERO;
+        let est = -IntervalDT::try_from_dhms(0, 5, 0, 0, 0).unwrap();
+        for (input, offset) in [
+            ("Tue, 15 Nov 1994 08:12:31 GMT", utc),
+            ("15 Nov 1994 08:12:31 +0000", utc),
+            ("Tue, 15 Nov 1994 08:12:31 -0500", est),
+            ("tuesday , 15 november 94 08 : 12 : 31 EST", est),
+            (
+                "  Tue,15 Nov 1994 08:12:31 (Eastern) EST (Standard Time) ",
+                est,
+            ),
+            ("Tue, 15 (a (nested) comment) Nov 1994 08:12:31 z", utc),
+            ("Tue, 15 Nov 94 08:12:31 A", utc),
+        ] {
+            assert_eq!(
+                Timestamp::parse_rfc2822(input).unwrap(),
+                (ts, offset),
+                "{}",
+                input
+            );
+        }
+        assert_eq!(
+            Timestamp::parse_rfc2822("1 Jan 2021 00:00 +0530").unwrap(),
+            (
+                generate_ts(2021, 1, 1, 0, 0, 0),
+                IntervalDT::try_from_dhms(0, 5, 30, 0, 0).unwrap()
+            )
+        );
+        assert_eq!(
+            Timestamp::parse_rfc2822("15 Nov 49 08:12:31 GMT")
+                .unwrap()
+                .0,
+            generate_ts(2049, 11, 15, 8, 12, 31)
+        );
+        assert_eq!(
+            Timestamp::parse_rfc2822("15 Nov 102 08:12:31 GMT")
+                .unwrap()
+                .0,
+            generate_ts(2002, 11, 15, 8, 12, 31)
+        );
+        assert_eq!(
+            Timestamp::parse_rfc2822_utc("Tue, 15 Nov 1994 03:12:31 EST").unwrap(),
+            ts
+        );
+        assert_eq!(
+            Timestamp::parse_rfc2822_utc("Tue, 15 Nov 1994 18:12:31 +1000").unwrap(),
+            ts
+        );
+
+        assert_eq!(
+            Timestamp::parse_rfc2822("Wed, 15 Nov 1994 08:12:31 GMT").unwrap_err(),
+            Error::InvalidDayOfWeek
+        );
+        assert_eq!(
+            Timestamp::parse_rfc2822("Tue, 15 Nev 1994 08:12:31 GMT").unwrap_err(),
+            Error::InvalidMonth
+        );
+        assert_eq!(
+            Timestamp::parse_rfc2822("31 Nov 1994 08:12:31 GMT").unwrap_err(),
+            Error::InvalidDate
+        );
+        assert_eq!(
+            Timestamp::parse_rfc2822("15 Nov 1994 08:12:60 GMT").unwrap_err(),
+            Error::InvalidSecond
+        );
+        assert_eq!(
+            Timestamp::parse_rfc2822("15 Nov 1994 08:12:31 +0060").unwrap_err(),
+            Error::InvalidMinute
+        );
+        assert_eq!(
+            Timestamp::parse_rfc2822("Sun, 06 Nov 1994 08:49:37 +9959").unwrap_err(),
+            Error::TimeOutOfRange
+        );
+        assert_eq!(
+            Timestamp::parse_rfc2822("Sun, 06 Nov 1994 08:49:37 -2400").unwrap_err(),
+            Error::TimeOutOfRange
+        );
+        for input in [
+            "Tue 15 Nov 1994 08:12:31 GMT",
+            "15 Nov 1994 08:12:31",
+            "15 Nov 1994 08:12:31 J",
+            "15 Nov 1994 08:12:31 CET",
+            "15 Nov 1994 08:12:31 +05",
+            "15Nov 1994 08:12:31 GMT",
+            "15 Nov 199408:12:31 GMT",
+            "15 Nov 1994 8:12:31 GMT",
+            "15 Nov 1994 08:12:31 GMT (unterminated",
+            "15 Nov 1994 08:12:31 GMT x",
+        ] {
+            assert_eq!(
+                Timestamp::parse_rfc2822(input).unwrap_err(),
+                Error::InvalidLiteral("RFC 2822 date"),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_http_date() {
+        let ts = generate_ts(1994, 11, 6, 8, 49, 37);
+        for input in [
+            "Sun, 06 Nov 1994 08:49:37 GMT",
+            "Sunday, 06-Nov-94 08:49:37 GMT",
+            "Sun Nov  6 08:49:37 1994",
+            "Sun, 06 Nov 1994 09:49:37 +0100",
+            "06 Nov 1994 08:49:37 UT",
+        ] {
+            assert_eq!(Timestamp::parse_http_date(input).unwrap(), ts, "{}", input);
+        }
+        assert_eq!(
+            Timestamp::parse_http_date("Mon Nov  6 08:49:37 1994").unwrap_err(),
+            Error::InvalidDayOfWeek
+        );
+        for input in [
+            "Sun Nov 6 08:49:37 94",
+            "Sun Nov 6 08:49:37 1994 GMT",
+            "Sunday, 06-Nov-94 08:49:37",
+            "Sun, 06 Nov 1994",
+        ] {
+            assert_eq!(
+                Timestamp::parse_http_date(input).unwrap_err(),
+                Error::InvalidLiteral("HTTP date"),
+                "{}",
+                input
+            );
+        }
+
+        assert_eq!(
+            ts.rfc1123().unwrap().to_string(),
+            "Sun, 06 Nov 1994 08:49:37 GMT"
+        );
+        let ts = Date::try_from_ymd(2021, 10, 1)
+            .unwrap()
+            .and_hms(0, 0, 5, 999999)
+            .unwrap();
+        assert_eq!(
+            ts.rfc1123().unwrap().to_string(),
+            "Fri, 01 Oct 2021 00:00:05 GMT"
+        );
+        assert_eq!(
+            Timestamp::parse_http_date(ts.rfc1123().unwrap().to_string()).unwrap(),
+            generate_ts(2021, 10, 1, 0, 0, 5)
+        );
+
+        // The years are from 1 to 9999
+        assert_eq!(
+            generate_ts(1, 1, 1, 0, 0, 0).rfc1123().unwrap().to_string(),
+            "Mon, 01 Jan 0001 00:00:00 GMT"
+        );
+        assert_eq!(
+            Timestamp::MAX.rfc1123().unwrap().to_string(),
+            "Fri, 31 Dec 9999 23:59:59 GMT"
+        );
+        assert!(matches!(
+            generate_ts(-44, 3, 15, 0, 0, 0).rfc1123(),
+            Err(Error::DateOutOfRange)
+        ));
+    }
+}
@@ -7,19 +7,26 @@ use serde_crate::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
 use stack_buf::StackStr;
 use std::fmt;
 
-static DATE_FORMATTER: Lazy<Formatter> = Lazy::new(|| Formatter::try_new("YYYY-MM-DD").unwrap());
+// The models are checked at compile time by `formatter!`.
+// The BC dates are written with a minus sign by the signed formats, which read
+// the years with or without a sign.
+static DATE_FORMATTER: Lazy<Formatter> = Lazy::new(|| crate::formatter!("YYYY-MM-DD"));
+static SIGNED_DATE_FORMATTER: Lazy<Formatter> = Lazy::new(|| crate::formatter!("SYYYY-MM-DD"));
 static TIMESTAMP_FORMATTER: Lazy<Formatter> =
-    Lazy::new(|| Formatter::try_new("YYYY-MM-DD HH24:MI:SS.FF6").unwrap());
-static TIME_FORMATTER: Lazy<Formatter> =
-    Lazy::new(|| Formatter::try_new("HH24:MI:SS.FF6").unwrap());
-static INTERVAL_YM_FORMATTER: Lazy<Formatter> =
-    Lazy::new(|| Formatter::try_new("YYYY-MM").unwrap());
+    Lazy::new(|| crate::formatter!("YYYY-MM-DD HH24:MI:SS.FF6"));
+static SIGNED_TIMESTAMP_FORMATTER: Lazy<Formatter> =
+    Lazy::new(|| crate::formatter!("SYYYY-MM-DD HH24:MI:SS.FF6"));
+static TIME_FORMATTER: Lazy<Formatter> = Lazy::new(|| crate::formatter!("HH24:MI:SS.FF6"));
+static INTERVAL_YM_FORMATTER: Lazy<Formatter> = Lazy::new(|| crate::formatter!("YYYY-MM"));
 static INTERVAL_DT_FORMATTER: Lazy<Formatter> =
-    Lazy::new(|| Formatter::try_new("DD HH24:MI:SS.FF6").unwrap());
+    Lazy::new(|| crate::formatter!("DD HH24:MI:SS.FF6"));
 
 #[cfg(feature = "oracle")]
 static ORACLE_DATE_FORMATTER: Lazy<Formatter> =
-    Lazy::new(|| Formatter::try_new("YYYY-MM-DD HH24:MI:SS").unwrap());
+    Lazy::new(|| crate::formatter!("YYYY-MM-DD HH24:MI:SS"));
+#[cfg(feature = "oracle")]
+static SIGNED_ORACLE_DATE_FORMATTER: Lazy<Formatter> =
+    Lazy::new(|| crate::formatter!("SYYYY-MM-DD HH24:MI:SS"));
 
 type StrBuf = StackStr<32>;
 
@@ -32,7 +39,12 @@ impl Serialize for Date {
     {
         if serializer.is_human_readable() {
             let mut buf = StrBuf::new();
-            DATE_FORMATTER
+            let formatter: &Formatter = if self.extract().0 < 0 {
+                &SIGNED_DATE_FORMATTER
+            } else {
+                &DATE_FORMATTER
+            };
+            formatter
                 .format(*self, &mut buf)
                 .map_err(ser::Error::custom)?;
             serializer.serialize_str(&buf)
@@ -72,7 +84,7 @@ impl<'de> Deserialize<'de> for Date {
             where
                 E: de::Error,
             {
-                DATE_FORMATTER.parse(v).map_err(de::Error::custom)
+                SIGNED_DATE_FORMATTER.parse(v).map_err(de::Error::custom)
             }
         }
 
@@ -93,7 +105,12 @@ impl Serialize for Timestamp {
     {
         if serializer.is_human_readable() {
             let mut buf = StrBuf::new();
-            TIMESTAMP_FORMATTER
+            let formatter: &Formatter = if self.extract().0.extract().0 < 0 {
+                &SIGNED_TIMESTAMP_FORMATTER
+            } else {
+                &TIMESTAMP_FORMATTER
+            };
+            formatter
                 .format(*self, &mut buf)
                 .map_err(ser::Error::custom)?;
             serializer.serialize_str(&buf)
@@ -133,7 +150,9 @@ impl<'de> Deserialize<'de> for Timestamp {
             where
                 E: de::Error,
             {
-                TIMESTAMP_FORMATTER.parse(v).map_err(de::Error::custom)
+                SIGNED_TIMESTAMP_FORMATTER
+                    .parse(v)
+                    .map_err(de::Error::custom)
             }
         }
 
@@ -338,7 +357,12 @@ impl Serialize for crate::oracle::Date {
     {
         if serializer.is_human_readable() {
             let mut buf = StrBuf::new();
-            ORACLE_DATE_FORMATTER
+            let formatter: &Formatter = if self.extract().0.extract().0 < 0 {
+                &SIGNED_ORACLE_DATE_FORMATTER
+            } else {
+                &ORACLE_DATE_FORMATTER
+            };
+            formatter
                 .format(*self, &mut buf)
                 .map_err(ser::Error::custom)?;
             serializer.serialize_str(&buf)
@@ -379,7 +403,9 @@ impl<'de> Deserialize<'de> for crate::oracle::Date {
             where
                 E: de::Error,
             {
-                ORACLE_DATE_FORMATTER.parse(v).map_err(de::Error::custom)
+                SIGNED_ORACLE_DATE_FORMATTER
+                    .parse(v)
+                    .map_err(de::Error::custom)
             }
         }
 
@@ -399,10 +425,12 @@ mod tests {
         let date = Date::try_from_ymd(year, mon, day).unwrap();
         let date_json = serde_json::to_string(&date).unwrap();
         let json_decode: Date = serde_json::from_str(&date_json).unwrap();
-        assert_eq!(
-            date_json,
-            format!("\"{}\"", date.format("YYYY-MM-DD").unwrap())
-        );
+        let fmt = if year < 0 {
+            "SYYYY-MM-DD"
+        } else {
+            "YYYY-MM-DD"
+        };
+        assert_eq!(date_json, format!("\"{}\"", date.format(fmt).unwrap()));
         assert_eq!(json_decode, date);
 
         let bin = bincode::serialize(&date).unwrap();
@@ -419,6 +447,16 @@ mod tests {
         test_date(1970, 1, 1);
         test_date(2000, 1, 1);
         test_date(9999, 12, 31);
+        test_date(-1, 12, 31);
+        test_date(-44, 3, 15);
+        test_date(-4712, 1, 1);
+
+        let date = Date::try_from_ymd(-44, 3, 15).unwrap();
+        assert_eq!(serde_json::to_string(&date).unwrap(), r#""-0044-03-15""#);
+        assert_eq!(
+            serde_json::from_str::<Date>(r#""-0044-03-15""#).unwrap(),
+            date
+        );
     }
 
     fn test_timestamp(year: i32, mon: u32, day: u32, hour: u32, min: u32, sec: u32, usec: u32) {
@@ -426,13 +464,12 @@ mod tests {
         let time = Time::try_from_hms(hour, min, sec, usec).unwrap();
         let timestamp = Timestamp::new(date, time);
         let ts_json = serde_json::to_string(&timestamp).unwrap();
-        assert_eq!(
-            ts_json,
-            format!(
-                "\"{}\"",
-                timestamp.format("YYYY-MM-DD HH24:MI:SS.FF6").unwrap()
-            )
-        );
+        let fmt = if year < 0 {
+            "SYYYY-MM-DD HH24:MI:SS.FF6"
+        } else {
+            "YYYY-MM-DD HH24:MI:SS.FF6"
+        };
+        assert_eq!(ts_json, format!("\"{}\"", timestamp.format(fmt).unwrap()));
         let json_decode: Timestamp = serde_json::from_str(&ts_json).unwrap();
         assert_eq!(json_decode, timestamp);
 
@@ -450,6 +487,16 @@ mod tests {
         test_timestamp(1970, 1, 1, 0, 0, 0, 0);
         test_timestamp(1970, 10, 1, 23, 30, 0, 0);
         test_timestamp(9999, 12, 31, 23, 59, 59, 999999);
+        test_timestamp(-44, 3, 15, 12, 30, 0, 500000);
+        test_timestamp(-4712, 1, 1, 0, 0, 0, 0);
+
+        let timestamp = Date::try_from_ymd(-44, 3, 15)
+            .unwrap()
+            .and_hms(12, 30, 0, 0)
+            .unwrap();
+        let json = r#""-0044-03-15 12:30:00.000000""#;
+        assert_eq!(serde_json::to_string(&timestamp).unwrap(), json);
+        assert_eq!(serde_json::from_str::<Timestamp>(json).unwrap(), timestamp);
     }
 
     fn test_time(hour: u32, min: u32, sec: u32, usec: u32) {
@@ -543,10 +590,12 @@ mod tests {
         let time = Time::try_from_hms(hour, min, sec, 0).unwrap();
         let date = crate::oracle::Date::new(date, time);
         let date_json = serde_json::to_string(&date).unwrap();
-        assert_eq!(
-            date_json,
-            format!("\"{}\"", date.format("YYYY-MM-DD HH24:MI:SS").unwrap())
-        );
+        let fmt = if year < 0 {
+            "SYYYY-MM-DD HH24:MI:SS"
+        } else {
+            "YYYY-MM-DD HH24:MI:SS"
+        };
+        assert_eq!(date_json, format!("\"{}\"", date.format(fmt).unwrap()));
         let json_decode: crate::oracle::Date = serde_json::from_str(&date_json).unwrap();
         assert_eq!(json_decode, date);
 
@@ -565,5 +614,6 @@ mod tests {
         test_oracle_date(1970, 1, 1, 0, 0, 0);
         test_oracle_date(1970, 10, 1, 23, 30, 0);
         test_oracle_date(9999, 12, 31, 23, 59, 59);
+        test_oracle_date(-44, 3, 15, 12, 30, 0);
     }
 }
@@ -6,6 +6,8 @@ use crate::common::{
 };
 use crate::error::{Error, Result};
 use crate::format::{Formatter, LazyFormat, NaiveDateTime};
+use crate::iso8601::{self, Iso8601};
+use crate::literal::{parse_auto, parse_mysql_time, DateOrder, LiteralForm};
 use crate::{Date, DateTime, IntervalDT, Timestamp};
 use std::cmp::Ordering;
 use std::convert::TryFrom;
@@ -159,6 +161,81 @@ impl Time {
         fmt.parse(input)
     }
 
+    /// Parses `Time` from a MySQL time literal, e.g. '17:06:07.123', '0 17:06' or '170607'.
+    #[inline]
+    pub fn parse_mysql<S: AsRef<str>>(input: S) -> Result<Self> {
+        Time::try_from(parse_mysql_time(input.as_ref())?)
+    }
+
+    /// Parses `Time` from a literal of a common form without a format, e.g. '17:06:07.123',
+    /// 'T17:06', TIME '17:06:07' or '170607', and returns the form matched.
+    /// A time with a UTC offset, e.g. '17:06Z' or '17:06+08:00', is normalized to UTC
+    /// as `parse_iso8601_utc` does.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use sqldatetime::{LiteralForm, Time};
+    ///
+    /// assert_eq!(
+    ///     Time::parse_auto("170607").unwrap(),
+    ///     (Time::try_from_hms(17, 6, 7, 0).unwrap(), LiteralForm::Compact)
+    /// );
+    /// ```
+    #[inline]
+    pub fn parse_auto<S: AsRef<str>>(input: S) -> Result<(Self, LiteralForm)> {
+        // The order of a date does not matter to a time
+        match parse_auto(input.as_ref(), DateOrder::DayMonthYear, "time")? {
+            auto if !auto.has_date => {
+                let time = Time::try_from(auto.dt)?;
+                let offset = auto.offset.unwrap_or(IntervalDT::ZERO);
+                Ok((time.sub_interval_dt(offset), auto.form))
+            }
+            _ => Err(Error::InvalidLiteral("time")),
+        }
+    }
+
+    /// Parses `Time` from an ISO 8601 time, extended or basic with an optional 'T', and returns
+    /// the UTC offset if any, e.g. '12:30:05.5', 'T123005,5Z' or '12:30+08:00'.
+    /// The fraction may have any length and is truncated to microseconds.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use sqldatetime::{IntervalDT, Time};
+    ///
+    /// assert_eq!(
+    ///     Time::parse_iso8601("12:30:05.5+08:00").unwrap(),
+    ///     (
+    ///         Time::try_from_hms(12, 30, 5, 500000).unwrap(),
+    ///         Some(IntervalDT::try_from_dhms(0, 8, 0, 0, 0).unwrap())
+    ///     )
+    /// );
+    /// ```
+    #[inline]
+    pub fn parse_iso8601<S: AsRef<str>>(input: S) -> Result<(Self, Option<IntervalDT>)> {
+        iso8601::parse_time(input.as_ref())
+    }
+
+    /// Parses `Time` from an ISO 8601 time as `parse_iso8601` does, and normalizes it to UTC
+    /// by the offset, wrapping around midnight. A time without an offset is taken as UTC.
+    #[inline]
+    pub fn parse_iso8601_utc<S: AsRef<str>>(input: S) -> Result<Self> {
+        let (time, offset) = iso8601::parse_time(input.as_ref())?;
+        Ok(time.sub_interval_dt(offset.unwrap_or(IntervalDT::ZERO)))
+    }
+
+    /// Formats `Time` as an ISO 8601 time, e.g. '12:30:05.5', with the fraction of seconds only
+    /// when it is not zero.
+    #[inline]
+    pub fn iso8601(self) -> impl Display {
+        Iso8601 {
+            date: None,
+            time: Some(self),
+            utc: false,
+        }
+    }
+
     /// `Time` subtracts `Time`
     #[inline]
     pub const fn sub_time(self, time: Time) -> IntervalDT {
@@ -387,30 +464,30 @@ mod tests {
             // Hours
             assert_eq!(
                 Err(Error::TimeOutOfRange),
-                Time::parse("24:59:59.9999995", "HH24:MI:SS.FF")
+                Time::parse("24:59:59.9999995", "HH24:MI:SS.FF").map_err(|e| e.kind())
             );
             assert_eq!(
                 Err(Error::TimeOutOfRange),
-                Time::parse("25:59:59.9999995", "HH24:MI:SS.FF")
+                Time::parse("25:59:59.9999995", "HH24:MI:SS.FF").map_err(|e| e.kind())
             );
             // Minutes
             assert_eq!(
                 Err(Error::InvalidMinute),
-                Time::parse("23:60:59.9999995", "HH24:MI:SS.FF")
+                Time::parse("23:60:59.9999995", "HH24:MI:SS.FF").map_err(|e| e.kind())
             );
             // Seconds
             assert_eq!(
                 Err(Error::InvalidSecond),
-                Time::parse("23:59:60.9999995", "HH24:MI:SS.FF")
+                Time::parse("23:59:60.9999995", "HH24:MI:SS.FF").map_err(|e| e.kind())
             );
             // Maximum Value
             assert_eq!(
                 Err(Error::TimeOutOfRange),
-                Time::parse("23:59:59.9999995", "HH24:MI:SS.FF")
+                Time::parse("23:59:59.9999995", "HH24:MI:SS.FF").map_err(|e| e.kind())
             );
             assert_eq!(
                 Err(Error::TimeOutOfRange),
-                Time::parse("23:59:59.99999999", "HH24:MI:SS.FF")
+                Time::parse("23:59:59.99999999", "HH24:MI:SS.FF").map_err(|e| e.kind())
             );
         }
 
@@ -580,6 +657,20 @@ mod tests {
             assert!(Time::parse("11:12:134", "hh24:mi:ss.ff").is_err());
             assert!(Time::parse("11:12134", "hh24:mi:ss.ff").is_err());
 
+            assert!(Time::parse("18367", "J").is_err());
+        }
+
+        // Seconds past midnight
+        {
+            let time = Time::try_from_hms(5, 6, 7, 0).unwrap();
+            assert_eq!(format!("{}", time.format("SSSSS").unwrap()), "18367");
+            assert_eq!(Time::parse("18367", "SSSSS").unwrap(), time);
+            assert_eq!(
+                Time::parse("18367.000008", "SSSSS.FF").unwrap(),
+                Time::try_from_hms(5, 6, 7, 8).unwrap()
+            );
+            assert!(Time::parse("18367 06", "SSSSS HH").is_err());
+
             // todo Add all types check
         }
     }
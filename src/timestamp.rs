@@ -3,6 +3,9 @@
 use crate::common::*;
 use crate::error::{Error, Result};
 use crate::format::{Formatter, LazyFormat, NaiveDateTime};
+use crate::iso8601::{self, Iso8601};
+use crate::literal::{parse_auto, parse_mysql_datetime, DateOrder, LiteralForm};
+use crate::rfc2822::{self, Rfc1123};
 use crate::{Date, DateTime, IntervalDT, IntervalYM, Round, Time, Trunc};
 use chrono::{Datelike, Local, Timelike};
 use std::cmp::Ordering;
@@ -17,7 +20,7 @@ use std::fmt::Display;
 pub struct Timestamp(i64);
 
 impl Timestamp {
-    /// The smallest timestamp that can be represented by `Date`, i.e. `0001-01-01 00:00:00.000000`.
+    /// The smallest timestamp that can be represented by `Date`, i.e. `-4712-01-01 00:00:00.000000`.
     pub const MIN: Self = Timestamp::new(Date::MIN, Time::ZERO);
 
     /// The largest timestamp that can be represented by `Date`, i.e. `9999-12-31 23:59:59.999999`.
@@ -66,9 +69,9 @@ impl Timestamp {
     pub(crate) fn time(self) -> Time {
         let temp_time = self.0 % USECONDS_PER_DAY;
         if temp_time.is_negative() {
-            unsafe { Time::from_usecs_unchecked(temp_time as i64 + USECONDS_PER_DAY) }
+            unsafe { Time::from_usecs_unchecked(temp_time + USECONDS_PER_DAY) }
         } else {
-            unsafe { Time::from_usecs_unchecked(temp_time as i64) }
+            unsafe { Time::from_usecs_unchecked(temp_time) }
         }
     }
 
@@ -102,6 +105,173 @@ impl Timestamp {
         fmt.parse(input)
     }
 
+    /// Parses `Timestamp` from a MySQL datetime literal, e.g. '2021-03-03 17:06:07.123', '2021^03^03T17+06+07'
+    /// or '20210303170607'.
+    #[inline]
+    pub fn parse_mysql<S: AsRef<str>>(input: S) -> Result<Self> {
+        Timestamp::try_from(parse_mysql_datetime(input.as_ref())?)
+    }
+
+    /// Parses `Timestamp` from a literal of a common form without a format, and returns the form matched:
+    ///
+    /// - ISO 8601, e.g. '2021-10-01T12:00:00.5', '2021-10-01T12:00:00Z' or '2021-10-01'.
+    /// - SQL standard, e.g. '2021-10-01 12:00:00.5', '2021-10-01 12:00:00+02:00'
+    ///   or TIMESTAMP '2021-10-01 12:00:00'.
+    /// - 'DD-MON-YYYY' and Oracle's default 'DD-MON-RR', e.g. '01-OCT-2021 12:00:00' or '01-OCT-21'.
+    /// - 'DD/MM/YYYY' and 'MM/DD/YYYY', e.g. '10/01/2021 12:00:00 PM', which is read in the given `order`
+    ///   unless it is obvious.
+    /// - 'YYYYMMDDHHMISS[.fraction]' and 'YYYYMMDD' without delimiters.
+    ///
+    /// The time may omit the seconds, and has a meridian indicator only in 'DD-MON-YYYY' and
+    /// the forms separated by slashes. A timestamp with a UTC offset is normalized to UTC.
+    /// A year of two digits is in the century chosen by Oracle's 'RR' rule.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use sqldatetime::{DateOrder, LiteralForm, Timestamp};
+    ///
+    /// let (ts, form) = Timestamp::parse_auto("10/13/2021 1:30 PM", DateOrder::DayMonthYear).unwrap();
+    /// assert_eq!(ts, Timestamp::parse("2021-10-13 13:30:00", "YYYY-MM-DD HH24:MI:SS").unwrap());
+    /// assert_eq!(form, LiteralForm::MonthDayYear);
+    /// ```
+    #[inline]
+    pub fn parse_auto<S: AsRef<str>>(input: S, order: DateOrder) -> Result<(Self, LiteralForm)> {
+        match parse_auto(input.as_ref(), order, "timestamp")? {
+            auto if auto.has_date => {
+                let ts = Timestamp::try_from(auto.dt)?;
+                match auto.offset {
+                    Some(offset) => Ok((ts.sub_interval_dt(offset)?, auto.form)),
+                    None => Ok((ts, auto.form)),
+                }
+            }
+            _ => Err(Error::InvalidLiteral("timestamp")),
+        }
+    }
+
+    /// Parses `Timestamp` from an ISO 8601 or RFC 3339 timestamp, and returns the UTC offset if any.
+    /// The date is any form of `Date::parse_iso8601`, and is followed by 'T' or a space and a time of
+    /// `Time::parse_iso8601`, e.g. '2021-10-01T12:30:05.5Z' or '20211001T123005+0800'.
+    /// A date alone is taken as midnight. The timestamp keeps the local time of the offset.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use sqldatetime::{IntervalDT, Timestamp};
+    ///
+    /// let (ts, offset) = Timestamp::parse_iso8601("2021-10-01T12:30:05.5-02:30").unwrap();
+    /// assert_eq!(ts, Timestamp::parse("2021-10-01 12:30:05.5", "YYYY-MM-DD HH24:MI:SS.FF").unwrap());
+    /// assert_eq!(offset, Some(-IntervalDT::try_from_dhms(0, 2, 30, 0, 0).unwrap()));
+    /// ```
+    #[inline]
+    pub fn parse_iso8601<S: AsRef<str>>(input: S) -> Result<(Self, Option<IntervalDT>)> {
+        iso8601::parse_timestamp(input.as_ref())
+    }
+
+    /// Parses `Timestamp` from an ISO 8601 or RFC 3339 timestamp as `parse_iso8601` does,
+    /// and normalizes it to UTC by the offset. A timestamp without an offset is taken as UTC.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use sqldatetime::Timestamp;
+    ///
+    /// let ts = Timestamp::parse_iso8601_utc("2021-10-01T01:00:00+08:00").unwrap();
+    /// assert_eq!(ts.rfc3339().to_string(), "2021-09-30T17:00:00Z");
+    /// ```
+    #[inline]
+    pub fn parse_iso8601_utc<S: AsRef<str>>(input: S) -> Result<Self> {
+        match iso8601::parse_timestamp(input.as_ref())? {
+            (ts, Some(offset)) => ts.sub_interval_dt(offset),
+            (ts, None) => Ok(ts),
+        }
+    }
+
+    /// Formats `Timestamp` as an ISO 8601 timestamp without an offset, e.g. '2021-10-01T12:30:05.5',
+    /// with the fraction of seconds only when it is not zero.
+    #[inline]
+    pub fn iso8601(self) -> impl Display {
+        let (date, time) = self.extract();
+        Iso8601 {
+            date: Some(date),
+            time: Some(time),
+            utc: false,
+        }
+    }
+
+    /// Formats `Timestamp`, which is in UTC, as an RFC 3339 timestamp, e.g. '2021-10-01T12:30:05.5Z'.
+    #[inline]
+    pub fn rfc3339(self) -> impl Display {
+        let (date, time) = self.extract();
+        Iso8601 {
+            date: Some(date),
+            time: Some(time),
+            utc: true,
+        }
+    }
+
+    /// Parses `Timestamp` from an RFC 2822 date, which is also an RFC 822 or RFC 1123 date,
+    /// and returns the offset of the zone, e.g. 'Tue, 15 Nov 1994 08:12:31 -0500'.
+    /// The day and month names are in English, and the obsolete forms of RFC 2822 are accepted:
+    /// years of two or three digits, zone names such as 'GMT' or 'EST', and comments.
+    /// The timestamp keeps the local time of the zone.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use sqldatetime::{IntervalDT, Timestamp};
+    ///
+    /// let (ts, offset) = Timestamp::parse_rfc2822("Tue, 15 Nov 1994 08:12:31 -0500").unwrap();
+    /// assert_eq!(ts, Timestamp::parse("1994-11-15 08:12:31", "YYYY-MM-DD HH24:MI:SS").unwrap());
+    /// assert_eq!(offset, -IntervalDT::try_from_dhms(0, 5, 0, 0, 0).unwrap());
+    /// ```
+    #[inline]
+    pub fn parse_rfc2822<S: AsRef<str>>(input: S) -> Result<(Self, IntervalDT)> {
+        rfc2822::parse_rfc2822(input.as_ref())
+    }
+
+    /// Parses `Timestamp` from an RFC 2822 date as `parse_rfc2822` does,
+    /// and normalizes it to UTC by the offset of the zone.
+    #[inline]
+    pub fn parse_rfc2822_utc<S: AsRef<str>>(input: S) -> Result<Self> {
+        let (ts, offset) = rfc2822::parse_rfc2822(input.as_ref())?;
+        ts.sub_interval_dt(offset)
+    }
+
+    /// Parses `Timestamp` in UTC from an HTTP date, such as a `Last-Modified` header,
+    /// in any of the forms of RFC 7231:
+    ///
+    /// - IMF-fixdate of RFC 1123, e.g. 'Sun, 06 Nov 1994 08:49:37 GMT', or any RFC 2822 date.
+    /// - RFC 850, e.g. 'Sunday, 06-Nov-94 08:49:37 GMT'.
+    /// - asctime, e.g. 'Sun Nov  6 08:49:37 1994'.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use sqldatetime::Timestamp;
+    ///
+    /// let ts = Timestamp::parse_http_date("Sunday, 06-Nov-94 08:49:37 GMT").unwrap();
+    /// assert_eq!(ts, Timestamp::parse_http_date("Sun Nov  6 08:49:37 1994").unwrap());
+    /// assert_eq!(ts.rfc1123().unwrap().to_string(), "Sun, 06 Nov 1994 08:49:37 GMT");
+    /// ```
+    #[inline]
+    pub fn parse_http_date<S: AsRef<str>>(input: S) -> Result<Self> {
+        rfc2822::parse_http_date(input.as_ref())
+    }
+
+    /// Formats `Timestamp`, which is in UTC, as an RFC 1123 date, which is the IMF-fixdate of HTTP,
+    /// e.g. 'Sun, 06 Nov 1994 08:49:37 GMT'. The fraction of seconds is truncated.
+    ///
+    /// The year of an RFC 1123 date has four digits, so a BC timestamp, whose year is out of
+    /// the range 1 to 9999, is rejected by `Error::DateOutOfRange`.
+    #[inline]
+    pub fn rfc1123(self) -> Result<impl Display> {
+        if self.extract().0.extract().0 < 1 {
+            return Err(Error::DateOutOfRange);
+        }
+        Ok(Rfc1123(self))
+    }
+
     /// Creates a `Timestamp` from the given microseconds from Unix Epoch
     #[inline]
     pub const fn try_from_usecs(usecs: i64) -> Result<Self> {
@@ -204,7 +374,7 @@ impl Timestamp {
                 now.hour(),
                 now.minute(),
                 now.second(),
-                now.timestamp_subsec_micros(),
+                now.nanosecond() / 1000,
             )?,
         ))
     }
@@ -378,7 +548,7 @@ impl Round for Timestamp {
                 hour += 1
             }
         }
-        Ok(date.and_time(unsafe { Time::from_hms_unchecked(hour as u32, 0, 0, 0) }))
+        Ok(date.and_time(unsafe { Time::from_hms_unchecked(hour, 0, 0, 0) }))
     }
 
     #[inline]
@@ -518,6 +688,7 @@ impl DateTime for Timestamp {
 mod tests {
     use super::*;
     use crate::common::DATE_MAX_YEAR;
+    use crate::{Dialect, Locale, Territory};
     use chrono::{Datelike, Local};
 
     fn generate_ts(
@@ -702,38 +873,46 @@ mod tests {
                 assert_eq!(
                     Err(Error::InvalidMonth),
                     Timestamp::parse("9999-13-31 24:60:59.999999", "yyyy-mm-dd hh24:mi:ss.ff")
+                        .map_err(|e| e.kind())
                 );
                 assert_eq!(
                     Err(Error::InvalidMonth),
                     Timestamp::parse("9999-20-31 24:60:59.999999", "yyyy-mm-dd hh24:mi:ss.ff")
+                        .map_err(|e| e.kind())
                 );
                 // Day
                 assert_eq!(
                     Err(Error::InvalidDay),
                     Timestamp::parse("9999-12-32 24:59:60.999999", "yyyy-mm-dd hh24:mi:ss.ff")
+                        .map_err(|e| e.kind())
                 );
                 assert_eq!(
                     Err(Error::InvalidDay),
                     Timestamp::parse("9999-12-40 24:59:60.999999", "yyyy-mm-dd hh24:mi:ss.ff")
+                        .map_err(|e| e.kind())
                 );
                 // Hour
                 assert_eq!(
                     Err(Error::TimeOutOfRange),
                     Timestamp::parse("9999-12-31 24:59:59.999999", "yyyy-mm-dd hh24:mi:ss.ff")
+                        .map_err(|e| e.kind())
                 );
                 assert_eq!(
                     Err(Error::TimeOutOfRange),
                     Timestamp::parse("9999-12-31 25:59:59.999999", "yyyy-mm-dd hh24:mi:ss.ff")
+                        .map_err(|e| e.kind())
                 );
                 // Minute
                 assert_eq!(
                     Err(Error::InvalidMinute),
                     Timestamp::parse("9999-12-31 23:60:59.999999", "yyyy-mm-dd hh24:mi:ss.ff")
+                        .map_err(|e| e.kind())
                 );
                 // Second
                 assert_eq!(
                     Err(Error::InvalidSecond),
                     Timestamp::parse("9999-12-31 23:59:60.999999", "yyyy-mm-dd hh24:mi:ss.ff")
+                        .map_err(|e| e.kind())
                 );
                 // Maximum Value
                 assert_eq!(
@@ -816,12 +995,18 @@ mod tests {
                 .is_err());
 
                 assert_eq!(
-                    Timestamp::parse("23:60:00", "hh24:mi:ss").err().unwrap(),
+                    Timestamp::parse("23:60:00", "hh24:mi:ss")
+                        .map_err(|e| e.kind())
+                        .err()
+                        .unwrap(),
                     Error::InvalidMinute
                 );
 
                 assert_eq!(
-                    Timestamp::parse("23:00:60", "hh24:mi:ss").err().unwrap(),
+                    Timestamp::parse("23:00:60", "hh24:mi:ss")
+                        .map_err(|e| e.kind())
+                        .err()
+                        .unwrap(),
                     Error::InvalidSecond
                 );
 
@@ -993,18 +1178,18 @@ mod tests {
                 assert_eq!(format!("{}", ts.format("MON").unwrap()), "AUG");
                 assert_eq!(format!("{}", ts.format("Mon").unwrap()), "Aug");
                 assert_eq!(format!("{}", ts.format("mon").unwrap()), "aug");
-                assert_eq!(format!("{}", ts.format("MONTH").unwrap()), "AUGUST");
-                assert_eq!(format!("{}", ts.format("MONtH").unwrap()), "AUGUST");
-                assert_eq!(format!("{}", ts.format("Month").unwrap()), "August");
-                assert_eq!(format!("{}", ts.format("month").unwrap()), "august");
+                assert_eq!(format!("{}", ts.format("MONTH").unwrap()), "AUGUST   ");
+                assert_eq!(format!("{}", ts.format("MONtH").unwrap()), "AUGUST   ");
+                assert_eq!(format!("{}", ts.format("Month").unwrap()), "August   ");
+                assert_eq!(format!("{}", ts.format("month").unwrap()), "august   ");
                 assert_eq!(format!("{}", ts.format("WW").unwrap()), "32");
                 assert_eq!(format!("{}", ts.format("W").unwrap()), "1");
-                assert_eq!(format!("{}", ts.format("DAY").unwrap()), "SUNDAY");
-                assert_eq!(format!("{}", ts.format("DAy").unwrap()), "SUNDAY");
-                assert_eq!(format!("{}", ts.format("Day").unwrap()), "Sunday");
-                assert_eq!(format!("{}", ts.format("DaY").unwrap()), "Sunday");
-                assert_eq!(format!("{}", ts.format("day").unwrap()), "sunday");
-                assert_eq!(format!("{}", ts.format("daY").unwrap()), "sunday");
+                assert_eq!(format!("{}", ts.format("DAY").unwrap()), "SUNDAY   ");
+                assert_eq!(format!("{}", ts.format("DAy").unwrap()), "SUNDAY   ");
+                assert_eq!(format!("{}", ts.format("Day").unwrap()), "Sunday   ");
+                assert_eq!(format!("{}", ts.format("DaY").unwrap()), "Sunday   ");
+                assert_eq!(format!("{}", ts.format("day").unwrap()), "sunday   ");
+                assert_eq!(format!("{}", ts.format("daY").unwrap()), "sunday   ");
                 assert_eq!(format!("{}", ts.format("DY").unwrap()), "SUN");
                 assert_eq!(format!("{}", ts.format("Dy").unwrap()), "Sun");
                 assert_eq!(format!("{}", ts.format("dy").unwrap()), "sun");
@@ -1034,14 +1219,14 @@ mod tests {
                 assert!(Timestamp::parse("-12", "ss").is_err());
 
                 let ts = generate_ts(1970, 1, 1, 7, 8, 9, 10);
-                assert_eq!(format!("{}", ts.format("day").unwrap()), "thursday");
+                assert_eq!(format!("{}", ts.format("day").unwrap()), "thursday ");
                 assert_eq!(format!("{}", ts.format("d").unwrap()), "5");
                 assert_eq!(format!("{}", ts.format("ddd").unwrap()), "001");
                 assert_eq!(format!("{}", ts.format("ww").unwrap()), "01");
                 assert_eq!(format!("{}", ts.format("w").unwrap()), "1");
 
                 let ts = generate_ts(1970, 1, 2, 7, 8, 9, 10);
-                assert_eq!(format!("{}", ts.format("day").unwrap()), "friday");
+                assert_eq!(format!("{}", ts.format("day").unwrap()), "friday   ");
 
                 let ts = generate_ts(1969, 12, 31, 7, 8, 9, 10);
                 assert_eq!(format!("{}", ts.format("day").unwrap()), "wednesday");
@@ -1054,20 +1239,20 @@ mod tests {
                 assert_eq!(format!("{}", ts.format("day").unwrap()), "wednesday");
 
                 let ts = generate_ts(9999, 11, 14, 7, 8, 9, 10);
-                assert_eq!(format!("{}", ts.format("day").unwrap()), "sunday");
+                assert_eq!(format!("{}", ts.format("day").unwrap()), "sunday   ");
             }
 
             // Normal
             {
                 let ts = generate_ts(2000, 1, 1, 0, 0, 0, 0);
                 let fmt = format!("{}", ts.format("yyyy-MONTH-dd hh:mi:ss.ff1").unwrap());
-                assert_eq!(fmt, "2000-JANUARY-01 12:00:00.0");
+                assert_eq!(fmt, "2000-JANUARY  -01 12:00:00.0");
 
                 let fmt = format!("{}", ts.format("yyyy-Mon-dd hh:mi:ss.ff1").unwrap());
                 assert_eq!(fmt, "2000-Jan-01 12:00:00.0");
 
                 let fmt = format!("{}", ts.format("Day yyyy-Mon-dd hh:mi:ss.ff1").unwrap());
-                assert_eq!(fmt, "Saturday 2000-Jan-01 12:00:00.0");
+                assert_eq!(fmt, "Saturday  2000-Jan-01 12:00:00.0");
 
                 let fmt = format!("{}", ts.format("yyyyMMdd hh24miss.ff1").unwrap());
                 assert_eq!(fmt, "20000101 000000.0");
@@ -1241,7 +1426,7 @@ mod tests {
                         ts.format("DAY DaY DY D DDD W WW WW MM MM yyyy YYYY MI MI")
                             .unwrap()
                     ),
-                    "SUNDAY Sunday SUN 1 115 4 17 17 04 04 2021 2021 04 04"
+                    "SUNDAY    Sunday    SUN 1 115 4 17 17 04 04 2021 2021 04 04"
                 );
 
                 assert_eq!(
@@ -1249,7 +1434,7 @@ mod tests {
                         "{}",
                         ts.format("DAYDaYDYDWWWWWDMMMMyyyyYYYYMIMIDDD").unwrap()
                     ),
-                    "SUNDAYSundaySUN11717410404202120210404115"
+                    "SUNDAY   Sunday   SUN11717410404202120210404115"
                 );
             }
 
@@ -1322,6 +1507,526 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_timestamp_locale_territory() {
+        let ts = generate_ts(2021, 4, 3, 17, 6, 7, 0);
+
+        let fmt = Formatter::try_new("YYYY MONTH DD AM HH:MI:SS")
+            .unwrap()
+            .with_locale(&Locale::SIMPLIFIED_CHINESE);
+        let mut s = String::new();
+        fmt.format(ts, &mut s).unwrap();
+        assert_eq!(s, "2021 四月  03 下午 05:06:07");
+        assert_eq!(fmt.parse::<_, Timestamp>(&s).unwrap(), ts);
+        assert_eq!(
+            fmt.parse::<_, Timestamp>("2021 四月 03 上午 05:06:07")
+                .unwrap(),
+            generate_ts(2021, 4, 3, 5, 6, 7, 0)
+        );
+        assert!(fmt
+            .parse::<_, Timestamp>("2021 四月 03 PM 05:06:07")
+            .is_err());
+
+        assert_eq!(
+            format!("{}", ts.format("DS TS").unwrap()),
+            "04/03/2021 05:06:07 PM"
+        );
+        assert_eq!(
+            format!("{}", ts.format("DL TS").unwrap()),
+            "Saturday, April 3, 2021 05:06:07 PM"
+        );
+        assert_eq!(
+            Timestamp::parse("04/03/2021 05:06:07 PM", "DS TS").unwrap(),
+            ts
+        );
+        let fmt = Formatter::try_new_with_territory("DS TS", &Territory::GERMANY).unwrap();
+        let mut s = String::new();
+        fmt.format(ts, &mut s).unwrap();
+        assert_eq!(s, "03.04.21 17:06:07");
+        assert_eq!(fmt.parse::<_, Timestamp>(&s).unwrap(), ts);
+
+        let fmt = Formatter::try_new("Dy, DD Mon YYYY HH:MI:SS a.m.")
+            .unwrap()
+            .with_locale(&Locale::GERMAN);
+        let mut s = String::new();
+        fmt.format(ts, &mut s).unwrap();
+        assert_eq!(s, "Sa, 03 Apr 2021 05:06:07 p.m.");
+        assert_eq!(fmt.parse::<_, Timestamp>(&s).unwrap(), ts);
+    }
+
+    #[test]
+    fn test_timestamp_postgres() {
+        fn format(ts: Timestamp, fmt: &str) -> String {
+            let mut s = String::new();
+            Formatter::try_new_with(fmt, Dialect::Postgres)
+                .unwrap()
+                .format(ts, &mut s)
+                .unwrap();
+            s
+        }
+
+        fn parse(input: &str, fmt: &str) -> Result<Timestamp> {
+            Formatter::try_new_with(fmt, Dialect::Postgres)
+                .unwrap()
+                .parse(input)
+        }
+
+        let ts = generate_ts(2021, 3, 3, 7, 6, 5, 4321);
+
+        // Format
+        assert_eq!(
+            format(ts, "YYYY-MM-DD HH24:MI:SS.MS"),
+            "2021-03-03 07:06:05.004"
+        );
+        assert_eq!(
+            format(ts, "YYYY-MM-DD HH24:MI:SS.US"),
+            "2021-03-03 07:06:05.004321"
+        );
+        assert_eq!(
+            format(ts, "YYYY-MM-DD HH24:MI:SSOF"),
+            "2021-03-03 07:06:05+00"
+        );
+        assert_eq!(format(ts, "HH24:MI:SS TZ"), "07:06:05 ");
+        assert_eq!(format(ts, "SSSS"), "25565");
+        // FM only affects the next field
+        assert_eq!(format(ts, "FMDD-MM-YYYY FMHH:MI"), "3-03-2021 7:06");
+        assert_eq!(format(ts, "FMDD-FMMM"), "3-3");
+        assert_eq!(
+            format(ts, "Dy DY dy Mon MON mon"),
+            "Wed WED wed Mar MAR mar"
+        );
+        assert_eq!(format(ts, "FMMonth FMDDth"), "March 3rd");
+        assert_eq!(format(ts, "DDTH DDth"), "03RD 03rd");
+        // Unrecognized characters are literal text
+        assert_eq!(format(ts, "HH24 at YYYY #1"), "07 at 2021 #1");
+        assert_eq!(format(ts, "YYYY-MM-DDTHH24:MI"), "2021-03-03T07:06");
+        assert_eq!(format(ts, "\"Day\" DD"), "Day 03");
+        assert_eq!(
+            format!("{}", ts.format("DD MON YYYY").unwrap()),
+            "03 MAR 2021"
+        );
+
+        // Parse
+        let date = generate_ts(2021, 3, 3, 0, 0, 0, 0);
+        assert_eq!(parse("2021-03-03", "YYYY-MM-DD").unwrap(), date);
+        assert_eq!(parse("2021/03/03", "YYYY-MM-DD").unwrap(), date);
+        assert_eq!(parse("2021#03_03", "YYYY-MM-DD").unwrap(), date);
+        assert_eq!(parse("2021 03 03", "YYYY-MM-DD").unwrap(), date);
+        assert_eq!(parse("20210303", "YYYYMMDD").unwrap(), date);
+        assert_eq!(parse("2021-03-03", "YYYY/MM/DD").unwrap(), date);
+        assert_eq!(
+            parse("2021年03月03日", "YYYY\"年\"MM\"月\"DD\"日\"").unwrap(),
+            date
+        );
+        assert_eq!(
+            parse("2021xx03", "YYYY\"--\"MM").unwrap(),
+            generate_ts(2021, 3, 1, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            parse("2021T03", "YYYYTMM").unwrap(),
+            generate_ts(2021, 3, 1, 0, 0, 0, 0)
+        );
+        assert_eq!(parse("2021-03-03 4", "YYYY-MM-DD Q").unwrap(), date);
+        assert_eq!(
+            parse("2021-03-03 07:06:05.004", "YYYY-MM-DD HH24:MI:SS.MS").unwrap(),
+            generate_ts(2021, 3, 3, 7, 6, 5, 4000)
+        );
+        assert_eq!(
+            parse("2021-03-03 07:06:05.3", "YYYY-MM-DD HH24:MI:SS.MS").unwrap(),
+            generate_ts(2021, 3, 3, 7, 6, 5, 300000)
+        );
+        assert_eq!(
+            parse("2021-03-03 07:06:05.004321", "YYYY-MM-DD HH24:MI:SS.US").unwrap(),
+            ts
+        );
+        assert_eq!(parse("3 mar 2021", "DD Mon YYYY").unwrap(), date);
+        assert!(parse("2021/03/03", "FXYYYY-MM-DD").is_err());
+        assert!(parse("2021-03-03 07:06:05+00", "YYYY-MM-DD HH24:MI:SSOF").is_err());
+        assert!(parse("2021-03-03 UTC", "YYYY-MM-DD TZ").is_err());
+        assert!(Timestamp::parse("2021-03-03 4", "YYYY-MM-DD Q").is_err());
+    }
+
+    #[test]
+    fn test_timestamp_strftime() {
+        fn format(ts: Timestamp, fmt: &str) -> String {
+            let mut s = String::new();
+            Formatter::try_new_with(fmt, Dialect::Strftime)
+                .unwrap()
+                .format(ts, &mut s)
+                .unwrap();
+            s
+        }
+
+        fn parse(input: &str, fmt: &str) -> Result<Timestamp> {
+            Formatter::try_new_with(fmt, Dialect::Strftime)
+                .unwrap()
+                .parse(input)
+        }
+
+        let ts = generate_ts(2021, 3, 3, 7, 6, 5, 4321);
+
+        // Format
+        assert_eq!(
+            format(ts, "%Y-%m-%d %H:%M:%S.%f"),
+            "2021-03-03 07:06:05.004321"
+        );
+        assert_eq!(format(ts, "%S.%N"), "05.004321000");
+        assert_eq!(format(ts, "%e|%-d|%_m|%-m|%0e"), " 3|3| 3|3|03");
+        assert_eq!(format(ts, "%k|%l|%I %p %P|%-j|%_H"), " 7| 7|07 AM am|62| 7");
+        assert_eq!(
+            format(ts, "%a %A %b %B %h %^a %^B"),
+            "Wed Wednesday Mar March Mar WED MARCH"
+        );
+        assert_eq!(
+            format(ts, "%j %U %W %V %G %g %u %w"),
+            "062 09 09 09 2021 21 3 3"
+        );
+        assert_eq!(format(ts, "%s"), "1614755165");
+        assert_eq!(format(generate_ts(1969, 12, 31, 23, 59, 59, 0), "%s"), "-1");
+        assert_eq!(format(ts, "%c"), "Wed Mar  3 07:06:05 2021");
+        assert_eq!(
+            format(ts, "%D|%F|%T|%R"),
+            "03/03/21|2021-03-03|07:06:05|07:06"
+        );
+        assert_eq!(format(ts, "%r|%x|%X"), "07:06:05 AM|03/03/21|07:06:05");
+        assert_eq!(format(ts, "%%Y%n%t at %H o'clock"), "%Y\n\t at 07 o'clock");
+        assert_eq!(
+            format(generate_ts(2021, 1, 1, 0, 0, 0, 0), "%U %W %w %u"),
+            "00 00 5 5"
+        );
+        assert_eq!(
+            format(generate_ts(2023, 1, 1, 0, 0, 0, 0), "%U %W %w %u"),
+            "01 00 0 7"
+        );
+        assert!(Formatter::try_new_with("%Q", Dialect::Strftime).is_err());
+        assert!(Formatter::try_new_with("%z", Dialect::Strftime).is_err());
+        assert!(Formatter::try_new_with("%Y%", Dialect::Strftime).is_err());
+
+        // Parse
+        let date = generate_ts(2021, 3, 3, 0, 0, 0, 0);
+        assert_eq!(
+            parse("2021-03-03 07:06:05.004321", "%Y-%m-%d %H:%M:%S.%f").unwrap(),
+            ts
+        );
+        assert_eq!(
+            parse("2021-03-03 07:06:05.004321000", "%F %T.%N").unwrap(),
+            ts
+        );
+        assert_eq!(parse(" 3/ 3/21", "%e/%_m/%y").unwrap(), date);
+        assert_eq!(parse("3/3/21", "%D").unwrap(), date);
+        assert_eq!(
+            parse("69-01-01", "%y-%m-%d").unwrap(),
+            generate_ts(1969, 1, 1, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            parse("68-01-01", "%y-%m-%d").unwrap(),
+            generate_ts(2068, 1, 1, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            parse("99-12-31", "%y-%m-%d").unwrap(),
+            generate_ts(1999, 12, 31, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            parse("Wed Mar  3 07:06:05 2021", "%c").unwrap(),
+            generate_ts(2021, 3, 3, 7, 6, 5, 0)
+        );
+        assert_eq!(
+            parse("wednesday, MARCH 3, 2021 7:06 pm", "%A, %B %d, %Y %I:%M %p").unwrap(),
+            generate_ts(2021, 3, 3, 19, 6, 0, 0)
+        );
+        assert_eq!(
+            parse("1614755165", "%s").unwrap(),
+            generate_ts(2021, 3, 3, 7, 6, 5, 0)
+        );
+        assert_eq!(parse("1614755165.004321", "%s.%f").unwrap(), ts);
+        assert_eq!(
+            parse("-1", "%s").unwrap(),
+            generate_ts(1969, 12, 31, 23, 59, 59, 0)
+        );
+        assert_eq!(parse("2021 062", "%Y %j").unwrap(), date);
+        assert_eq!(parse("2021 09 Wed", "%Y %U %a").unwrap(), date);
+        assert_eq!(parse("2021 09 3", "%Y %W %u").unwrap(), date);
+        assert_eq!(parse("2021 09 3", "%Y %U %w").unwrap(), date);
+        assert_eq!(
+            parse("2021 09", "%Y %U").unwrap(),
+            generate_ts(2021, 2, 28, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            parse("2021 00 Sat", "%Y %U %a").unwrap(),
+            generate_ts(2021, 1, 2, 0, 0, 0, 0)
+        );
+        assert_eq!(parse("2021-W09-3", "%G-W%V-%u").unwrap(), date);
+        assert_eq!(parse("100% 2021-03-03", "100%% %F").unwrap(), date);
+        assert!(parse("2021 00 Thu", "%Y %U %a").is_err());
+        assert!(parse("2021 54", "%Y %W").is_err());
+        assert!(parse("2021 09 Thu 03-03", "%Y %U %a %m-%d").is_err());
+        assert!(parse("2021-03-03 Thu", "%F %a").is_err());
+        assert!(parse("2021-03-03 8", "%F %u").is_err());
+        assert!(parse("2021-03-03 7", "%F %w").is_err());
+        assert!(parse("1614755165 2021", "%s %Y").is_err());
+        assert!(parse("1614755165 1614755165", "%s %s").is_err());
+        assert!(parse("99999999999999", "%s").is_err());
+        assert_eq!(
+            Formatter::try_new_with("%s", Dialect::Strftime)
+                .unwrap()
+                .parse::<_, Date>("1614755165")
+                .unwrap(),
+            Date::try_from_ymd(2021, 3, 3).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_timestamp_mysql() {
+        fn format(ts: Timestamp, fmt: &str) -> String {
+            let mut s = String::new();
+            Formatter::try_new_with(fmt, Dialect::MySql)
+                .unwrap()
+                .format(ts, &mut s)
+                .unwrap();
+            s
+        }
+
+        fn parse(input: &str, fmt: &str) -> Result<Timestamp> {
+            Formatter::try_new_with(fmt, Dialect::MySql)
+                .unwrap()
+                .parse(input)
+        }
+
+        let ts = generate_ts(2021, 3, 3, 7, 6, 5, 4321);
+
+        // Format
+        assert_eq!(format(ts, "%W %M %Y"), "Wednesday March 2021");
+        assert_eq!(
+            format(ts, "%a %b %c %D %d %e %f"),
+            "Wed Mar 3 3rd 03 3 004321"
+        );
+        assert_eq!(
+            format(ts, "%H %h %I %i %j %k %l %m %p %S %s"),
+            "07 07 07 06 062 7 7 03 AM 05 05"
+        );
+        assert_eq!(format(ts, "%r|%T"), "07:06:05 AM|07:06:05");
+        assert_eq!(
+            format(ts, "%U %u %V %v %X %x %w %y"),
+            "09 09 09 09 2021 2021 3 21"
+        );
+        assert_eq!(
+            format(generate_ts(2021, 1, 1, 0, 0, 0, 0), "%U %u %V %X %v %x"),
+            "00 00 52 2020 53 2020"
+        );
+        assert_eq!(
+            format(generate_ts(2020, 1, 1, 0, 0, 0, 0), "%U %u %V %X %v %x"),
+            "00 01 52 2019 01 2020"
+        );
+        assert_eq!(
+            format(generate_ts(2022, 1, 1, 0, 0, 0, 0), "%U %u %V %X %v %x"),
+            "00 00 52 2021 52 2021"
+        );
+        assert_eq!(format(generate_ts(2021, 3, 1, 0, 0, 0, 0), "%D"), "1st");
+        assert_eq!(format(generate_ts(2021, 3, 12, 0, 0, 0, 0), "%D"), "12th");
+        assert_eq!(format(generate_ts(2021, 3, 22, 0, 0, 0, 0), "%D"), "22nd");
+        assert_eq!(format(ts, "%Y%%%q年"), "2021%q年");
+        assert!(Formatter::try_new_with("%Y%", Dialect::MySql).is_err());
+
+        // Parse
+        let date = generate_ts(2021, 3, 3, 0, 0, 0, 0);
+        assert_eq!(
+            parse("2021-03-03 07:06:05.004321", "%Y-%m-%d %H:%i:%s.%f").unwrap(),
+            ts
+        );
+        assert_eq!(
+            parse("Wednesday March 3rd 2021", "%W %M %D %Y").unwrap(),
+            date
+        );
+        assert_eq!(parse("wed mar 3 2021", "%a %b %e %Y").unwrap(), date);
+        assert_eq!(parse("21-3-3", "%y-%c-%e").unwrap(), date);
+        assert_eq!(parse("21-03-03", "%Y-%m-%d").unwrap(), date);
+        assert_eq!(
+            parse("0021-03-03", "%Y-%m-%d").unwrap(),
+            generate_ts(21, 3, 3, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            parse("70-01-01", "%y-%m-%d").unwrap(),
+            generate_ts(1970, 1, 1, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            parse("69-01-01", "%y-%m-%d").unwrap(),
+            generate_ts(2069, 1, 1, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            parse("2021-03-03 07:06:05 PM", "%Y-%m-%d %r").unwrap(),
+            generate_ts(2021, 3, 3, 19, 6, 5, 0)
+        );
+        assert_eq!(
+            parse("2021-03-03 7:06:05", "%Y-%m-%d %T").unwrap(),
+            generate_ts(2021, 3, 3, 7, 6, 5, 0)
+        );
+        assert_eq!(parse("2021 09 Wednesday", "%X %V %W").unwrap(), date);
+        assert_eq!(parse("2021 09 Wednesday", "%x %v %W").unwrap(), date);
+        assert_eq!(parse("2021 09 3", "%Y %u %w").unwrap(), date);
+        assert_eq!(parse("2021 09 3", "%Y %U %w").unwrap(), date);
+        assert_eq!(
+            parse("2020 01 Wednesday", "%Y %u %W").unwrap(),
+            generate_ts(2020, 1, 1, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            parse("2021 01", "%X %V").unwrap(),
+            generate_ts(2021, 1, 3, 0, 0, 0, 0)
+        );
+        assert_eq!(parse("2021q03z03", "%Y%q%m%z%d").unwrap(), date);
+        assert!(parse("2021x03z03", "%Y%q%m%z%d").is_err());
+        assert!(parse("2021 00", "%X %V").is_err());
+        assert!(parse("2021", "%X").is_err());
+        assert!(parse("2021 2021 09", "%Y %X %V").is_err());
+        assert!(parse("2021-03-03 Wednesday", "%Y-%m-%d %a").is_err());
+    }
+
+    #[test]
+    fn test_timestamp_julian_seconds() {
+        let ts = generate_ts(2021, 4, 3, 5, 6, 7, 80000);
+
+        // Format
+        assert_eq!(
+            format!("{}", ts.format("J SSSSS").unwrap()),
+            "2459308 18367"
+        );
+        assert_eq!(
+            format!("{}", ts.format("Jsp").unwrap()),
+            "Two Million Four Hundred Fifty-Nine Thousand Three Hundred Eight"
+        );
+        assert_eq!(
+            format!("{}", ts.format("HH12TH MISP SSSPTH").unwrap()),
+            "05TH SIX SEVENTH"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                generate_ts(1, 1, 1, 0, 0, 5, 0).format("J SSSSS").unwrap()
+            ),
+            "1721426 00005"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                generate_ts(2000, 1, 1, 23, 59, 59, 0)
+                    .format("J FMSSSSS")
+                    .unwrap()
+            ),
+            "2451545 86399"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                generate_ts(2000, 1, 1, 0, 0, 5, 0)
+                    .format("FMSSSSS")
+                    .unwrap()
+            ),
+            "5"
+        );
+
+        // Parse
+        let ts = generate_ts(2021, 4, 3, 5, 6, 7, 0);
+        assert_eq!(Timestamp::parse("2459308 18367", "J SSSSS").unwrap(), ts);
+        assert_eq!(Timestamp::parse("2459308 18367", "FXJ SSSSS").unwrap(), ts);
+        assert_eq!(
+            Timestamp::parse("2459308", "J").unwrap(),
+            generate_ts(2021, 4, 3, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            Timestamp::parse("2021-04-03 18367.5", "YYYY-MM-DD SSSSS.FF").unwrap(),
+            generate_ts(2021, 4, 3, 5, 6, 7, 500000)
+        );
+        assert_eq!(
+            Timestamp::parse("2459308 2021-04-03 093 Sat", "J YYYY-MM-DD DDD DY").unwrap(),
+            generate_ts(2021, 4, 3, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            Timestamp::parse("20210403 18367 05:06:07 AM", "YYYYMMDD SSSSS HH:MI:SS AM").unwrap(),
+            ts
+        );
+
+        // Out of range
+        assert!(Timestamp::parse("0", "J").is_err());
+        assert!(Timestamp::parse("5373485", "J").is_err());
+        assert!(Timestamp::parse("38", "J").is_err());
+        assert_eq!(
+            Timestamp::parse("1721425", "J").unwrap(),
+            generate_ts(-1, 12, 31, 0, 0, 0, 0)
+        );
+        assert!(Timestamp::parse("86400", "SSSSS").is_err());
+        assert!(Timestamp::parse("-1", "SSSSS").is_err());
+        assert!(Timestamp::parse("1 1", "J J").is_err());
+
+        // Conflicts
+        assert!(Timestamp::parse("2459308 2020", "J YYYY").is_err());
+        assert!(Timestamp::parse("2459308 05", "J MM").is_err());
+        assert!(Timestamp::parse("2459308 04", "J DD").is_err());
+        assert!(Timestamp::parse("2459308 094", "J DDD").is_err());
+        assert!(Timestamp::parse("2459308 Sun", "J DY").is_err());
+        assert!(Timestamp::parse("18367 04", "SSSSS HH24").is_err());
+        assert!(Timestamp::parse("18367 07", "SSSSS MI").is_err());
+        assert!(Timestamp::parse("18367 08", "SSSSS SS").is_err());
+        assert!(Timestamp::parse("18367 PM", "SSSSS AM").is_err());
+        assert!(Timestamp::parse("18367 05:06:07 PM", "SSSSS HH:MI:SS AM").is_err());
+    }
+
+    #[test]
+    fn test_timestamp_format_exact() {
+        let ts = generate_ts(2021, 4, 3, 5, 6, 7, 80000);
+        let fmt = "FXyyyy-mm-dd hh24:mi:ss.ff2";
+
+        assert_eq!(Timestamp::parse("2021-04-03 05:06:07.08", fmt).unwrap(), ts);
+        assert_eq!(
+            format!("{}", ts.format(fmt).unwrap()),
+            "2021-04-03 05:06:07.08"
+        );
+
+        // Field widths must match exactly
+        assert!(Timestamp::parse("2021-4-03 05:06:07.08", fmt).is_err());
+        assert!(Timestamp::parse("2021-04-3 05:06:07.08", fmt).is_err());
+        assert!(Timestamp::parse("21-04-03 05:06:07.08", fmt).is_err());
+        assert!(Timestamp::parse("2021-04-03 5:06:07.08", fmt).is_err());
+        assert!(Timestamp::parse("2021-04-03 05:6:07.08", fmt).is_err());
+        assert!(Timestamp::parse("2021-04-03 05:06:7.08", fmt).is_err());
+        assert!(Timestamp::parse("2021-04-03 05:06:07.8", fmt).is_err());
+
+        // Whitespaces must match exactly
+        assert!(Timestamp::parse(" 2021-04-03 05:06:07.08", fmt).is_err());
+        assert!(Timestamp::parse("2021-04-03  05:06:07.08", fmt).is_err());
+        assert!(Timestamp::parse("2021-04-03 05:06:07.08 ", fmt).is_err());
+        assert!(Timestamp::parse("2021-04-0305:06:07.08", fmt).is_err());
+
+        // Separators must match exactly and can not be omitted
+        assert!(Timestamp::parse("2021/04-03 05:06:07.08", fmt).is_err());
+        assert!(Timestamp::parse("2021-04-03 05:06:07", fmt).is_err());
+        assert!(Timestamp::parse("2021-04-03 05:06", fmt).is_err());
+
+        // Without FX, the same input is accepted
+        let fmt = "yyyy-mm-dd hh24:mi:ss.ff2";
+        assert_eq!(Timestamp::parse(" 2021-4-3  5:6:7.08 ", fmt).unwrap(), ts);
+
+        // FX toggles exact matching on and off
+        let fmt = "FXyyyy-mm-dd FXhh24:mi:ss";
+        let ts = generate_ts(2021, 4, 3, 5, 6, 7, 0);
+        assert_eq!(Timestamp::parse("2021-04-03 5:6:7", fmt).unwrap(), ts);
+        assert_eq!(
+            Timestamp::parse("2021-04-03   5:6", fmt).unwrap(),
+            generate_ts(2021, 4, 3, 5, 6, 0, 0)
+        );
+        assert!(Timestamp::parse("2021-4-03 5:6:7", fmt).is_err());
+
+        // FM suppresses leading zeros, and relaxes the field widths in FX mode
+        let ts = generate_ts(2021, 4, 3, 5, 6, 7, 0);
+        let fmt = "FXFMyyyy-mm-dd hh24:mi:ss";
+        assert_eq!(format!("{}", ts.format(fmt).unwrap()), "2021-4-3 5:6:7");
+        assert_eq!(Timestamp::parse("2021-4-3 5:6:7", fmt).unwrap(), ts);
+        assert_eq!(Timestamp::parse("2021-04-03 05:06:07", fmt).unwrap(), ts);
+        assert!(Timestamp::parse("2021-4-3  5:6:7", fmt).is_err());
+        assert_eq!(
+            format!("{}", ts.format("FMhh:mi:ss FMAM").unwrap()),
+            "5:6:7 AM"
+        );
+    }
+
     #[test]
     fn test_timestamp_date_time() {
         let ts = generate_ts(1, 1, 1, 0, 0, 0, 0);
@@ -1427,9 +2132,9 @@ mod tests {
         let interval = IntervalDT::try_from_dhms(12345, 12, 3, 5, 6).unwrap();
         assert!(ts.add_interval_dt(interval).is_err());
 
-        let ts = generate_ts(1, 1, 1, 0, 0, 0, 0);
+        let ts = generate_ts(-4712, 1, 1, 0, 0, 0, 0);
         let interval = IntervalDT::try_from_dhms(5, 4, 3, 2, 1).unwrap();
-        let expect = generate_ts(1, 1, 6, 4, 3, 2, 1);
+        let expect = generate_ts(-4712, 1, 6, 4, 3, 2, 1);
         assert_eq!(ts.add_interval_dt(interval).unwrap(), expect);
 
         let interval = IntervalDT::try_from_dhms(0, 0, 0, 0, 1).unwrap();
@@ -1497,7 +2202,7 @@ mod tests {
 
         // Boundary test
         let upper_ts = generate_ts(9999, 12, 31, 23, 59, 59, 999999);
-        let lower_ts = generate_ts(1, 1, 1, 0, 0, 0, 0);
+        let lower_ts = generate_ts(-4712, 1, 1, 0, 0, 0, 0);
         let interval = IntervalYM::try_from_ym(0, 1).unwrap();
 
         assert!(upper_ts.add_interval_ym(interval).is_err());
@@ -1570,7 +2275,7 @@ mod tests {
         let time = Time::try_from_hms(0, 0, 0, 1).unwrap();
         assert!(ts.add_time(time).is_err());
 
-        let ts = generate_ts(1, 1, 1, 0, 0, 0, 0);
+        let ts = generate_ts(-4712, 1, 1, 0, 0, 0, 0);
         let time = Time::try_from_hms(5, 4, 3, 2).unwrap();
         assert!(ts.sub_time(time).is_err());
 
@@ -1627,7 +2332,7 @@ mod tests {
     #[test]
     fn test_timestamp_add_sub_days() {
         let upper_ts = generate_ts(9999, 12, 31, 23, 59, 59, 999999);
-        let lower_ts = generate_ts(1, 1, 1, 0, 0, 0, 0);
+        let lower_ts = generate_ts(-4712, 1, 1, 0, 0, 0, 0);
 
         // Out of range
         assert!(lower_ts.add_days(213435445784784.13).is_err());
@@ -1649,7 +2354,7 @@ mod tests {
         // Round
         assert_eq!(
             lower_ts.add_days(1.123456789).unwrap(),
-            generate_ts(1, 1, 2, 2, 57, 46, 666570)
+            generate_ts(-4712, 1, 2, 2, 57, 46, 666570)
         );
         assert_eq!(
             upper_ts.sub_days(1.123456789).unwrap(),
@@ -1665,7 +2370,7 @@ mod tests {
         );
         assert_eq!(
             lower_ts.add_days(1.0).unwrap(),
-            generate_ts(1, 1, 2, 0, 0, 0, 0)
+            generate_ts(-4712, 1, 2, 0, 0, 0, 0)
         );
 
         let ts = generate_ts(5000, 6, 15, 12, 30, 30, 555555);
@@ -1714,7 +2419,7 @@ mod tests {
     fn test_now() {
         let now = Local::now().naive_local();
         let dt = Timestamp::now().unwrap();
-        assert_eq!(now.year() as i32, dt.year().unwrap());
+        assert_eq!(now.year(), dt.year().unwrap());
         assert_eq!(now.month() as i32, dt.month().unwrap());
         assert_eq!(now.day() as i32, dt.day().unwrap());
         assert_eq!(now.hour() as i32, dt.hour().unwrap());